@@ -5,13 +5,14 @@
 use std::path::PathBuf;
 use std::sync::Arc;
 
-use clap::{Parser, Subcommand};
+use chrono::Datelike;
+use clap::{Parser, Subcommand, ValueEnum};
 use crawler::{
     error::Result,
     models::{Campus, Config},
-    pipeline,
-    storage::LocalStorage,
-    utils::http,
+    pipeline::{self, CircuitBreaker},
+    storage::{ByteReader, LocalStorage, NoticeStorage},
+    utils::{export, http},
 };
 
 /// uRing - University Notice Crawler
@@ -21,7 +22,6 @@ use crawler::{
     version,
     about = "Integrated University Notice Crawler"
 )]
-
 struct Cli {
     /// Path to storage directory containing config files
     #[arg(short, long, default_value = "storage")]
@@ -31,6 +31,12 @@ struct Cli {
     #[arg(short, long)]
     verbose: bool,
 
+    /// Keep snapshots in a directory of this campus's own under
+    /// {storage_dir}, so it can be crawled and published on its own schedule.
+    /// Crawl and pipeline then only crawl this campus
+    #[arg(long, global = true)]
+    campus_scope: Option<String>,
+
     #[command(subcommand)]
     command: Command,
 }
@@ -50,6 +56,17 @@ enum Command {
         /// Path to sitemap file (default: {storage_dir}/siteMap.json)
         #[arg(long)]
         sitemap: Option<PathBuf>,
+
+        /// Crawl and summarize without writing anything to storage
+        #[arg(long)]
+        dry_run: bool,
+
+        /// Only crawl campuses whose name contains this text. Requires
+        /// --dry-run: writing a partial snapshot would drop every other
+        /// campus's notices from current.json (use --campus-scope to write
+        /// one campus to its own storage)
+        #[arg(long, requires = "dry_run")]
+        campus: Option<String>,
     },
 
     /// Run full pipeline: Map → Crawl
@@ -58,6 +75,10 @@ enum Command {
         /// Skip mapping, use existing sitemap
         #[arg(long)]
         skip_map: bool,
+
+        /// Crawl and summarize without writing anything to storage
+        #[arg(long)]
+        dry_run: bool,
     },
 
     /// Validate configuration files
@@ -65,6 +86,142 @@ enum Command {
 
     /// Show current snapshot info
     Info,
+
+    /// Diagnose common misconfigurations (exits non-zero on failure)
+    Doctor,
+
+    /// Enable or disable a board in the sitemap
+    Board {
+        #[command(subcommand)]
+        action: BoardAction,
+    },
+
+    /// Work through departments flagged for manual review
+    #[cfg(feature = "map")]
+    Review {
+        #[command(subcommand)]
+        action: ReviewAction,
+    },
+
+    /// List stored notices dated in a month (archive plus hot window)
+    Load {
+        /// Month to load, as YYYY-MM
+        month: String,
+    },
+
+    /// List the snapshots of recent crawl runs, newest first
+    Snapshots,
+
+    /// Compare the notices written by two crawl runs and print the added,
+    /// updated and removed notice IDs. Each write keeps a snapshot of its
+    /// notices, for the most recent runs only (see `snapshots`)
+    Diff {
+        /// Older run, as listed by `snapshots`
+        from: String,
+
+        /// Newer run, or `latest`
+        #[arg(default_value = "latest")]
+        to: String,
+    },
+
+    /// Search the latest snapshot by keyword, best matches first
+    Search {
+        /// Search query
+        query: String,
+
+        /// Only show notices from this campus
+        #[arg(long)]
+        campus: Option<String>,
+
+        /// Maximum number of results (default: 20)
+        #[arg(long)]
+        limit: Option<usize>,
+    },
+
+    /// Write a feed of one board's latest notices to
+    /// {storage_dir}/feed-{dept}-{board}.xml (or .json with --json)
+    Feed {
+        /// Board ID
+        #[arg(long)]
+        board: String,
+
+        /// Only match boards in this department
+        #[arg(long)]
+        dept: Option<String>,
+
+        /// Maximum number of feed items
+        #[arg(long, default_value_t = pipeline::FEED_MAX_ITEMS)]
+        limit: usize,
+
+        /// Write a JSON Feed 1.1 document instead of RSS
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Export the latest snapshot in a publishable format
+    Export {
+        /// Output format
+        #[arg(long, value_enum, default_value_t = ExportFormat::Sitemap)]
+        format: ExportFormat,
+
+        /// Public base URL where exported files are served (sitemaps only)
+        #[arg(long)]
+        base_url: Option<String>,
+
+        /// Output directory (default: {storage_dir})
+        #[arg(long)]
+        output: Option<PathBuf>,
+
+        /// Start the CSV with a UTF-8 byte order mark (for Excel)
+        #[arg(long)]
+        bom: bool,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+enum BoardAction {
+    /// Stop crawling a board while keeping it in the sitemap
+    Disable {
+        /// Board ID
+        id: String,
+
+        /// Only match boards in this department
+        #[arg(long)]
+        dept: Option<String>,
+    },
+
+    /// Resume crawling a previously disabled board
+    Enable {
+        /// Board ID
+        id: String,
+
+        /// Only match boards in this department
+        #[arg(long)]
+        dept: Option<String>,
+    },
+}
+
+#[cfg(feature = "map")]
+#[derive(Subcommand, Debug)]
+enum ReviewAction {
+    /// Re-discover a fixed department and drop it from the review list
+    Resolve {
+        /// Department name or URL of the review item
+        target: String,
+    },
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug)]
+enum ExportFormat {
+    /// Search-engine sitemap.xml (split into a sitemap index when large)
+    Sitemap,
+    /// Like `sitemap`, listing our notice pages ({base_url}/notice/{id})
+    /// instead of the source links
+    DetailSitemap,
+    /// notices.csv, one row per notice
+    Csv,
+    /// notices.json, the snapshot's notices as a JSON array
+    Json,
 }
 
 /// Initialize logging based on verbosity flag.
@@ -75,6 +232,21 @@ fn init_logging(verbose: bool) {
         .init();
 }
 
+/// Keep only the campus named by `--campus-scope`, if given.
+fn scope_campuses(campuses: &mut Vec<Campus>, scope: Option<&str>) -> Result<()> {
+    let Some(scope) = scope else {
+        return Ok(());
+    };
+    campuses.retain(|c| c.campus == scope);
+    if campuses.is_empty() {
+        return Err(crawler::error::AppError::validation(format!(
+            "Campus '{}' is not in the sitemap",
+            scope
+        )));
+    }
+    Ok(())
+}
+
 /// Main entry point for the CLI application.
 #[tokio::main]
 async fn main() -> Result<()> {
@@ -83,15 +255,30 @@ async fn main() -> Result<()> {
 
     log::info!("uRing Crawler starting...");
 
-    // Load configurations
-    let config_path = cli.storage_dir.join("config.toml");
-    let config = Config::load_or_default(&config_path);
+    let storage = LocalStorage::new(&cli.storage_dir);
+
+    // Load configurations through the storage backend
+    let config = storage.load_config_or_default(Config::STORAGE_KEY).await;
 
     log::info!("Loaded configuration from {}", cli.storage_dir.display());
 
+    // The breaker guarding snapshot writes is configured from [circuit_breaker]
+    let storage = LocalStorage::with_circuit_breaker(
+        &cli.storage_dir,
+        CircuitBreaker::with_config(config.circuit_breaker.clone()),
+    )
+    .with_index_config(config.index.clone())
+    .with_id_options(config.crawler.id_options());
+    let storage = match &cli.campus_scope {
+        Some(campus) => {
+            log::info!("Using the storage of campus '{}'", campus);
+            storage.with_campus(campus)
+        }
+        None => storage,
+    };
+
     let config = Arc::new(config);
-    let storage = LocalStorage::new(&cli.storage_dir);
-    let sitemap_path = cli.storage_dir.join("siteMap.json");
+    let sitemap_path = cli.storage_dir.join(Campus::STORAGE_KEY);
 
     match cli.command {
         #[cfg(feature = "map")]
@@ -107,11 +294,9 @@ async fn main() -> Result<()> {
             let client = http::create_client(&config.crawler)?;
             let result = pipeline::run_mapper(&config, &client).await?;
 
-            // Save sitemap
-            let json = serde_json::to_string_pretty(&result.campuses)?;
-            std::fs::write(&sitemap_path, json)?;
+            let review_path = cli.storage_dir.join("mapReview.json");
+            pipeline::write_mapper_result(&result, &sitemap_path, &review_path)?;
 
-            log::info!("Sitemap saved to {}", sitemap_path.display());
             log::info!(
                 "Discovered {} campuses with {} total boards",
                 result.campuses.len(),
@@ -121,83 +306,93 @@ async fn main() -> Result<()> {
                     .map(|c| c.board_count())
                     .sum::<usize>()
             );
-
-            // Save manual review items if any
-            if !result.manual_reviews.is_empty() {
-                let review_path = cli.storage_dir.join("mapReview.json");
-                let review_json = serde_json::to_string_pretty(&result.manual_reviews)?;
-                std::fs::write(&review_path, review_json)?;
-                log::warn!(
-                    "{} items need manual review. See {}",
-                    result.manual_reviews.len(),
-                    review_path.display()
-                );
-            }
         }
 
-        Command::Crawl { sitemap } => {
-            let sitemap_path = sitemap.unwrap_or(sitemap_path);
-
-            if !sitemap_path.exists() {
-                log::error!(
-                    "Sitemap not found at {}. Run 'map' first.",
-                    sitemap_path.display()
-                );
-                return Err(crawler::error::AppError::config("Sitemap not found"));
-            }
-
-            let campuses = Campus::load_all(&sitemap_path)?;
+        Command::Crawl {
+            sitemap,
+            dry_run,
+            campus,
+        } => {
+            let mut campuses = match sitemap {
+                Some(path) => Campus::load_all(&path)?,
+                None => {
+                    storage
+                        .load_sitemap_or_file(Campus::STORAGE_KEY, &sitemap_path)
+                        .await?
+                }
+            };
+            scope_campuses(&mut campuses, cli.campus_scope.as_deref())?;
             log::info!(
                 "Loaded {} campuses with {} boards",
                 campuses.len(),
                 campuses.iter().map(|c| c.board_count()).sum::<usize>()
             );
+            if let Some(filter) = &campus {
+                campuses.retain(|c| c.campus.contains(filter.as_str()));
+                if campuses.is_empty() {
+                    return Err(crawler::error::AppError::validation(format!(
+                        "No campus matches '{}'",
+                        filter
+                    )));
+                }
+                log::info!(
+                    "Filtered to {} campuses with {} boards matching '{}'",
+                    campuses.len(),
+                    campuses.iter().map(|c| c.board_count()).sum::<usize>(),
+                    filter
+                );
+            }
 
-            let client = http::create_client(&config.crawler)?;
-            pipeline::run_crawler(Arc::clone(&config), &storage, &campuses, &client).await?;
+            let client = http::create_crawl_client(&config.crawler, &campuses)?;
+            pipeline::run_crawler(
+                Arc::clone(&config),
+                &storage,
+                &campuses,
+                &client,
+                None,
+                dry_run,
+            )
+            .await?;
 
             log::info!("Crawl complete!");
         }
 
         #[cfg(feature = "map")]
-        Command::Pipeline { skip_map } => {
-            let client = http::create_client(&config.crawler)?;
-
+        Command::Pipeline { skip_map, dry_run } => {
             // Step 1: Map (unless skipped)
-            let campuses = if skip_map {
-                if !sitemap_path.exists() {
-                    return Err(crawler::error::AppError::config(
-                        "Cannot skip map: siteMap.json not found",
-                    ));
-                }
-
+            let mut campuses = if skip_map {
                 log::info!("Skipping map, loading existing sitemap...");
-                Campus::load_all(&sitemap_path)?
+                storage
+                    .load_sitemap_or_file(Campus::STORAGE_KEY, &sitemap_path)
+                    .await?
             } else {
                 log::info!("Step 1/2: Mapping departments and boards...");
+                let client = http::create_client(&config.crawler)?;
                 let result = pipeline::run_mapper(&config, &client).await?;
-                let json = serde_json::to_string_pretty(&result.campuses)?;
-                std::fs::write(&sitemap_path, json)?;
-                log::info!("Sitemap saved to {}", sitemap_path.display());
-
-                // Save manual review items if any
-                if !result.manual_reviews.is_empty() {
+                if dry_run {
+                    log::info!("Dry run: sitemap not written");
+                } else {
                     let review_path = cli.storage_dir.join("mapReview.json");
-                    let review_json = serde_json::to_string_pretty(&result.manual_reviews)?;
-                    std::fs::write(&review_path, review_json)?;
-                    log::warn!(
-                        "{} items need manual review. See {}",
-                        result.manual_reviews.len(),
-                        review_path.display()
-                    );
+                    pipeline::write_mapper_result(&result, &sitemap_path, &review_path)?;
                 }
 
                 result.campuses
             };
 
+            scope_campuses(&mut campuses, cli.campus_scope.as_deref())?;
+
             // Step 2: Crawl
             log::info!("Step 2/2: Crawling notices...");
-            pipeline::run_crawler(Arc::clone(&config), &storage, &campuses, &client).await?;
+            let client = http::create_crawl_client(&config.crawler, &campuses)?;
+            pipeline::run_crawler(
+                Arc::clone(&config),
+                &storage,
+                &campuses,
+                &client,
+                None,
+                dry_run,
+            )
+            .await?;
 
             log::info!("Pipeline complete!");
         }
@@ -225,22 +420,335 @@ async fn main() -> Result<()> {
                 }
             );
 
-            let current_path = cli.storage_dir.join("current.json");
+            let current_path = storage.root_dir().join("current.json");
             if current_path.exists() {
-                if let Ok(content) = std::fs::read_to_string(&current_path) {
-                    if let Ok(pointer) = serde_json::from_str::<serde_json::Value>(&content) {
-                        if let Some(version) = pointer.get("version") {
-                            log::info!("Current snapshot: {}", version);
-                        }
-                        if let Some(updated) = pointer.get("updated_at") {
-                            log::info!("Last updated: {}", updated);
-                        }
+                if let Ok(content) = std::fs::read_to_string(&current_path)
+                    && let Ok(pointer) = serde_json::from_str::<serde_json::Value>(&content)
+                {
+                    if let Some(version) = pointer.get("version") {
+                        log::info!("Current snapshot: {}", version);
+                    }
+                    if let Some(updated) = pointer.get("updated_at") {
+                        log::info!("Last updated: {}", updated);
                     }
                 }
             } else {
                 log::info!("No snapshot found yet.");
             }
         }
+
+        Command::Doctor => {
+            let client = http::create_client(&config.crawler)?;
+            let report =
+                pipeline::run_doctor(Arc::clone(&config), &client, &storage, &sitemap_path).await;
+
+            for check in &report.checks {
+                if check.passed {
+                    log::info!("[PASS] {}: {}", check.name, check.detail);
+                } else {
+                    log::error!("[FAIL] {}: {}", check.name, check.detail);
+                    if let Some(hint) = check.hint {
+                        log::error!("       hint: {}", hint);
+                    }
+                }
+            }
+
+            let failed = report.failures().count();
+            if failed > 0 {
+                return Err(crawler::error::AppError::validation(format!(
+                    "{} doctor check(s) failed",
+                    failed
+                )));
+            }
+            log::info!("All checks passed!");
+        }
+
+        Command::Board { action } => {
+            let (id, dept, enabled) = match action {
+                BoardAction::Disable { id, dept } => (id, dept, false),
+                BoardAction::Enable { id, dept } => (id, dept, true),
+            };
+
+            let mut campuses = storage
+                .load_sitemap_or_file(Campus::STORAGE_KEY, &sitemap_path)
+                .await?;
+            let matched: usize = campuses
+                .iter_mut()
+                .map(|c| c.set_board_enabled(dept.as_deref(), &id, enabled))
+                .sum();
+            if matched == 0 {
+                return Err(crawler::error::AppError::config(format!(
+                    "No board '{}' found in {}",
+                    id,
+                    sitemap_path.display()
+                )));
+            }
+
+            let json = serde_json::to_string_pretty(&campuses)?;
+            std::fs::write(&sitemap_path, json)?;
+            log::info!(
+                "{} {} board(s) with id '{}'",
+                if enabled { "Enabled" } else { "Disabled" },
+                matched,
+                id
+            );
+        }
+
+        #[cfg(feature = "map")]
+        Command::Review {
+            action: ReviewAction::Resolve { target },
+        } => {
+            let review_path = cli.storage_dir.join("mapReview.json");
+            let mut reviews: Vec<crawler::models::ManualReviewItem> =
+                serde_json::from_str(&std::fs::read_to_string(&review_path)?)?;
+            let mut campuses = Campus::load_all(&sitemap_path)?;
+
+            let client = http::create_client(&config.crawler)?;
+            let resolution =
+                pipeline::resolve_reviews(&config, &client, &mut campuses, &mut reviews, &target)
+                    .await?;
+
+            std::fs::write(&sitemap_path, serde_json::to_string_pretty(&campuses)?)?;
+            std::fs::write(&review_path, serde_json::to_string_pretty(&reviews)?)?;
+            log::info!(
+                "Resolved {} item(s), added {} board(s); {} item(s) still pending",
+                resolution.resolved.len(),
+                resolution.boards_added,
+                resolution.pending.len()
+            );
+        }
+
+        Command::Load { month } => {
+            let date = chrono::NaiveDate::parse_from_str(&format!("{}-01", month), "%Y-%m-%d")
+                .map_err(|_| {
+                    crawler::error::AppError::validation(format!(
+                        "Invalid month '{}', expected YYYY-MM",
+                        month
+                    ))
+                })?;
+            let notices = storage.load_month(date.year(), date.month()).await?;
+            log::info!("{} notices stored for {}", notices.len(), month);
+            for notice in notices {
+                println!(
+                    "{} [{}] {}\n  {}",
+                    notice.metadata.date, notice.metadata.board_name, notice.title, notice.link
+                );
+            }
+        }
+
+        Command::Snapshots => {
+            let snapshots = storage.list_snapshots().await?;
+            if snapshots.is_empty() {
+                log::info!("No snapshot found yet.");
+            }
+            for snapshot in snapshots {
+                let finished_at = snapshot
+                    .finished_at
+                    .map_or_else(|| "-".to_string(), |at| at.to_rfc3339());
+                let status = if snapshot.complete {
+                    format!("{} notices", snapshot.notice_count)
+                } else {
+                    "incomplete".to_string()
+                };
+                println!("{}\t{}\t{}", snapshot.version, finished_at, status);
+            }
+        }
+
+        Command::Diff { from, to } => {
+            let previous = storage.load_version(&from).await?;
+            let current = storage.load_version(&to).await?;
+            let result = pipeline::DiffCalculator::new().calculate(&previous, &current);
+            log::info!(
+                "{} -> {}: {} added, {} updated, {} removed",
+                from,
+                to,
+                result.diff.added.len(),
+                result.diff.updated.len(),
+                result.diff.removed.len()
+            );
+
+            let mut added = result.diff.added.clone();
+            added.sort();
+            for id in added {
+                println!("+ {}", id);
+            }
+            let mut updated = result.updated_changes.clone();
+            updated.sort_by(|a, b| a.id.cmp(&b.id));
+            for change in updated {
+                println!("~ {} ({})", change.id, change.changed_fields.join(", "));
+            }
+            let mut removed = result.diff.removed.clone();
+            removed.sort();
+            for id in removed {
+                println!("- {}", id);
+            }
+        }
+
+        Command::Search {
+            query,
+            campus,
+            limit,
+        } => {
+            let notices = storage.load_current().await?;
+            if notices.is_empty() {
+                return Err(crawler::error::AppError::validation(
+                    "No snapshot found yet. Run `crawl` first.",
+                ));
+            }
+
+            // Prefer the stored index (only the shards the query needs when it
+            // is sharded); rebuild it for snapshots crawled without one, or
+            // when the index is disabled and any stored one may be stale
+            let stored = if config.index.enabled {
+                storage.load_index_for_query(&query, &config.index).await?
+            } else {
+                None
+            };
+            let index = match stored {
+                Some(index) => index,
+                None => pipeline::build_index_with_config(&notices, &config.index),
+            };
+
+            let limit = limit.unwrap_or(20);
+            let by_id: std::collections::HashMap<&str, _> =
+                notices.iter().map(|n| (n.id.as_str(), n)).collect();
+            // Rank everything when filtering so the campus filter can still fill `limit`
+            let rank_limit = if campus.is_some() {
+                notices.len()
+            } else {
+                limit
+            };
+            let matches: Vec<_> = index
+                .search_ranked(&query, &config.index, rank_limit)
+                .into_iter()
+                .filter_map(|(id, _)| by_id.get(id.as_str()).copied())
+                .filter(|n| campus.as_ref().is_none_or(|c| &n.metadata.campus == c))
+                .take(limit)
+                .collect();
+
+            if matches.is_empty() {
+                log::info!("No notices match '{}'", query);
+            }
+            for notice in matches {
+                println!("{}\n  {}", notice.title, notice.link);
+            }
+        }
+
+        Command::Feed {
+            board,
+            dept,
+            limit,
+            json,
+        } => {
+            let campuses = storage
+                .load_sitemap_or_file(Campus::STORAGE_KEY, &sitemap_path)
+                .await?;
+            let matches: Vec<_> = Campus::departments_of(&campuses)
+                .into_iter()
+                .filter(|d| dept.as_deref().is_none_or(|id| d.dept.id == id))
+                .flat_map(|d| {
+                    d.dept
+                        .boards
+                        .iter()
+                        .filter(|b| b.id == board)
+                        .map(move |b| (d.campus, d.dept, b))
+                })
+                .collect();
+            let (campus, department, target) = match matches.as_slice() {
+                [only] => *only,
+                [] => {
+                    return Err(crawler::error::AppError::config(format!(
+                        "No board '{}' found in {}",
+                        board,
+                        sitemap_path.display()
+                    )));
+                }
+                _ => {
+                    return Err(crawler::error::AppError::config(format!(
+                        "Board '{}' exists in {} departments; pass --dept to pick one",
+                        board,
+                        matches.len()
+                    )));
+                }
+            };
+
+            // Snapshot notices carry names rather than IDs, so match on those
+            let notices: Vec<_> = storage
+                .load_current()
+                .await?
+                .into_iter()
+                .filter(|n| {
+                    n.metadata.campus == campus
+                        && n.metadata.department_name == department.name
+                        && n.metadata.board_name == target.name
+                })
+                .collect();
+
+            let title = format!("{} {}", department.name, target.name);
+            let (contents, extension) = if json {
+                let feed =
+                    pipeline::build_json_feed_with_limit(&notices, &title, &target.url, limit);
+                (feed, "json")
+            } else {
+                let xml = pipeline::build_rss_with_limit(&notices, &title, &target.url, limit);
+                (xml, "xml")
+            };
+            // One file per board, so feeds of different boards sit side by side
+            let file_name = format!("feed-{}-{}.{}", department.id, target.id, extension);
+            let feed_path = cli.storage_dir.join(file_name);
+            std::fs::write(&feed_path, contents)?;
+            log::info!(
+                "Wrote {} item(s) for {} to {}",
+                notices.len().min(limit),
+                title,
+                feed_path.display()
+            );
+        }
+
+        Command::Export {
+            format,
+            base_url,
+            output,
+            bom,
+        } => {
+            let output_dir = output.unwrap_or_else(|| cli.storage_dir.clone());
+            let notices = storage.load_current().await?;
+
+            let files = match format {
+                ExportFormat::Sitemap => {
+                    let base_url = base_url.ok_or_else(|| {
+                        crawler::error::AppError::validation("--base-url is required for sitemaps")
+                    })?;
+                    export::write_search_sitemap(&notices, &base_url)
+                }
+                ExportFormat::DetailSitemap => {
+                    let base_url = base_url.ok_or_else(|| {
+                        crawler::error::AppError::validation("--base-url is required for sitemaps")
+                    })?;
+                    pipeline::build_sitemap_xml_files(&notices, &base_url)
+                }
+                ExportFormat::Csv => vec![export::ExportFile {
+                    name: export::CSV_FILE.to_string(),
+                    contents: export::to_csv(&notices, bom)?,
+                }],
+                ExportFormat::Json => vec![export::ExportFile {
+                    name: export::JSON_FILE.to_string(),
+                    contents: serde_json::to_string_pretty(&notices)?,
+                }],
+            };
+
+            std::fs::create_dir_all(&output_dir)?;
+            for file in &files {
+                std::fs::write(output_dir.join(&file.name), &file.contents)?;
+            }
+
+            log::info!(
+                "Exported {} notices to {} file(s) in {}",
+                notices.len(),
+                files.len(),
+                output_dir.display()
+            );
+        }
     }
 
     log::info!("Done!");
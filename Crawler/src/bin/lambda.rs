@@ -3,6 +3,7 @@
 //! Deploy with `cargo lambda build --release`
 //! Invoke with AWS Lambda using the generated binary.
 
+use crawler::AppError;
 use lambda_runtime::{Error as LambdaError, LambdaEvent, service_fn};
 
 use serde_json::Value;
@@ -38,9 +39,13 @@ async fn handler(event: LambdaEvent<Value>) -> Result<Value, LambdaError> {
         }
         Err(e) => {
             error!("Lambda execution failed: {}", e);
+            let error_code = e
+                .downcast_ref::<AppError>()
+                .map_or("INTERNAL", AppError::code);
             Ok(serde_json::json!({
                 "status": "error",
-                "message": e.to_string()
+                "message": e.to_string(),
+                "error_code": error_code
             }))
         }
     }
@@ -5,6 +5,7 @@
 //! including I/O errors, HTTP errors, parsing errors, and domain-specific errors.
 
 use std::fmt;
+use std::time::Duration;
 
 use thiserror::Error;
 
@@ -68,7 +69,12 @@ pub enum AppError {
 
     /// Upstream returned non-success HTTP status
     #[error("Upstream HTTP {status} for {url}")]
-    UpstreamHttp { url: String, status: u16 },
+    UpstreamHttp {
+        url: String,
+        status: u16,
+        /// Delay requested by a `Retry-After` header on a 429 or 503
+        retry_after: Option<Duration>,
+    },
 
     /// Upstream returned 304 Not Modified
     #[error("Upstream not modified for {url}")]
@@ -86,6 +92,22 @@ pub enum AppError {
         max_bytes: u64,
     },
 
+    /// Redirect rejected by the redirect policy
+    #[error("Redirect blocked for {url}: {reason}")]
+    RedirectBlocked { url: String, reason: String },
+
+    /// Upstream body could not be decoded for its content-encoding
+    #[error("Upstream content-encoding '{encoding}' could not be decoded for {url}")]
+    UpstreamUndecodable { url: String, encoding: String },
+
+    /// Upstream document has too many elements to parse safely
+    #[error("Upstream document too complex for {url}: ~{nodes} nodes > {max_nodes}")]
+    UpstreamTooComplex {
+        url: String,
+        nodes: usize,
+        max_nodes: usize,
+    },
+
     /// Circuit breaker triggered - data drop threshold exceeded
     #[error(
         "Circuit breaker triggered: {current_count} notices vs {previous_count} previous ({drop_percent:.1}% drop > {threshold_percent}% threshold)"
@@ -97,6 +119,17 @@ pub enum AppError {
         threshold_percent: u8,
     },
 
+    /// Circuit breaker triggered - notice count grew beyond the gain threshold
+    #[error(
+        "Circuit breaker spike: {current_count} notices vs {previous_count} previous ({gain_percent:.1}% gain > {threshold_percent}% threshold)"
+    )]
+    CircuitBreakerSpike {
+        current_count: usize,
+        previous_count: usize,
+        gain_percent: f64,
+        threshold_percent: u8,
+    },
+
     /// Empty crawl result
     #[error("Empty crawl result - no notices fetched")]
     EmptyCrawlResult,
@@ -135,6 +168,56 @@ impl AppError {
         }
     }
 
+    /// Stable machine-readable code for this error, for logs and API responses.
+    ///
+    /// Codes never change once published; new variants get new codes.
+    pub fn code(&self) -> &'static str {
+        match self {
+            AppError::S3(_) => "S3_WRITE",
+            AppError::Io(_) => "IO",
+            AppError::Http(e) if e.is_timeout() => "HTTP_TIMEOUT",
+            AppError::Http(_) => "HTTP",
+            AppError::Json(_) => "JSON",
+            AppError::Toml(_) => "TOML_PARSE",
+            AppError::TomlSerialize(_) => "TOML_SERIALIZE",
+            AppError::Url(_) => "URL_PARSE",
+            AppError::Selector { .. } => "SELECTOR_PARSE",
+            AppError::Config(_) => "CONFIG",
+            AppError::Validation(_) => "VALIDATION",
+            AppError::Discovery(_) => "DISCOVERY",
+            AppError::Crawl { .. } => "CRAWL",
+            AppError::LocalStorage(_) => "LOCAL_STORAGE",
+            AppError::UpstreamHttp { .. } => "UPSTREAM_HTTP",
+            AppError::UpstreamNotModified { .. } => "UPSTREAM_NOT_MODIFIED",
+            AppError::UpstreamUnexpectedContentType { .. } => "UPSTREAM_CONTENT_TYPE",
+            AppError::UpstreamBodyTooLarge { .. } => "UPSTREAM_BODY_TOO_LARGE",
+            AppError::RedirectBlocked { .. } => "REDIRECT_BLOCKED",
+            AppError::UpstreamUndecodable { .. } => "UPSTREAM_UNDECODABLE",
+            AppError::UpstreamTooComplex { .. } => "UPSTREAM_TOO_COMPLEX",
+            AppError::CircuitBreakerTriggered { .. } => "CIRCUIT_BREAKER",
+            AppError::CircuitBreakerSpike { .. } => "CIRCUIT_BREAKER_SPIKE",
+            AppError::EmptyCrawlResult => "EMPTY_CRAWL_RESULT",
+        }
+    }
+
+    /// HTTP status of the response that caused this error, if any.
+    pub fn http_status(&self) -> Option<u16> {
+        match self {
+            AppError::Http(e) => e.status().map(|s| s.as_u16()),
+            AppError::UpstreamHttp { status, .. } => Some(*status),
+            AppError::UpstreamNotModified { .. } => Some(304),
+            _ => None,
+        }
+    }
+
+    /// Delay the server asked for before retrying, if any.
+    pub fn retry_after(&self) -> Option<Duration> {
+        match self {
+            AppError::UpstreamHttp { retry_after, .. } => *retry_after,
+            _ => None,
+        }
+    }
+
     /// Check retriable error based on HTTP status code.
     pub fn is_retryable(&self) -> bool {
         match self {
@@ -153,3 +236,106 @@ impl AppError {
 pub type CrawlerError = AppError;
 #[allow(dead_code)]
 pub type MapperError = AppError;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_codes() {
+        let url = || "https://example.com".to_string();
+        let cases = [
+            (AppError::S3("put failed".into()), "S3_WRITE"),
+            (AppError::Io(std::io::Error::other("disk")), "IO"),
+            (
+                AppError::Json(serde_json::from_str::<u8>("x").unwrap_err()),
+                "JSON",
+            ),
+            (
+                AppError::Toml(toml::from_str::<u8>("=").unwrap_err()),
+                "TOML_PARSE",
+            ),
+            (
+                AppError::Url(url::Url::parse("not a url").unwrap_err()),
+                "URL_PARSE",
+            ),
+            (AppError::selector("tr[", "bad"), "SELECTOR_PARSE"),
+            (AppError::config("x"), "CONFIG"),
+            (AppError::validation("x"), "VALIDATION"),
+            (AppError::discovery("x"), "DISCOVERY"),
+            (AppError::crawl("board", "x"), "CRAWL"),
+            (AppError::LocalStorage("x".into()), "LOCAL_STORAGE"),
+            (
+                AppError::UpstreamHttp {
+                    url: url(),
+                    status: 500,
+                    retry_after: None,
+                },
+                "UPSTREAM_HTTP",
+            ),
+            (
+                AppError::UpstreamNotModified { url: url() },
+                "UPSTREAM_NOT_MODIFIED",
+            ),
+            (
+                AppError::UpstreamUnexpectedContentType {
+                    url: url(),
+                    content_type: "image/png".into(),
+                },
+                "UPSTREAM_CONTENT_TYPE",
+            ),
+            (
+                AppError::UpstreamBodyTooLarge {
+                    url: url(),
+                    bytes: 2,
+                    max_bytes: 1,
+                },
+                "UPSTREAM_BODY_TOO_LARGE",
+            ),
+            (
+                AppError::RedirectBlocked {
+                    url: url(),
+                    reason: "x".into(),
+                },
+                "REDIRECT_BLOCKED",
+            ),
+            (
+                AppError::UpstreamUndecodable {
+                    url: url(),
+                    encoding: "br".into(),
+                },
+                "UPSTREAM_UNDECODABLE",
+            ),
+            (
+                AppError::UpstreamTooComplex {
+                    url: url(),
+                    nodes: 2,
+                    max_nodes: 1,
+                },
+                "UPSTREAM_TOO_COMPLEX",
+            ),
+            (
+                AppError::CircuitBreakerTriggered {
+                    current_count: 1,
+                    previous_count: 10,
+                    drop_percent: 90.0,
+                    threshold_percent: 50,
+                },
+                "CIRCUIT_BREAKER",
+            ),
+            (
+                AppError::CircuitBreakerSpike {
+                    current_count: 10,
+                    previous_count: 1,
+                    gain_percent: 900.0,
+                    threshold_percent: 50,
+                },
+                "CIRCUIT_BREAKER_SPIKE",
+            ),
+            (AppError::EmptyCrawlResult, "EMPTY_CRAWL_RESULT"),
+        ];
+        for (error, code) in cases {
+            assert_eq!(error.code(), code, "{}", error);
+        }
+    }
+}
@@ -31,8 +31,9 @@ pub use error::{AppError, Result};
 // Re-export pipeline components
 pub use pipeline::{
     CircuitBreaker, CircuitBreakerConfig, CircuitBreakerResult, DiffCalculator, DiffResult,
-    IndexBuilder, IndexConfig, InvertedIndex, build_index, calculate_diff,
+    IndexBuilder, IndexConfig, IndexManifest, InvertedIndex, TokenizeMode, build_index,
+    calculate_diff,
 };
 
 // Re-export storage components
-pub use storage::{LocalStorage, NoticeStorage, WriteMetadata, WriteOptions};
+pub use storage::{ByteReader, LocalStorage, NoticeStorage, WriteMetadata, WriteOptions};
@@ -1,5 +1,6 @@
 //! Campus, College, Department, and Board data structures.
 
+use std::collections::BTreeMap;
 use std::fs;
 use std::path::Path;
 
@@ -36,9 +37,16 @@ pub struct Campus {
     /// Departments directly under campus (without college)
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub departments: Vec<Department>,
+
+    /// Listing page the departments were discovered from
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_url: Option<String>,
 }
 
 impl Campus {
+    /// Storage key of the sitemap, relative to the storage root.
+    pub const STORAGE_KEY: &'static str = "siteMap.json";
+
     /// Load campus configurations from a JSON file.
     pub fn load_all(path: impl AsRef<Path>) -> Result<Vec<Self>> {
         let content = fs::read_to_string(path)?;
@@ -46,32 +54,64 @@ impl Campus {
     }
 
     /// Get all departments with their hierarchical context.
+    ///
+    /// Departments are yielded in source order: college departments first,
+    /// then those directly under the campus. `campus_idx` is 0; use
+    /// [`Campus::departments_of`] to index across several campuses.
     pub fn all_departments(&self) -> Vec<DepartmentRef<'_>> {
+        self.departments_at(0)
+    }
+
+    /// Get all departments of `campuses` in source order, with positions.
+    pub fn departments_of(campuses: &[Campus]) -> Vec<DepartmentRef<'_>> {
+        campuses
+            .iter()
+            .enumerate()
+            .flat_map(|(campus_idx, campus)| campus.departments_at(campus_idx))
+            .collect()
+    }
+
+    fn departments_at(&self, campus_idx: usize) -> Vec<DepartmentRef<'_>> {
         let mut result = Vec::new();
 
         // Departments within colleges
-        for college in &self.colleges {
-            for dept in &college.departments {
+        for (college_idx, college) in self.colleges.iter().enumerate() {
+            for (dept_idx, dept) in college.departments.iter().enumerate() {
                 result.push(DepartmentRef {
                     campus: &self.campus,
                     college: Some(&college.name),
                     dept,
+                    campus_idx,
+                    college_idx: Some(college_idx),
+                    dept_idx,
                 });
             }
         }
 
         // Departments directly under campus
-        for dept in &self.departments {
+        for (dept_idx, dept) in self.departments.iter().enumerate() {
             result.push(DepartmentRef {
                 campus: &self.campus,
                 college: None,
                 dept,
+                campus_idx,
+                college_idx: None,
+                dept_idx,
             });
         }
 
         result
     }
 
+    /// Find a department by name, whether under a college or the campus.
+    pub fn department_mut(&mut self, name: &str) -> Option<&mut Department> {
+        self.colleges
+            .iter_mut()
+            .flat_map(|c| &mut c.departments)
+            .chain(&mut self.departments)
+            .find(|d| d.name == name)
+    }
+
     /// Count total departments in this campus.
     pub fn department_count(&self) -> usize {
         self.colleges
@@ -81,6 +121,31 @@ impl Campus {
             + self.departments.len()
     }
 
+    /// Enable or disable boards matching `board_id` (optionally within `dept_id`).
+    ///
+    /// Returns the number of boards matched.
+    pub fn set_board_enabled(
+        &mut self,
+        dept_id: Option<&str>,
+        board_id: &str,
+        enabled: bool,
+    ) -> usize {
+        let mut matched = 0;
+        for dept in self
+            .colleges
+            .iter_mut()
+            .flat_map(|c| &mut c.departments)
+            .chain(&mut self.departments)
+            .filter(|d| dept_id.is_none_or(|id| d.id == id))
+        {
+            for board in dept.boards.iter_mut().filter(|b| b.id == board_id) {
+                board.enabled = enabled;
+                matched += 1;
+            }
+        }
+        matched
+    }
+
     /// Count total boards in this campus.
     pub fn board_count(&self) -> usize {
         self.colleges
@@ -98,6 +163,23 @@ pub struct DepartmentRef<'a> {
     pub campus: &'a str,
     pub college: Option<&'a str>,
     pub dept: &'a Department,
+    /// Position of the campus in the source list
+    pub campus_idx: usize,
+    /// Position of the college in its campus (`None` for campus-level departments)
+    pub college_idx: Option<usize>,
+    /// Position of the department in its college or campus
+    pub dept_idx: usize,
+}
+
+impl DepartmentRef<'_> {
+    /// Sort key matching source order (campus-level departments after colleges).
+    pub fn position(&self) -> (usize, usize, usize) {
+        (
+            self.campus_idx,
+            self.college_idx.unwrap_or(usize::MAX),
+            self.dept_idx,
+        )
+    }
 }
 
 /// A college containing multiple departments.
@@ -139,6 +221,86 @@ pub struct Board {
     /// CSS selectors for scraping
     #[serde(flatten)]
     pub selectors: CmsSelectors,
+
+    /// Selector for the element holding the notice list; only this element
+    /// is parsed for rows and pagination (default: the whole document).
+    /// It is cut out of the page before parsing, so it must be a simple
+    /// `tag`, `#id` or `.class` selector
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_root_selector: Option<String>,
+
+    /// Base URL for resolving relative notice links (default: the board URL)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub link_base: Option<String>,
+
+    /// Whether the board is crawled (disabled boards are kept in the sitemap)
+    #[serde(default = "default_true", skip_serializing_if = "is_true")]
+    pub enabled: bool,
+
+    /// Extra request settings for boards gated on headers or a session cookie
+    #[serde(default, skip_serializing_if = "FetchConfig::is_empty")]
+    pub fetch: FetchConfig,
+
+    /// How the board's list is read
+    #[serde(default, skip_serializing_if = "BoardSource::is_html")]
+    pub source: BoardSource,
+}
+
+/// Format of a board's list page.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BoardSource {
+    /// HTML list scraped with the board's CSS selectors
+    #[default]
+    Html,
+    /// RSS or Atom feed; items are read from the feed XML
+    Rss,
+}
+
+impl BoardSource {
+    fn is_html(&self) -> bool {
+        *self == Self::Html
+    }
+}
+
+/// Per-board request customization.
+///
+/// Board headers are set on each list-page and feed request and override
+/// the client's defaults of the same name, including `User-Agent`.
+/// `cookies` seed the crawl client's cookie store for the board's host, so
+/// session cookies the server sets afterwards are sent back as well. A
+/// `Cookie` entry in `headers` bypasses the store for that board.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FetchConfig {
+    /// Headers added to every list-page and feed request (e.g., `Referer`)
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub headers: BTreeMap<String, String>,
+
+    /// Initial cookies for the board's host (e.g., `"JSESSIONID=abc; lang=ko"`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cookies: Option<String>,
+}
+
+impl FetchConfig {
+    /// Whether no headers or cookies are configured.
+    pub fn is_empty(&self) -> bool {
+        self.headers.is_empty() && self.cookies.is_none()
+    }
+}
+
+impl Board {
+    /// Base URL that relative notice links resolve against.
+    pub fn link_base_url(&self) -> &str {
+        self.link_base.as_deref().unwrap_or(&self.url)
+    }
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn is_true(value: &bool) -> bool {
+    *value
 }
 
 #[cfg(test)]
@@ -158,6 +320,7 @@ mod tests {
                 }],
             }],
             departments: vec![],
+            source_url: None,
         }
     }
 
@@ -169,6 +332,93 @@ mod tests {
         assert_eq!(deps[0].college, Some("TestCollege"));
     }
 
+    #[test]
+    fn test_departments_of_yield_source_order_with_indices() {
+        let dept = |name: &str| Department {
+            id: name.to_string(),
+            name: name.to_string(),
+            url: "https://example.com".to_string(),
+            boards: vec![],
+        };
+        let mut second = create_test_campus();
+        second.campus = "SecondCampus".to_string();
+        second.colleges.push(College {
+            name: "OtherCollege".to_string(),
+            departments: vec![dept("c1"), dept("c2")],
+        });
+        second.departments.push(dept("direct"));
+        let campuses = [create_test_campus(), second];
+
+        let deps = Campus::departments_of(&campuses);
+        let positions: Vec<_> = deps
+            .iter()
+            .map(|d| {
+                (
+                    d.dept.name.as_str(),
+                    d.campus_idx,
+                    d.college_idx,
+                    d.dept_idx,
+                )
+            })
+            .collect();
+        assert_eq!(
+            positions,
+            [
+                ("Department 1", 0, Some(0), 0),
+                ("Department 1", 1, Some(0), 0),
+                ("c1", 1, Some(1), 0),
+                ("c2", 1, Some(1), 1),
+                ("direct", 1, None, 0),
+            ]
+        );
+
+        let mut sorted = deps.clone();
+        sorted.reverse();
+        sorted.sort_by_key(|d| d.position());
+        let names: Vec<_> = sorted.iter().map(|d| d.dept.name.as_str()).collect();
+        assert_eq!(
+            names,
+            ["Department 1", "Department 1", "c1", "c2", "direct"]
+        );
+    }
+
+    #[test]
+    fn test_set_board_enabled() {
+        let mut campus = create_test_campus();
+        campus.colleges[0].departments[0].boards.push(Board {
+            id: "notice".to_string(),
+            name: "공지사항".to_string(),
+            url: "https://example.com/notice".to_string(),
+            selectors: CmsSelectors::default(),
+            content_root_selector: None,
+            link_base: None,
+            enabled: true,
+            fetch: FetchConfig::default(),
+            source: BoardSource::Html,
+        });
+
+        assert_eq!(campus.set_board_enabled(Some("other"), "notice", false), 0);
+        assert_eq!(campus.set_board_enabled(None, "notice", false), 1);
+        assert!(!campus.colleges[0].departments[0].boards[0].enabled);
+    }
+
+    #[test]
+    fn test_board_enabled_defaults_to_true() {
+        let board: Board = serde_json::from_str(
+            r#"{
+                "id": "notice",
+                "name": "공지",
+                "url": "https://example.com",
+                "row_selector": "tr",
+                "title_selector": "a",
+                "date_selector": ".date"
+            }"#,
+        )
+        .unwrap();
+        assert!(board.enabled);
+        assert!(!serde_json::to_string(&board).unwrap().contains("enabled"));
+    }
+
     #[test]
     fn test_department_count() {
         let campus = create_test_campus();
@@ -1,11 +1,15 @@
 //! Application configuration structures.
 
 use std::fs;
+use std::io::Read;
 use std::path::Path;
+use std::time::Duration;
 
 use serde::{Deserialize, Serialize};
 
 use crate::error::{AppError, Result};
+use crate::models::IdOptions;
+use crate::utils::normalize_text;
 
 /// Root application configuration.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -22,6 +26,14 @@ pub struct Config {
     #[serde(default)]
     pub cleaning: CleaningConfig,
 
+    /// Thresholds guarding snapshot writes against abnormal drops
+    #[serde(default)]
+    pub circuit_breaker: CircuitBreakerConfig,
+
+    /// Search index generation
+    #[serde(default)]
+    pub index: IndexConfig,
+
     /// Campus definitions
     #[serde(default)]
     pub campuses: Vec<CampusInfo>,
@@ -36,10 +48,35 @@ pub struct Config {
 }
 
 impl Config {
+    /// Storage key of the configuration seed, relative to the storage root.
+    pub const STORAGE_KEY: &'static str = "config.toml";
+
     /// Load configuration from a TOML file.
     pub fn load(path: impl AsRef<Path>) -> Result<Self> {
         let content = fs::read_to_string(path)?;
-        Ok(toml::from_str(&content)?)
+        Self::parse(&content)
+    }
+
+    /// Parse configuration from raw TOML bytes.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let content = std::str::from_utf8(bytes)
+            .map_err(|e| AppError::config(format!("Config is not valid UTF-8: {e}")))?;
+        Self::parse(content)
+    }
+
+    /// Parse TOML, rejecting settings that would fail at run time rather
+    /// than in [`Config::validate`].
+    fn parse(content: &str) -> Result<Self> {
+        let config: Self = toml::from_str(content)?;
+        config.crawler.request_interval()?;
+        Ok(config)
+    }
+
+    /// Parse configuration from any reader yielding TOML.
+    pub fn from_reader(mut reader: impl Read) -> Result<Self> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes)?;
+        Self::from_bytes(&bytes)
     }
 
     /// Load configuration or return default if loading fails.
@@ -70,6 +107,17 @@ impl Config {
         if self.crawler.max_concurrent == 0 {
             return Err(AppError::validation("crawler.max_concurrent must be > 0"));
         }
+        self.crawler.request_interval()?;
+        if self.crawler.max_body_bytes == 0 || self.crawler.max_html_nodes == 0 {
+            return Err(AppError::validation(
+                "crawler.max_body_bytes and crawler.max_html_nodes must be > 0",
+            ));
+        }
+        if self.circuit_breaker.max_drop_percent > 100 {
+            return Err(AppError::validation(
+                "circuit_breaker.max_drop_percent must be <= 100",
+            ));
+        }
         if self.discovery.max_board_name_length == 0 {
             return Err(AppError::validation(
                 "discovery.max_board_name_length must be > 0",
@@ -91,6 +139,8 @@ impl Default for Config {
             crawler: CrawlerConfig::default(),
             discovery: DiscoveryConfig::default(),
             cleaning: CleaningConfig::default(),
+            circuit_breaker: CircuitBreakerConfig::default(),
+            index: IndexConfig::default(),
             campuses: defaults::default_campuses(),
             keywords: defaults::default_keywords(),
             cms_patterns: defaults::default_cms_patterns(),
@@ -120,6 +170,205 @@ pub struct CrawlerConfig {
     /// Maximum concurrent requests
     #[serde(default = "defaults::max_concurrent")]
     pub max_concurrent: usize,
+
+    /// Maximum response body size in bytes before a page is rejected
+    #[serde(default = "defaults::max_body_bytes")]
+    pub max_body_bytes: u64,
+
+    /// Maximum estimated HTML element count before a page is rejected
+    #[serde(default = "defaults::max_html_nodes")]
+    pub max_html_nodes: usize,
+
+    /// Ramp per-domain concurrency up from 1 to `max_concurrent` (slow start)
+    #[serde(default)]
+    pub slow_start: bool,
+
+    /// Maximum redirects followed per request
+    #[serde(default = "defaults::max_redirects")]
+    pub max_redirects: usize,
+
+    /// Domains redirects may lead to (subdomains included); empty allows any
+    #[serde(default)]
+    pub allowed_domains: Vec<String>,
+
+    /// Pinned notices kept per board, newest first; 0 keeps all
+    #[serde(default)]
+    pub max_pinned_per_board: usize,
+
+    /// Drop pinned notices beyond the limit instead of demoting them
+    #[serde(default)]
+    pub drop_excess_pinned: bool,
+
+    /// Requests per second allowed per domain; replaces `request_delay_ms` when set
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub per_domain_rps: Option<f64>,
+
+    /// Skip board lists disallowed by the host's robots.txt
+    #[serde(default = "defaults::respect_robots")]
+    pub respect_robots: bool,
+
+    /// Notices kept per run after dedup and age filtering, shared evenly
+    /// across boards; 0 keeps all
+    #[serde(default)]
+    pub max_total_notices: usize,
+
+    /// Scope of notice deduplication by canonical ID
+    #[serde(default)]
+    pub dedup_scope: DedupScope,
+
+    /// Collapse a notice listed on several boards of one department into a
+    /// single entry (same title and date, or same source ID plus title or date)
+    #[serde(default)]
+    pub dedup_cross_board: bool,
+
+    /// Drop notices dated more than this many days ago; undated or
+    /// unparseable dates are always kept. Pinned notices get no exemption.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_age_days: Option<u32>,
+
+    /// Tag each notice with the script of its title (`ko`, `en`, `mixed`)
+    #[serde(default)]
+    pub detect_language: bool,
+
+    /// Include the college in notice IDs, so departments sharing a generated
+    /// ID in different colleges do not collide. Changes every existing ID
+    #[serde(default)]
+    pub id_includes_college: bool,
+
+    /// Log each request and response (headers redacted) at debug level
+    #[serde(default)]
+    pub debug_http: bool,
+
+    /// Revalidate board lists with `If-None-Match` / `If-Modified-Since`
+    /// and reuse the cached notices on `304 Not Modified`
+    #[serde(default)]
+    pub conditional_requests: bool,
+
+    /// Route all crawl traffic through this proxy (e.g., `http://proxy:3128`)
+    #[serde(default)]
+    pub proxy_url: Option<String>,
+
+    /// Domains that bypass `proxy_url`, in `NO_PROXY` syntax
+    /// (`example.com` also matches its subdomains)
+    #[serde(default)]
+    pub no_proxy: Vec<String>,
+
+    /// Extra attempts for each list page or feed request after a retryable
+    /// failure (timeout, 5xx, 429); 0 disables retries
+    #[serde(default = "defaults::board_max_retries")]
+    pub board_max_retries: u32,
+
+    /// Backoff before the first retry, doubled for each further attempt and
+    /// raised to any `Retry-After` the server sends
+    #[serde(default = "defaults::board_retry_base_ms")]
+    pub board_retry_base_ms: u64,
+
+    /// Canonicalize links before hashing notice IDs, so the same notice
+    /// linked with reordered, tracking or session parameters gets one ID.
+    /// Changes the ID of such notices once when turned on.
+    #[serde(default)]
+    pub canonicalize_links: bool,
+
+    /// Query parameters (e.g., session tokens) ignored when identifying a
+    /// notice by its link, in addition to `utm_*` and common session IDs;
+    /// only used with `canonicalize_links`
+    #[serde(default)]
+    pub ignored_query_params: Vec<String>,
+
+    /// Which query parameters identify a notice (its `source_id`)
+    #[serde(default)]
+    pub id_extraction: IdExtractionConfig,
+
+    /// Board success rate (0.0-1.0) below which a campus is reported
+    /// unhealthy in `health.json`
+    #[serde(default = "defaults::health_min_success_rate")]
+    pub health_min_success_rate: f32,
+}
+
+/// Scope in which notices with the same canonical ID are collapsed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DedupScope {
+    /// Across all boards (boards sharing an ID in a department collapse together)
+    #[default]
+    Global,
+    /// Within each board URL only
+    PerBoard,
+    /// Keep every parsed row (for debugging)
+    None,
+}
+
+/// Query parameters consulted when extracting a notice's source ID from its
+/// link (see [`crate::utils::extract_notice_id_with`]).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IdExtractionConfig {
+    /// Keys holding a notice ID; the leftmost one in the URL with a numeric
+    /// value wins
+    #[serde(default = "defaults::id_preferred_keys")]
+    pub preferred_keys: Vec<String>,
+
+    /// Try `preferred_keys` in their configured order instead of taking the
+    /// leftmost match in the URL
+    #[serde(default)]
+    pub prefer_key_order: bool,
+
+    /// Keys never used as an ID, such as pagination parameters
+    #[serde(default = "defaults::id_ignore_keys")]
+    pub ignore_keys: Vec<String>,
+
+    /// Fall back to the first other numeric query parameter not in
+    /// `ignore_keys` when neither a preferred key nor a `/view/123` style
+    /// path matches
+    #[serde(default)]
+    pub numeric_fallback: bool,
+}
+
+impl Default for IdExtractionConfig {
+    fn default() -> Self {
+        Self {
+            preferred_keys: defaults::id_preferred_keys(),
+            prefer_key_order: false,
+            ignore_keys: defaults::id_ignore_keys(),
+            numeric_fallback: false,
+        }
+    }
+}
+
+impl CrawlerConfig {
+    /// Extra parameters to drop when canonicalizing links for notice IDs, or
+    /// `None` when links are hashed as is (see [`Notice::canonical_id_with`]).
+    ///
+    /// [`Notice::canonical_id_with`]: crate::models::Notice::canonical_id_with
+    pub fn link_id_params(&self) -> Option<&[String]> {
+        self.canonicalize_links
+            .then_some(self.ignored_query_params.as_slice())
+    }
+
+    /// How notice IDs are built under this config: `link_id_params` and
+    /// `id_includes_college`.
+    pub fn id_options(&self) -> IdOptions<'_> {
+        IdOptions {
+            link_params: self.link_id_params(),
+            include_college: self.id_includes_college,
+        }
+    }
+
+    /// Interval between requests to one domain under `per_domain_rps`.
+    ///
+    /// Fails unless the rate is a finite number of at least one request a
+    /// day.
+    pub fn request_interval(&self) -> Result<Option<Duration>> {
+        const MIN_RPS: f64 = 1.0 / 86_400.0;
+        match self.per_domain_rps {
+            None => Ok(None),
+            Some(rps) if rps.is_finite() && rps >= MIN_RPS => {
+                Ok(Some(Duration::from_secs_f64(rps.recip())))
+            }
+            Some(rps) => Err(AppError::validation(format!(
+                "crawler.per_domain_rps must be at least one request a day ({MIN_RPS:e}), got {rps}"
+            ))),
+        }
+    }
 }
 
 impl Default for CrawlerConfig {
@@ -130,6 +379,31 @@ impl Default for CrawlerConfig {
             sitemap_timeout_secs: defaults::sitemap_timeout(),
             request_delay_ms: defaults::request_delay(),
             max_concurrent: defaults::max_concurrent(),
+            max_body_bytes: defaults::max_body_bytes(),
+            max_html_nodes: defaults::max_html_nodes(),
+            slow_start: false,
+            max_redirects: defaults::max_redirects(),
+            allowed_domains: Vec::new(),
+            max_pinned_per_board: 0,
+            drop_excess_pinned: false,
+            per_domain_rps: None,
+            respect_robots: defaults::respect_robots(),
+            max_total_notices: 0,
+            dedup_scope: DedupScope::Global,
+            dedup_cross_board: false,
+            max_age_days: None,
+            detect_language: false,
+            id_includes_college: false,
+            debug_http: false,
+            conditional_requests: false,
+            proxy_url: None,
+            no_proxy: Vec::new(),
+            board_max_retries: defaults::board_max_retries(),
+            board_retry_base_ms: defaults::board_retry_base_ms(),
+            canonicalize_links: false,
+            ignored_query_params: Vec::new(),
+            id_extraction: IdExtractionConfig::default(),
+            health_min_success_rate: defaults::health_min_success_rate(),
         }
     }
 }
@@ -144,6 +418,38 @@ pub struct DiscoveryConfig {
     /// URL patterns to exclude from board discovery
     #[serde(default = "defaults::blacklist_patterns")]
     pub blacklist_patterns: Vec<String>,
+
+    /// Probe `/sitemap.xml` (then `/sitemap.xml.gz`) for board-list URLs
+    #[serde(default)]
+    pub probe_xml_sitemap: bool,
+
+    /// Maximum sitemap index nesting followed below the root sitemap
+    #[serde(default = "defaults::max_sitemap_depth")]
+    pub max_sitemap_depth: usize,
+
+    /// Maximum sitemap files fetched per department
+    #[serde(default = "defaults::max_sitemap_fetches")]
+    pub max_sitemap_fetches: usize,
+
+    /// Selectors for college/department headings on campus pages, tried in
+    /// order until one yields departments
+    #[serde(default = "defaults::heading_selectors")]
+    pub heading_selectors: Vec<String>,
+
+    /// Selectors for the main content area of campus pages, tried in order;
+    /// the first that matches is scanned for headings
+    #[serde(default = "defaults::main_content_selectors")]
+    pub main_content_selectors: Vec<String>,
+
+    /// Prefix of generated department IDs (`{prefix}_{subdomain}`); empty
+    /// for none
+    #[serde(default = "defaults::id_prefix")]
+    pub id_prefix: String,
+
+    /// Scan departments of all campuses in one concurrent pass instead of
+    /// campus by campus
+    #[serde(default)]
+    pub concurrent_campuses: bool,
 }
 
 impl Default for DiscoveryConfig {
@@ -151,12 +457,19 @@ impl Default for DiscoveryConfig {
         Self {
             max_board_name_length: defaults::max_board_name_length(),
             blacklist_patterns: defaults::blacklist_patterns(),
+            probe_xml_sitemap: false,
+            max_sitemap_depth: defaults::max_sitemap_depth(),
+            max_sitemap_fetches: defaults::max_sitemap_fetches(),
+            heading_selectors: defaults::heading_selectors(),
+            main_content_selectors: defaults::main_content_selectors(),
+            id_prefix: defaults::id_prefix(),
+            concurrent_campuses: false,
         }
     }
 }
 
 /// Text cleaning/preprocessing settings.
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CleaningConfig {
     /// Patterns to remove from titles
     #[serde(default)]
@@ -169,6 +482,27 @@ pub struct CleaningConfig {
     /// Text replacements to apply to dates
     #[serde(default)]
     pub date_replacements: Vec<Replacement>,
+
+    /// Remove a leading board name (e.g. "학사공지] ") from titles
+    #[serde(default)]
+    pub strip_board_prefix: bool,
+
+    /// Field labels (e.g. "작성자", "등록일") removed, with a following colon,
+    /// from author, date and view-count text (see [`Self::strip_labels`])
+    #[serde(default = "defaults::field_labels")]
+    pub field_labels: Vec<String>,
+}
+
+impl Default for CleaningConfig {
+    fn default() -> Self {
+        Self {
+            title_remove_patterns: Vec::new(),
+            date_remove_patterns: Vec::new(),
+            date_replacements: Vec::new(),
+            strip_board_prefix: false,
+            field_labels: defaults::field_labels(),
+        }
+    }
 }
 
 impl CleaningConfig {
@@ -187,16 +521,87 @@ impl CleaningConfig {
         result.trim().to_string()
     }
 
-    /// Clean a title string.
-    pub fn clean_title(&self, text: &str) -> String {
+    /// Clean a title string from a board named `board_name`.
+    pub fn clean_title(&self, text: &str, board_name: &str) -> String {
+        let text = Self::normalize_whitespace(text);
+        let text = if self.strip_board_prefix {
+            Self::strip_prefix_name(&text, board_name)
+        } else {
+            &text
+        };
         self.clean(text, &self.title_remove_patterns, &[])
     }
 
+    /// Strip `name` at the start of `title`, with optional surrounding
+    /// brackets and a trailing separator. Titles that would become empty are
+    /// left untouched.
+    fn strip_prefix_name<'a>(title: &'a str, name: &str) -> &'a str {
+        let name = name.trim();
+        if name.is_empty() {
+            return title;
+        }
+
+        let rest = title.strip_prefix(['[', '(', '【']).unwrap_or(title);
+        let Some(rest) = rest.strip_prefix(name) else {
+            return title;
+        };
+        let rest = rest
+            .trim_start_matches([']', ')', '】', ':', '-', '|'])
+            .trim_start();
+
+        if rest.is_empty() { title } else { rest }
+    }
+
     /// Clean a date string.
     pub fn clean_date(&self, text: &str) -> String {
         self.clean(text, &self.date_remove_patterns, &self.date_replacements)
     }
 
+    /// Remove `field_labels` (longest first) used as labels: at the start of
+    /// the text, or anywhere at a word start when followed by a colon
+    /// ("작성자: 학사팀 | 등록일: 2024-01-05"). A colon after a label is
+    /// removed with it; the same words inside other text are kept.
+    pub fn strip_labels(&self, text: &str) -> String {
+        let mut labels: Vec<&str> = self
+            .field_labels
+            .iter()
+            .map(String::as_str)
+            .filter(|l| !l.is_empty())
+            .collect();
+        labels.sort_by_key(|label| std::cmp::Reverse(label.chars().count()));
+
+        let text = Self::normalize_whitespace(text);
+        let mut result = String::with_capacity(text.len());
+        let mut rest = text.as_str();
+        while let Some(c) = rest.chars().next() {
+            let at_start = rest.len() == text.len();
+            let word_start = result
+                .chars()
+                .next_back()
+                .is_none_or(|c| !c.is_alphanumeric());
+            let value = labels.iter().filter(|_| word_start).find_map(|label| {
+                let after = rest.strip_prefix(label)?;
+                let trimmed = after.trim_start();
+                if let Some(value) = trimmed.strip_prefix([':', '：']) {
+                    return Some(value.trim_start());
+                }
+                let bare = after
+                    .chars()
+                    .next()
+                    .is_none_or(|c| c.is_whitespace() || c.is_ascii_digit());
+                (at_start && bare).then_some(trimmed)
+            });
+            match value {
+                Some(value) => rest = value,
+                None => {
+                    result.push(c);
+                    rest = &rest[c.len_utf8()..];
+                }
+            }
+        }
+        Self::normalize_whitespace(&result)
+    }
+
     fn normalize_whitespace(s: &str) -> String {
         s.split_whitespace().collect::<Vec<_>>().join(" ")
     }
@@ -209,6 +614,114 @@ pub struct Replacement {
     pub to: String,
 }
 
+/// Circuit breaker configuration (`[circuit_breaker]` in config.toml).
+///
+/// Omitted fields take their defaults.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CircuitBreakerConfig {
+    /// Maximum allowed drop percentage (0-100). Default: 20%
+    pub max_drop_percent: u8,
+    /// Maximum allowed gain percentage, catching selectors that suddenly
+    /// match far too much. Default: unchecked
+    pub max_gain_percent: Option<u8>,
+    /// Minimum notice count to trigger circuit breaker check.
+    /// Below this threshold, the check is skipped (for new deployments).
+    pub min_baseline: usize,
+    /// Allow empty results when previous was also empty
+    pub allow_cold_start: bool,
+    /// Consecutive empty runs tolerated before tripping `EmptyResult`.
+    /// Default: 0 (trip on the first empty run)
+    pub empty_grace_runs: u8,
+    /// Whether thresholds are also applied per campus. Default: off
+    pub per_campus: PerCampusMode,
+}
+
+impl Default for CircuitBreakerConfig {
+    fn default() -> Self {
+        Self {
+            max_drop_percent: 20,
+            max_gain_percent: None,
+            min_baseline: 10,
+            allow_cold_start: true,
+            empty_grace_runs: 0,
+            per_campus: PerCampusMode::Off,
+        }
+    }
+}
+
+/// How per-campus checks combine into the write decision.
+///
+/// In every mode but `Off`, a campus that comes back empty within
+/// `empty_grace_runs` keeps its stored notices for that run.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PerCampusMode {
+    /// Only the global count is checked
+    #[default]
+    Off,
+    /// A campus that trips keeps its stored notices; the others are written
+    Any,
+    /// Abort the write only when every campus trips
+    All,
+}
+
+/// Configuration for index generation (`[index]` in `config.toml`).
+///
+/// Omitted fields take their defaults.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct IndexConfig {
+    /// Write `index.json` with each snapshot (default: true); when disabled,
+    /// an index left by earlier runs is removed
+    pub enabled: bool,
+    /// Minimum token length to include (default: 2)
+    pub min_token_length: usize,
+    /// Maximum tokens per notice (default: 50)
+    pub max_tokens_per_notice: usize,
+    /// Include metadata fields in indexing (campus, department, board)
+    pub index_metadata: bool,
+    /// How text is split into tokens
+    pub tokenize: TokenizeMode,
+    /// Store per-notice token counts for BM25 length normalization
+    pub store_ranking_stats: bool,
+    /// Split the index into this many shards instead of one `index.json`
+    /// (0 disables sharding, the default)
+    pub shards: usize,
+}
+
+impl Default for IndexConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            min_token_length: 2,
+            max_tokens_per_notice: 50,
+            index_metadata: true,
+            tokenize: TokenizeMode::UnicodeWords,
+            store_ranking_stats: false,
+            shards: 0,
+        }
+    }
+}
+
+/// How text is split into index tokens.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "mode", rename_all = "snake_case")]
+pub enum TokenizeMode {
+    /// Unicode word boundaries; a Korean compound such as "장학금신청" is one token
+    #[default]
+    UnicodeWords,
+    /// Overlapping `n`-character grams for CJK runs, so substrings of a
+    /// compound match; other text is still split into words
+    CharNgram { n: usize },
+}
+
+impl TokenizeMode {
+    pub(crate) fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
 /// Error message strings.
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 #[allow(dead_code)]
@@ -244,6 +757,9 @@ mod defaults {
     pub fn user_agent() -> String {
         "Mozilla/5.0 (compatible; uRing/1.0)".into()
     }
+    pub fn respect_robots() -> bool {
+        true
+    }
     pub fn timeout() -> u64 {
         30
     }
@@ -256,11 +772,64 @@ mod defaults {
     pub fn max_concurrent() -> usize {
         5
     }
+    pub fn max_body_bytes() -> u64 {
+        2_000_000
+    }
+    pub fn max_html_nodes() -> usize {
+        100_000
+    }
+    pub fn max_redirects() -> usize {
+        5
+    }
+
+    // Cleaning defaults
+    pub fn field_labels() -> Vec<String> {
+        ["작성자", "글쓴이", "등록일", "작성일", "조회수", "조회"]
+            .into_iter()
+            .map(String::from)
+            .collect()
+    }
+    pub fn id_preferred_keys() -> Vec<String> {
+        ["id", "seq", "no", "idx", "article_seq", "articleNo"]
+            .into_iter()
+            .map(String::from)
+            .collect()
+    }
+    pub fn id_ignore_keys() -> Vec<String> {
+        ["page", "pageIndex", "offset", "limit"]
+            .into_iter()
+            .map(String::from)
+            .collect()
+    }
+    pub fn health_min_success_rate() -> f32 {
+        crate::models::HealthReport::DEFAULT_MIN_SUCCESS_RATE
+    }
+    pub fn board_max_retries() -> u32 {
+        2
+    }
+    pub fn board_retry_base_ms() -> u64 {
+        500
+    }
 
     // Discovery defaults
     pub fn max_board_name_length() -> usize {
         20
     }
+    pub fn max_sitemap_depth() -> usize {
+        2
+    }
+    pub fn max_sitemap_fetches() -> usize {
+        10
+    }
+    pub fn heading_selectors() -> Vec<String> {
+        vec!["h1".into(), "h2".into(), "h3".into()]
+    }
+    pub fn main_content_selectors() -> Vec<String> {
+        vec!["main".into()]
+    }
+    pub fn id_prefix() -> String {
+        "yonsei".into()
+    }
     pub fn blacklist_patterns() -> Vec<String> {
         vec![
             "articleNo".into(),
@@ -278,10 +847,12 @@ mod defaults {
             CampusInfo {
                 name: "신촌캠퍼스".to_string(),
                 url: "https://www.yonsei.ac.kr/sc/186/subview.do".to_string(),
+                fallback_urls: Vec::new(),
             },
             CampusInfo {
                 name: "미래캠퍼스".to_string(),
                 url: "https://mirae.yonsei.ac.kr/wj/1413/subview.do".to_string(),
+                fallback_urls: Vec::new(),
             },
         ]
     }
@@ -293,51 +864,61 @@ mod defaults {
                 keyword: "학부공지".to_string(),
                 id: "academic".to_string(),
                 display_name: "학사공지".to_string(),
+                aliases: Vec::new(),
             },
             KeywordMapping {
                 keyword: "학사공지".to_string(),
                 id: "academic".to_string(),
                 display_name: "학사공지".to_string(),
+                aliases: Vec::new(),
             },
             KeywordMapping {
                 keyword: "대학원공지".to_string(),
                 id: "grad_notice".to_string(),
                 display_name: "대학원공지".to_string(),
+                aliases: Vec::new(),
             },
             KeywordMapping {
                 keyword: "장학".to_string(),
                 id: "scholarship".to_string(),
                 display_name: "장학공지".to_string(),
+                aliases: Vec::new(),
             },
             KeywordMapping {
                 keyword: "취업".to_string(),
                 id: "career".to_string(),
                 display_name: "취업/진로".to_string(),
+                aliases: Vec::new(),
             },
             KeywordMapping {
                 keyword: "공지사항".to_string(),
                 id: "notice".to_string(),
                 display_name: "일반공지".to_string(),
+                aliases: Vec::new(),
             },
             KeywordMapping {
                 keyword: "공지".to_string(),
                 id: "notice".to_string(),
                 display_name: "일반공지".to_string(),
+                aliases: Vec::new(),
             },
             KeywordMapping {
                 keyword: "진로".to_string(),
                 id: "career".to_string(),
                 display_name: "취업/진로".to_string(),
+                aliases: Vec::new(),
             },
             KeywordMapping {
                 keyword: "채용".to_string(),
                 id: "career".to_string(),
                 display_name: "채용정보".to_string(),
+                aliases: Vec::new(),
             },
             KeywordMapping {
                 keyword: "알림".to_string(),
                 id: "notice".to_string(),
                 display_name: "알림".to_string(),
+                aliases: Vec::new(),
             },
         ]
     }
@@ -425,6 +1006,32 @@ mod defaults {
 mod tests {
     use super::*;
 
+    #[test]
+    fn keyword_matching_ignores_spacing_and_punctuation() {
+        let keywords = defaults::default_keywords();
+        let id_for = |text: &str| {
+            keywords
+                .iter()
+                .find(|m| m.matches(text))
+                .map(|m| m.id.as_str())
+        };
+
+        assert_eq!(id_for("학사 공지"), Some("academic"));
+        assert_eq!(id_for("[학사·공지]"), Some("academic"));
+        assert_eq!(id_for("대학원 공지사항"), Some("grad_notice"));
+        assert_eq!(id_for("장학 / 복지"), Some("scholarship"));
+        assert_eq!(id_for("학과소개"), None);
+
+        let mapping = KeywordMapping {
+            keyword: "공지사항".to_string(),
+            id: "notice".to_string(),
+            display_name: "일반공지".to_string(),
+            aliases: vec!["Notice".to_string()],
+        };
+        assert!(mapping.matches("NOTICE board"));
+        assert!(!mapping.matches("News"));
+    }
+
     #[test]
     fn validate_default_config_ok() {
         assert!(Config::default().validate().is_ok());
@@ -437,6 +1044,43 @@ mod tests {
         assert!(config.validate().is_err());
     }
 
+    #[test]
+    fn validate_rejects_non_positive_rps() {
+        let mut config = Config::default();
+        for rps in [0.0, -1.0, 1e-300, f64::NAN, f64::INFINITY] {
+            config.crawler.per_domain_rps = Some(rps);
+            assert!(config.validate().is_err(), "{rps} accepted");
+        }
+        config.crawler.per_domain_rps = Some(2.5);
+        assert!(config.validate().is_ok());
+        assert!(Config::from_bytes(b"[crawler]\nper_domain_rps = 0.0").is_err());
+    }
+
+    #[test]
+    fn circuit_breaker_section_parses_with_defaults() {
+        let config: Config = toml::from_str(
+            r#"
+            [circuit_breaker]
+            max_drop_percent = 35
+            allow_cold_start = false
+            "#,
+        )
+        .unwrap();
+        assert_eq!(config.circuit_breaker.max_drop_percent, 35);
+        assert!(!config.circuit_breaker.allow_cold_start);
+        assert_eq!(
+            config.circuit_breaker.min_baseline,
+            CircuitBreakerConfig::default().min_baseline
+        );
+
+        let config: Config = toml::from_str("").unwrap();
+        assert_eq!(config.circuit_breaker.max_drop_percent, 20);
+
+        let mut config = Config::default();
+        config.circuit_breaker.max_drop_percent = 101;
+        assert!(config.validate().is_err());
+    }
+
     #[test]
     fn validate_rejects_zero_concurrency() {
         let mut config = Config::default();
@@ -444,6 +1088,55 @@ mod tests {
         assert!(config.validate().is_err());
     }
 
+    #[test]
+    fn strip_labels_removes_field_labels() {
+        let cleaning = CleaningConfig::default();
+        assert_eq!(cleaning.strip_labels("작성자: 홍길동"), "홍길동");
+        assert_eq!(
+            cleaning.strip_labels("등록일 : 2024.01.05 조회수：1,024"),
+            "2024.01.05 1,024"
+        );
+        assert_eq!(cleaning.strip_labels("학생지원팀"), "학생지원팀");
+        assert_eq!(cleaning.strip_labels("조회 57"), "57");
+        // Label words inside other text are not labels
+        assert_eq!(
+            cleaning.strip_labels("성적 조회 및 작성일 안내"),
+            "성적 조회 및 작성일 안내"
+        );
+        assert_eq!(
+            cleaning.strip_labels("조회수가 많은 글"),
+            "조회수가 많은 글"
+        );
+    }
+
+    #[test]
+    fn clean_title_strips_board_prefix() {
+        let cleaning = CleaningConfig {
+            strip_board_prefix: true,
+            ..CleaningConfig::default()
+        };
+
+        assert_eq!(
+            cleaning.clean_title("학사공지] 수강신청 안내", "학사공지"),
+            "수강신청 안내"
+        );
+        assert_eq!(
+            cleaning.clean_title("[학사공지] 수강신청 안내", "학사공지"),
+            "수강신청 안내"
+        );
+        assert_eq!(
+            cleaning.clean_title("[필독] 수강신청 안내", "학사공지"),
+            "[필독] 수강신청 안내"
+        );
+        assert_eq!(cleaning.clean_title("학사공지", "학사공지"), "학사공지");
+
+        let disabled = CleaningConfig::default();
+        assert_eq!(
+            disabled.clean_title("학사공지] 수강신청 안내", "학사공지"),
+            "학사공지] 수강신청 안내"
+        );
+    }
+
     #[test]
     fn validate_accepts_valid_campuses_and_keywords() {
         let config = Config::default();
@@ -461,6 +1154,17 @@ pub struct CampusInfo {
 
     /// URL of the campus department listing page
     pub url: String,
+
+    /// Mirror listing pages tried in order when `url` fails or is empty
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub fallback_urls: Vec<String>,
+}
+
+impl CampusInfo {
+    /// Primary URL followed by each fallback.
+    pub fn urls(&self) -> impl Iterator<Item = &str> {
+        std::iter::once(self.url.as_str()).chain(self.fallback_urls.iter().map(String::as_str))
+    }
 }
 
 /// Mapping from board keyword to standardized ID.
@@ -474,6 +1178,24 @@ pub struct KeywordMapping {
 
     /// Human-readable display name
     pub display_name: String,
+
+    /// Alternative spellings matched like `keyword` (e.g., "Notice")
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub aliases: Vec<String>,
+}
+
+impl KeywordMapping {
+    /// Whether `text` contains the keyword or an alias.
+    ///
+    /// Both sides are compared lowercased with whitespace and punctuation
+    /// removed, so "학사 공지" and "[학사·공지]" match "학사공지".
+    pub fn matches(&self, text: &str) -> bool {
+        let text = normalize_text(text);
+        std::iter::once(&self.keyword)
+            .chain(&self.aliases)
+            .map(|k| normalize_text(k))
+            .any(|k| !k.is_empty() && text.contains(&k))
+    }
 }
 
 /// CMS detection pattern with corresponding selectors.
@@ -13,18 +13,36 @@ mod config;
 mod notice;
 mod selectors;
 
+use std::collections::{BTreeMap, HashMap, HashSet};
+
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
+use crate::error::AppError;
+use crate::utils::http::ResolveError;
+
 // Re-export all public types
-pub use campus::{Board, Campus, CampusMeta, College, Department, DepartmentRef};
-pub use config::{CampusInfo, CmsPattern, Config, CrawlerConfig, DiscoveryConfig, KeywordMapping};
-pub use notice::{Notice, NoticeMetadata, NoticeOutput};
+pub use campus::{
+    Board, BoardSource, Campus, CampusMeta, College, Department, DepartmentRef, FetchConfig,
+};
+pub use config::{
+    CampusInfo, CircuitBreakerConfig, CleaningConfig, CmsPattern, Config, CrawlerConfig,
+    DedupScope, DiscoveryConfig, IdExtractionConfig, IndexConfig, KeywordMapping, PerCampusMode,
+    TokenizeMode,
+};
+pub use notice::{IdOptions, Notice, NoticeMetadata, NoticeOutput};
 pub use selectors::CmsSelectors;
 
 /// Statistics for a crawl session.
+///
+/// Written to `stats.json`. Fields added after the first schema must carry
+/// `#[serde(default)]` so older files keep loading; bump
+/// [`CrawlStats::SCHEMA_VERSION`] when the layout changes.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CrawlStats {
+    /// Layout version of this record (files predating versioning are 1)
+    #[serde(default = "CrawlStats::legacy_schema_version")]
+    pub schema_version: u32,
     pub start_time: DateTime<Utc>,
     pub end_time: DateTime<Utc>,
     pub notice_count: usize,
@@ -39,22 +57,171 @@ pub struct CrawlStats {
     pub detail_total: usize,
     pub detail_failures: usize,
     pub detail_success_rate: f32,
+    /// Response bytes received on the wire (compressed size)
+    #[serde(default)]
+    pub bytes_downloaded: u64,
+    /// Response bytes after decompression, as parsed
+    #[serde(default)]
+    pub bytes_parsed: u64,
+    /// Time spent on board list requests, summed over boards (excludes
+    /// waits and parsing)
+    #[serde(default)]
+    pub total_fetch_ms: u64,
+    /// Time spent decoding, parsing and extracting rows from fetched pages,
+    /// summed over boards
+    #[serde(default)]
+    pub total_parse_ms: u64,
+    /// Time spent in request delays and retry backoff, summed over boards
+    #[serde(default)]
+    pub total_wait_ms: u64,
+    /// Mean fetch time of a successfully fetched board
+    #[serde(default)]
+    pub avg_board_fetch_ms: u64,
+    /// Notices kept per second of wall-clock run time
+    #[serde(default)]
+    pub notices_per_second: f32,
+    /// Notices dropped for being older than `max_age_days` (not failures)
+    #[serde(default)]
+    pub notices_filtered: usize,
+}
+
+impl CrawlStats {
+    /// Current `stats.json` layout.
+    ///
+    /// - 1: initial counters and success rates
+    /// - 2: `bytes_downloaded` / `bytes_parsed`
+    /// - 3: `total_fetch_ms` / `total_parse_ms` / `avg_board_fetch_ms` /
+    ///   `notices_per_second`
+    /// - 4: `notices_filtered`
+    /// - 5: `total_wait_ms`
+    pub const SCHEMA_VERSION: u32 = 5;
+
+    fn legacy_schema_version() -> u32 {
+        1
+    }
 }
 
 /// Crawl stage for structured error reporting.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum CrawlStage {
     Selector,
     BoardList,
     NoticeDetail,
     BoardLookup,
+    /// Board list skipped because robots.txt disallows it
+    RobotsDisallowed,
+    /// A later list page failed or was disallowed; rows from earlier pages
+    /// were kept
+    BoardPage,
+}
+
+/// Machine-readable error kind for grouping and retry decisions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CrawlErrorCode {
+    Dns,
+    Timeout,
+    Tls,
+    Connect,
+    /// HTTP 4xx other than 429
+    ClientError,
+    /// HTTP 429
+    RateLimited,
+    /// HTTP 5xx
+    ServerError,
+    NotModified,
+    Redirect,
+    ContentType,
+    TooLarge,
+    /// Body could not be decoded or parsed
+    Parse,
+    Selector,
+    BoardLookup,
+    #[default]
+    Other,
+}
+
+impl CrawlErrorCode {
+    /// Classify an application error.
+    pub fn from_error(error: &AppError) -> Self {
+        match error {
+            AppError::Http(e) => Self::from_reqwest(e),
+            AppError::UpstreamHttp { status: 429, .. } => Self::RateLimited,
+            AppError::UpstreamHttp { status, .. } if (400..500).contains(status) => {
+                Self::ClientError
+            }
+            AppError::UpstreamHttp { status, .. } if (500..600).contains(status) => {
+                Self::ServerError
+            }
+            AppError::UpstreamNotModified { .. } => Self::NotModified,
+            AppError::RedirectBlocked { .. } => Self::Redirect,
+            AppError::UpstreamUnexpectedContentType { .. } => Self::ContentType,
+            AppError::UpstreamBodyTooLarge { .. } => Self::TooLarge,
+            AppError::UpstreamTooComplex { .. } | AppError::UpstreamUndecodable { .. } => {
+                Self::Parse
+            }
+            AppError::Selector { .. } => Self::Selector,
+            AppError::Crawl { context, .. } if context == "find_board" => Self::BoardLookup,
+            _ => Self::Other,
+        }
+    }
+
+    fn from_reqwest(error: &reqwest::Error) -> Self {
+        if error.is_timeout() {
+            return Self::Timeout;
+        }
+        if error.is_redirect() {
+            return Self::Redirect;
+        }
+        if let Some(status) = error.status() {
+            return Self::from_error(&AppError::UpstreamHttp {
+                url: String::new(),
+                status: status.as_u16(),
+                retry_after: None,
+            });
+        }
+
+        // Connection failures only expose their cause through the source chain
+        let mut source = std::error::Error::source(error);
+        while let Some(err) = source {
+            if let Some(code) = Self::from_cause(err) {
+                return code;
+            }
+            source = err.source();
+        }
+        if error.is_connect() {
+            Self::Connect
+        } else {
+            Self::Other
+        }
+    }
+
+    /// DNS or TLS code for one error in a source chain. `io::Error` hides
+    /// the error it wraps from `source()`, so that is checked here too.
+    fn from_cause(err: &(dyn std::error::Error + 'static)) -> Option<Self> {
+        if err.is::<ResolveError>() {
+            Some(Self::Dns)
+        } else if err.is::<rustls::Error>() {
+            Some(Self::Tls)
+        } else {
+            let inner = err.downcast_ref::<std::io::Error>()?.get_ref()?;
+            Self::from_cause(inner)
+        }
+    }
 }
 
 /// Structured crawl error for storage/reporting.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CrawlError {
     pub stage: CrawlStage,
+    #[serde(default)]
+    pub code: CrawlErrorCode,
+    /// Campus of the failed board, if the error belongs to one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub campus: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub department_id: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub board_id: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -65,6 +232,12 @@ pub struct CrawlError {
     pub notice_id: Option<String>,
     pub message: String,
     pub retryable: bool,
+    /// HTTP status of the failed response, if one was received
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub http_status: Option<u16>,
+    /// Time spent on the failed fetch, in milliseconds
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub duration_ms: Option<u64>,
 }
 
 /// Summary of a crawl run.
@@ -78,8 +251,217 @@ pub struct CrawlOutcome {
     pub notice_failures: usize,
     pub detail_total: usize,
     pub detail_failures: usize,
+    #[serde(default)]
+    pub bytes_downloaded: u64,
+    #[serde(default)]
+    pub bytes_parsed: u64,
+    /// Board list fetch time summed over successful boards
+    #[serde(default)]
+    pub total_fetch_ms: u64,
+    /// Board list decoding, parsing and row extraction time summed over
+    /// successful boards
+    #[serde(default)]
+    pub total_parse_ms: u64,
+    /// Request delays and retry backoff summed over successful boards
+    #[serde(default)]
+    pub total_wait_ms: u64,
+    /// Notices dropped for being older than `max_age_days`
+    #[serde(default)]
+    pub notices_filtered: usize,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub errors: Vec<CrawlError>,
+    /// Validators and notices of this run's board lists, for the next run
+    #[serde(skip)]
+    pub http_cache: HttpCache,
+}
+
+impl CrawlOutcome {
+    /// Drop notices that fail `Notice::validate`, counting them as notice failures.
+    ///
+    /// Returns the number of notices removed.
+    pub fn retain_valid(&mut self) -> usize {
+        let before = self.notices.len();
+        self.notices.retain(|notice| match notice.validate() {
+            Ok(()) => true,
+            Err(e) => {
+                log::warn!("Dropping invalid notice '{}': {}", notice.title, e);
+                false
+            }
+        });
+        let removed = before - self.notices.len();
+        self.notice_failures += removed;
+        removed
+    }
+}
+
+impl CrawlOutcome {
+    /// Per-campus board health with [`HealthReport::DEFAULT_MIN_SUCCESS_RATE`].
+    pub fn health_report(&self, campuses: &[Campus]) -> HealthReport {
+        self.health_report_with_threshold(campuses, HealthReport::DEFAULT_MIN_SUCCESS_RATE)
+    }
+
+    /// Per-campus board health, flagging campuses whose board success rate is
+    /// below `min_success_rate` (0.0-1.0).
+    ///
+    /// Only enabled boards count. A board failed when its list or a later list
+    /// page could not be fetched, was disallowed by robots.txt or had invalid
+    /// selectors; a board
+    /// fetched without yielding a notice is listed in
+    /// [`CampusHealth::empty_boards`] instead.
+    pub fn health_report_with_threshold(
+        &self,
+        campuses: &[Campus],
+        min_success_rate: f32,
+    ) -> HealthReport {
+        let failed_boards: HashSet<(&str, &str, &str)> = self
+            .errors
+            .iter()
+            .filter(|e| {
+                matches!(
+                    e.stage,
+                    CrawlStage::BoardList
+                        | CrawlStage::RobotsDisallowed
+                        | CrawlStage::Selector
+                        | CrawlStage::BoardPage
+                )
+            })
+            .filter_map(|e| {
+                Some((
+                    e.campus.as_deref()?,
+                    e.department_id.as_deref()?,
+                    e.board_id.as_deref()?,
+                ))
+            })
+            .collect();
+        let mut with_notices = HashSet::new();
+        let mut notice_counts: HashMap<&str, usize> = HashMap::new();
+        for n in &self.notices {
+            with_notices.insert((
+                n.campus.as_str(),
+                n.department_id.as_str(),
+                n.board_id.as_str(),
+            ));
+            *notice_counts.entry(n.campus.as_str()).or_default() += 1;
+        }
+
+        let campuses = campuses
+            .iter()
+            .map(|campus| {
+                let mut health = CampusHealth {
+                    campus: campus.campus.clone(),
+                    board_total: 0,
+                    board_failures: 0,
+                    board_success_rate: 0.0,
+                    notice_count: notice_counts
+                        .get(campus.campus.as_str())
+                        .copied()
+                        .unwrap_or(0),
+                    empty_boards: Vec::new(),
+                    healthy: true,
+                };
+                for dept_ref in campus.all_departments() {
+                    for board in dept_ref.dept.boards.iter().filter(|b| b.enabled) {
+                        health.board_total += 1;
+                        let key = (
+                            dept_ref.campus,
+                            dept_ref.dept.id.as_str(),
+                            board.id.as_str(),
+                        );
+                        if failed_boards.contains(&key) {
+                            health.board_failures += 1;
+                        } else if !with_notices.contains(&key) {
+                            health.empty_boards.push(EmptyBoard {
+                                department_name: dept_ref.dept.name.clone(),
+                                board_id: board.id.clone(),
+                                board_name: board.name.clone(),
+                                url: board.url.clone(),
+                            });
+                        }
+                    }
+                }
+                if health.board_total > 0 {
+                    health.board_success_rate = (health.board_total - health.board_failures) as f32
+                        / health.board_total as f32;
+                    health.healthy = health.board_success_rate >= min_success_rate;
+                }
+                health
+            })
+            .collect();
+
+        HealthReport {
+            min_success_rate,
+            campuses,
+        }
+    }
+}
+
+/// Per-campus crawl health (`health.json`), for spotting degrading campuses.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HealthReport {
+    /// Board success rate below which a campus is flagged unhealthy
+    pub min_success_rate: f32,
+    pub campuses: Vec<CampusHealth>,
+}
+
+impl HealthReport {
+    /// Default for [`CrawlOutcome::health_report`].
+    pub const DEFAULT_MIN_SUCCESS_RATE: f32 = 0.8;
+
+    /// Campuses below the success-rate threshold.
+    pub fn unhealthy(&self) -> impl Iterator<Item = &CampusHealth> {
+        self.campuses.iter().filter(|c| !c.healthy)
+    }
+}
+
+/// Board health of one campus.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CampusHealth {
+    pub campus: String,
+    pub board_total: usize,
+    pub board_failures: usize,
+    /// Share of enabled boards fetched (0.0 when the campus has none)
+    pub board_success_rate: f32,
+    pub notice_count: usize,
+    /// Boards fetched without a single notice (possible selector rot)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub empty_boards: Vec<EmptyBoard>,
+    /// Whether the success rate meets the threshold (campuses without
+    /// boards are healthy)
+    pub healthy: bool,
+}
+
+/// A board that was fetched but yielded no notices.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EmptyBoard {
+    pub department_name: String,
+    pub board_id: String,
+    pub board_name: String,
+    pub url: String,
+}
+
+impl CrawlError {
+    /// Tag the error with the campus and department of its board.
+    pub fn with_department(mut self, dept_ref: DepartmentRef<'_>) -> Self {
+        self.campus = Some(dept_ref.campus.to_string());
+        self.department_id = Some(dept_ref.dept.id.clone());
+        self
+    }
+
+    /// Sort errors deterministically by stage, board, then message.
+    ///
+    /// Errors are collected in concurrent-completion order; sorting keeps
+    /// reports byte-identical across runs with the same failures.
+    pub fn sort(errors: &mut [CrawlError]) {
+        errors.sort_by(|a, b| {
+            (a.stage, &a.board_id, &a.message, &a.url, &a.notice_id).cmp(&(
+                b.stage,
+                &b.board_id,
+                &b.message,
+                &b.url,
+                &b.notice_id,
+            ))
+        });
+    }
 }
 
 /// Crawl outcome report without notice payloads.
@@ -97,6 +479,8 @@ pub struct CrawlOutcomeReport {
 
 impl From<&CrawlOutcome> for CrawlOutcomeReport {
     fn from(outcome: &CrawlOutcome) -> Self {
+        let mut errors = outcome.errors.clone();
+        CrawlError::sort(&mut errors);
         Self {
             board_total: outcome.board_total,
             board_failures: outcome.board_failures,
@@ -104,11 +488,47 @@ impl From<&CrawlOutcome> for CrawlOutcomeReport {
             notice_failures: outcome.notice_failures,
             detail_total: outcome.detail_total,
             detail_failures: outcome.detail_failures,
-            errors: outcome.errors.clone(),
+            errors,
         }
     }
 }
 
+/// Validators from a response, replayed on the next request as
+/// `If-None-Match` / `If-Modified-Since`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HttpValidators {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub etag: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_modified: Option<String>,
+}
+
+impl HttpValidators {
+    /// Whether the response carried no validators.
+    pub fn is_empty(&self) -> bool {
+        self.etag.is_none() && self.last_modified.is_none()
+    }
+}
+
+/// Conditional-request cache kept between runs (`http-cache.json`).
+///
+/// Each board list URL maps to its last validators and the notices parsed
+/// from it, which are reused when the board answers `304 Not Modified`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct HttpCache {
+    #[serde(default)]
+    pub entries: BTreeMap<String, HttpCacheEntry>,
+}
+
+/// Cached state of one board list URL.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HttpCacheEntry {
+    #[serde(flatten)]
+    pub validators: HttpValidators,
+    #[serde(default)]
+    pub notices: Vec<Notice>,
+}
+
 /// Represents the difference between two snapshots.
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct Diff {
@@ -139,3 +559,282 @@ pub struct ManualReviewItem {
     pub url: String,
     pub reason: String,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn notice(title: &str, link: &str) -> Notice {
+        Notice {
+            campus: "TestCampus".to_string(),
+            college: "".to_string(),
+            department_id: "dept1".to_string(),
+            department_name: "Department".to_string(),
+            board_id: "notice".to_string(),
+            board_name: "공지사항".to_string(),
+            title: title.to_string(),
+            author: "".to_string(),
+            date: "2024-01-15".to_string(),
+            views: None,
+            link: link.to_string(),
+            source_id: None,
+            is_pinned: false,
+            date_inferred: false,
+            lang: None,
+            attachments: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_error_code_from_status() {
+        let status = |status| AppError::UpstreamHttp {
+            url: "https://example.com".to_string(),
+            status,
+            retry_after: None,
+        };
+        assert_eq!(
+            CrawlErrorCode::from_error(&status(404)),
+            CrawlErrorCode::ClientError
+        );
+        assert_eq!(
+            CrawlErrorCode::from_error(&status(429)),
+            CrawlErrorCode::RateLimited
+        );
+        assert_eq!(
+            CrawlErrorCode::from_error(&status(503)),
+            CrawlErrorCode::ServerError
+        );
+    }
+
+    #[test]
+    fn test_crawl_error_code_defaults_when_missing() {
+        let error: CrawlError = serde_json::from_str(
+            r#"{"stage": "board_list", "message": "boom", "retryable": false}"#,
+        )
+        .unwrap();
+        assert_eq!(error.code, CrawlErrorCode::Other);
+    }
+
+    fn crawl_error(stage: CrawlStage, board_id: &str, message: &str) -> CrawlError {
+        CrawlError {
+            stage,
+            code: CrawlErrorCode::Other,
+            campus: None,
+            department_id: None,
+            board_id: Some(board_id.to_string()),
+            board_name: None,
+            url: None,
+            notice_id: None,
+            message: message.to_string(),
+            retryable: false,
+            http_status: None,
+            duration_ms: None,
+        }
+    }
+
+    #[test]
+    fn test_error_report_is_order_independent() {
+        let errors = vec![
+            crawl_error(CrawlStage::NoticeDetail, "notice", "timeout"),
+            crawl_error(CrawlStage::BoardList, "scholarship", "HTTP 500"),
+            crawl_error(CrawlStage::BoardList, "notice", "HTTP 503"),
+            crawl_error(CrawlStage::Selector, "notice", "bad selector"),
+        ];
+        let mut reversed = errors.clone();
+        reversed.reverse();
+
+        let report = |errors| {
+            let outcome = CrawlOutcome {
+                errors,
+                ..CrawlOutcome::default()
+            };
+            serde_json::to_string(&CrawlOutcomeReport::from(&outcome)).unwrap()
+        };
+
+        let forward = report(errors);
+        assert_eq!(forward, report(reversed));
+
+        let parsed: CrawlOutcomeReport = serde_json::from_str(&forward).unwrap();
+        let order: Vec<_> = parsed
+            .errors
+            .iter()
+            .map(|e| (e.stage, e.board_id.as_deref().unwrap()))
+            .collect();
+        assert_eq!(
+            order,
+            [
+                (CrawlStage::Selector, "notice"),
+                (CrawlStage::BoardList, "notice"),
+                (CrawlStage::BoardList, "scholarship"),
+                (CrawlStage::NoticeDetail, "notice"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_retain_valid_drops_invalid_and_counts_failures() {
+        let mut outcome = CrawlOutcome {
+            notices: vec![
+                notice("Valid", "https://example.com/1"),
+                notice("Bad link", "#"),
+                notice("", "https://example.com/2"),
+            ],
+            notice_total: 3,
+            ..CrawlOutcome::default()
+        };
+
+        assert_eq!(outcome.retain_valid(), 2);
+        assert_eq!(outcome.notices.len(), 1);
+        assert_eq!(outcome.notices[0].title, "Valid");
+        assert_eq!(outcome.notice_total, 3);
+        assert_eq!(outcome.notice_failures, 2);
+    }
+
+    const STATS_V1: &str = r#"{
+        "start_time": "2026-01-05T00:00:00Z",
+        "end_time": "2026-01-05T00:10:00Z",
+        "notice_count": 120,
+        "department_count": 10,
+        "board_count": 25,
+        "board_total": 25,
+        "board_failures": 1,
+        "board_success_rate": 0.96,
+        "notice_total": 130,
+        "notice_failures": 10,
+        "notice_success_rate": 0.92,
+        "detail_total": 120,
+        "detail_failures": 0,
+        "detail_success_rate": 1.0
+    }"#;
+
+    #[test]
+    fn test_stats_v1_loads_with_defaults() {
+        let stats: CrawlStats = serde_json::from_str(STATS_V1).unwrap();
+
+        assert_eq!(stats.schema_version, 1);
+        assert_eq!(stats.bytes_downloaded, 0);
+        assert_eq!(stats.bytes_parsed, 0);
+        assert_eq!(stats.total_fetch_ms, 0);
+        assert_eq!(stats.notices_per_second, 0.0);
+        assert_eq!(stats.notice_count, 120);
+        assert_eq!(stats.board_failures, 1);
+        assert_eq!(stats.detail_success_rate, 1.0);
+    }
+
+    #[test]
+    fn test_stats_round_trip() {
+        let mut stats: CrawlStats = serde_json::from_str(STATS_V1).unwrap();
+        stats.schema_version = CrawlStats::SCHEMA_VERSION;
+        stats.bytes_downloaded = 4_096;
+        stats.bytes_parsed = 16_384;
+        stats.total_fetch_ms = 1_500;
+        stats.notices_per_second = 2.5;
+
+        let json = serde_json::to_value(&stats).unwrap();
+        assert_eq!(json["schema_version"], CrawlStats::SCHEMA_VERSION);
+
+        let back: CrawlStats = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(serde_json::to_value(&back).unwrap(), json);
+        assert_eq!(back.bytes_downloaded, 4_096);
+        assert_eq!(back.bytes_parsed, 16_384);
+        assert_eq!(back.total_fetch_ms, 1_500);
+        assert_eq!(back.notices_per_second, 2.5);
+    }
+
+    #[test]
+    fn test_health_report_flags_campus_with_failing_boards() {
+        let board = |id: &str, enabled: bool| Board {
+            id: id.to_string(),
+            name: id.to_string(),
+            url: format!("https://example.com/{}", id),
+            selectors: CmsSelectors::default(),
+            content_root_selector: None,
+            link_base: None,
+            enabled,
+            fetch: FetchConfig::default(),
+            source: BoardSource::Html,
+        };
+        let campus = |name: &str, dept_id: &str, boards: Vec<Board>| Campus {
+            campus: name.to_string(),
+            colleges: vec![],
+            departments: vec![Department {
+                id: dept_id.to_string(),
+                name: "Department".to_string(),
+                url: "https://example.com".to_string(),
+                boards,
+            }],
+            source_url: None,
+        };
+        let campuses = vec![
+            campus(
+                "TestCampus",
+                "dept1",
+                // Shares its URL with the failing board "a" of the other campus
+                vec![
+                    board("notice", true),
+                    board("events", true),
+                    board("a", true),
+                ],
+            ),
+            campus(
+                "미래캠퍼스",
+                "dept2",
+                vec![board("a", true), board("b", true), board("old", false)],
+            ),
+        ];
+        let failure = |stage: CrawlStage, id: &str| CrawlError {
+            stage,
+            code: CrawlErrorCode::ServerError,
+            campus: Some("미래캠퍼스".to_string()),
+            department_id: Some("dept2".to_string()),
+            board_id: Some(id.to_string()),
+            board_name: None,
+            url: Some(format!("https://example.com/{}", id)),
+            notice_id: None,
+            message: "HTTP 503".to_string(),
+            retryable: true,
+            http_status: Some(503),
+            duration_ms: None,
+        };
+        let outcome = CrawlOutcome {
+            notices: vec![notice("Exam schedule", "https://example.com/view?id=1")],
+            board_total: 5,
+            board_failures: 2,
+            errors: vec![
+                failure(CrawlStage::BoardList, "a"),
+                failure(CrawlStage::BoardPage, "b"),
+            ],
+            ..CrawlOutcome::default()
+        };
+
+        let report = outcome.health_report(&campuses);
+
+        let healthy = &report.campuses[0];
+        assert!(healthy.healthy);
+        assert_eq!((healthy.board_total, healthy.board_failures), (3, 0));
+        assert_eq!(healthy.board_success_rate, 1.0);
+        assert_eq!(healthy.notice_count, 1);
+        let empty: Vec<_> = healthy
+            .empty_boards
+            .iter()
+            .map(|b| b.board_id.as_str())
+            .collect();
+        assert_eq!(empty, ["events", "a"]);
+
+        let failing = &report.campuses[1];
+        assert!(!failing.healthy);
+        assert_eq!((failing.board_total, failing.board_failures), (2, 2));
+        assert_eq!(failing.board_success_rate, 0.0);
+        assert!(failing.empty_boards.is_empty());
+
+        let unhealthy: Vec<_> = report.unhealthy().map(|c| c.campus.as_str()).collect();
+        assert_eq!(unhealthy, ["미래캠퍼스"]);
+        assert_eq!(
+            outcome
+                .health_report_with_threshold(&campuses, 0.0)
+                .unhealthy()
+                .count(),
+            0
+        );
+    }
+}
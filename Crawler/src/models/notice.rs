@@ -2,10 +2,16 @@
 //!
 //! Data schema for Hot/Cold storage pattern.
 
-use chrono::NaiveDate;
+use std::sync::LazyLock;
+
+use chrono::{DateTime, NaiveDate, TimeDelta, Utc};
+use regex::Regex;
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
 
+use crate::error::{AppError, Result};
+use crate::utils::url::canonicalize_with;
+
 /// A notice fetched from a board (internal representation).
 ///
 /// This contains all crawled metadata. For JSON output, convert to `NoticeOutput`.
@@ -39,6 +45,10 @@ pub struct Notice {
     /// Notice date (YYYY-MM-DD format)
     pub date: String,
 
+    /// View count shown on the list page, when the board has one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub views: Option<u32>,
+
     /// Full URL to the notice
     pub link: String,
 
@@ -49,16 +59,59 @@ pub struct Notice {
     /// Whether this notice is pinned/important
     #[serde(default)]
     pub is_pinned: bool,
+
+    /// Whether `date` was inferred from a neighbouring row (the row had none)
+    #[serde(default)]
+    pub date_inferred: bool,
+
+    /// Script of the title (`ko`, `en` or `mixed`) when language detection is on
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lang: Option<String>,
+
+    /// Absolute URLs of files attached to the notice (HWP, PDF, ...)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub attachments: Vec<String>,
+}
+
+/// How [`Notice::canonical_id_with`] builds an ID. The default is the
+/// original scheme: link hashed as is, college left out.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct IdOptions<'a> {
+    /// Canonicalize the link, also dropping these query parameters; `None`
+    /// hashes the link as is (see `CrawlerConfig::link_id_params`)
+    pub link_params: Option<&'a [String]>,
+    /// Include the college (see `CrawlerConfig::id_includes_college`)
+    pub include_college: bool,
 }
 
 impl Notice {
-    /// Compute a canonical identifier for deduplication.
-    /// Format: YYYYMMDD-XXX (date-based with sequence number)
+    /// Hex characters of the SHA-256 digest kept in [`Notice::canonical_id`].
+    ///
+    /// 12 chars (48 bits) keep collisions negligible at tens of thousands of
+    /// notices per day; a collision would silently drop a notice in dedup.
+    pub const ID_HASH_LEN: usize = 12;
+
+    /// Compute a canonical identifier for deduplication, hashing `link` as is.
+    /// Format: YYYYMMDD-XXXXXXXXXXXX (date + truncated hash)
     pub fn canonical_id(&self) -> String {
+        self.canonical_id_with(IdOptions::default())
+    }
+
+    /// [`Notice::canonical_id`] built as `options` say. With
+    /// `options.link_params` set, `link` is canonicalized: query parameters
+    /// sorted and tracking/session parameters plus `link_params` dropped (see
+    /// [`canonicalize_with`]). With `options.include_college` the college is
+    /// hashed along with the campus.
+    pub fn canonical_id_with(&self, options: IdOptions<'_>) -> String {
         // Create a hash-based short ID
+        let campus = if options.include_college {
+            format!("{}|{}", self.campus.trim(), self.college.trim())
+        } else {
+            self.campus.trim().to_string()
+        };
         let normalized = format!(
             "{}|{}|{}|{}|{}",
-            self.campus.trim().to_lowercase(),
+            campus.to_lowercase(),
             self.department_id.trim().to_lowercase(),
             self.board_id.trim().to_lowercase(),
             self.source_id
@@ -66,20 +119,82 @@ impl Notice {
                 .unwrap_or("")
                 .trim()
                 .to_lowercase(),
-            self.link.trim().to_lowercase()
+            match options.link_params {
+                Some(params) => canonicalize_with(&self.link, params).to_lowercase(),
+                None => self.link.trim().to_lowercase(),
+            }
         );
         let mut hasher = Sha256::new();
         hasher.update(normalized.as_bytes());
         let digest = hasher.finalize();
 
-        // Use date prefix + first 6 hex chars of hash
+        // Use date prefix + first ID_HASH_LEN hex chars of hash
         let date_part = self.normalized_date().replace("-", "");
-        let hash_part = &hex::encode(digest)[..6];
+        let hash_part = &hex::encode(digest)[..Self::ID_HASH_LEN];
         format!("{}-{}", date_part, hash_part)
     }
 
+    /// Hash of the fields a board may edit in place: title, date, author and
+    /// pinned state. Unlike [`Notice::canonical_id`] it changes on edits, so
+    /// the diff can report the notice as updated.
+    pub fn content_hash(&self) -> String {
+        let content = format!(
+            "{}|{}|{}|{}",
+            self.title.trim(),
+            self.normalized_date(),
+            self.author.trim(),
+            self.is_pinned
+        );
+        let digest = Sha256::digest(content.as_bytes());
+        hex::encode(digest)[..16].to_string()
+    }
+
+    /// Validate that the notice is usable for storage.
+    ///
+    /// A notice needs a non-empty title and an absolute `http(s)` link.
+    pub fn validate(&self) -> Result<()> {
+        if self.title.trim().is_empty() {
+            return Err(AppError::validation("notice title is empty"));
+        }
+        match url::Url::parse(&self.link) {
+            Ok(url) if matches!(url.scheme(), "http" | "https") => Ok(()),
+            _ => Err(AppError::validation(format!(
+                "notice link is not an absolute http(s) URL: '{}'",
+                self.link
+            ))),
+        }
+    }
+
     /// Normalize date to YYYY-MM-DD format.
     pub fn normalized_date(&self) -> String {
+        self.normalized_date_at(Utc::now())
+    }
+
+    /// Replace a relative date ("오늘", "3일 전") with its `YYYY-MM-DD` date
+    /// as of `now`, so the notice's ID no longer depends on when it is
+    /// normalized.
+    pub fn resolve_relative_date(&mut self, now: DateTime<Utc>) {
+        let trimmed = self.date.trim();
+        if !trimmed.is_ascii()
+            && let Some(date) = Self::parse_relative_date(trimmed, now)
+        {
+            self.date = date.format("%Y-%m-%d").to_string();
+        }
+    }
+
+    /// Normalize date to YYYY-MM-DD format, resolving relative dates
+    /// ("오늘", "어제", "3일 전") against `now`.
+    pub fn normalized_date_at(&self, now: DateTime<Utc>) -> String {
+        // Korean and relative forms always contain non-ASCII text; skipping
+        // them keeps this cheap for the common numeric dates
+        let trimmed = self.date.trim();
+        if !trimmed.is_ascii()
+            && let Some(date) =
+                Self::parse_relative_date(trimmed, now).or_else(|| Self::parse_korean_date(trimmed))
+        {
+            return date.format("%Y-%m-%d").to_string();
+        }
+
         // Handle various date formats: YYYY.MM.DD, YYYY-MM-DD, YYYY/MM/DD
         let cleaned = self.date.replace(['.', '/'], "-");
 
@@ -101,6 +216,47 @@ impl Notice {
         }
     }
 
+    /// Parse `YYYY년 MM월 DD일` (also with a two-digit year).
+    fn parse_korean_date(date: &str) -> Option<NaiveDate> {
+        static KOREAN_DATE: LazyLock<Regex> = LazyLock::new(|| {
+            Regex::new(r"^(\d{2}|\d{4})\s*년\s*(\d{1,2})\s*월\s*(\d{1,2})\s*일").unwrap()
+        });
+        let caps = KOREAN_DATE.captures(date)?;
+        let year: i32 = caps[1].parse().ok()?;
+        let year = if caps[1].len() == 2 {
+            2000 + year
+        } else {
+            year
+        };
+        NaiveDate::from_ymd_opt(year, caps[2].parse().ok()?, caps[3].parse().ok()?)
+    }
+
+    /// Resolve "오늘", "어제", "그제" and "N분/시간/일/주 전" against `now`.
+    fn parse_relative_date(date: &str, now: DateTime<Utc>) -> Option<NaiveDate> {
+        let days_ago = match date {
+            "오늘" => Some(0),
+            "어제" => Some(1),
+            "그제" | "그저께" => Some(2),
+            _ => None,
+        };
+        let delta = match days_ago {
+            Some(days) => TimeDelta::try_days(days)?,
+            None => {
+                static RELATIVE: LazyLock<Regex> =
+                    LazyLock::new(|| Regex::new(r"^(\d+)\s*(분|시간|일|주)\s*전$").unwrap());
+                let caps = RELATIVE.captures(date)?;
+                let n: i64 = caps[1].parse().ok()?;
+                match &caps[2] {
+                    "분" => TimeDelta::try_minutes(n)?,
+                    "시간" => TimeDelta::try_hours(n)?,
+                    "일" => TimeDelta::try_days(n)?,
+                    _ => TimeDelta::try_weeks(n)?,
+                }
+            }
+        };
+        now.checked_sub_signed(delta).map(|t| t.date_naive())
+    }
+
     /// Get the year-month for archiving (YYYY, MM).
     pub fn archive_period(&self) -> (i32, u32) {
         let normalized = self.normalized_date();
@@ -143,11 +299,28 @@ pub struct NoticeMetadata {
     /// Board display name
     pub board_name: String,
 
+    /// Author as listed on the board (omitted when the board shows none)
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub author: String,
+
     /// Notice date (YYYY-MM-DD format)
     pub date: String,
 
     /// Whether this notice is pinned/important
     pub pinned: bool,
+
+    /// Script of the title (`ko`, `en` or `mixed`), if detected
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lang: Option<String>,
+
+    /// Whether the notice has downloadable attachments
+    #[serde(default)]
+    pub has_attachment: bool,
+
+    /// Hash of the notice's mutable content (see [`Notice::content_hash`]);
+    /// absent in snapshots written before it was introduced
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_hash: Option<String>,
 }
 
 /// Output format for JSON files.
@@ -182,10 +355,12 @@ pub struct NoticeOutput {
     pub metadata: NoticeMetadata,
 }
 
-impl From<&Notice> for NoticeOutput {
-    fn from(notice: &Notice) -> Self {
+impl NoticeOutput {
+    /// Output form of `notice`, its ID computed with
+    /// [`Notice::canonical_id_with`].
+    pub fn new(notice: &Notice, id_options: IdOptions<'_>) -> Self {
         Self {
-            id: notice.canonical_id(),
+            id: notice.canonical_id_with(id_options),
             title: notice.title.clone(),
             link: notice.link.clone(),
             metadata: NoticeMetadata {
@@ -193,13 +368,23 @@ impl From<&Notice> for NoticeOutput {
                 college: notice.college.clone(),
                 department_name: notice.department_name.clone(),
                 board_name: notice.board_name.clone(),
+                author: notice.author.clone(),
                 date: notice.normalized_date(),
                 pinned: notice.is_pinned,
+                lang: notice.lang.clone(),
+                has_attachment: !notice.attachments.is_empty(),
+                content_hash: Some(notice.content_hash()),
             },
         }
     }
 }
 
+impl From<&Notice> for NoticeOutput {
+    fn from(notice: &Notice) -> Self {
+        Self::new(notice, IdOptions::default())
+    }
+}
+
 impl From<Notice> for NoticeOutput {
     fn from(notice: Notice) -> Self {
         Self::from(&notice)
@@ -234,9 +419,13 @@ mod tests {
             title: "Test Title".to_string(),
             author: "Admin".to_string(),
             date: "2024-01-15".to_string(),
+            views: None,
             link: "https://example.com/notice/1".to_string(),
             source_id: None,
             is_pinned: false,
+            date_inferred: false,
+            lang: None,
+            attachments: Vec::new(),
         }
     }
 
@@ -244,13 +433,45 @@ mod tests {
     fn test_canonical_id_format() {
         let notice = sample_notice();
         let id = notice.canonical_id();
-        // Should be YYYYMMDD-XXXXXX format
+        // Should be YYYYMMDD-XXXXXXXXXXXX format
         assert!(
             id.starts_with("20240115-"),
             "ID should start with date: {}",
             id
         );
-        assert_eq!(id.len(), 15, "ID should be 15 chars: YYYYMMDD-XXXXXX");
+        assert_eq!(
+            id.len(),
+            9 + Notice::ID_HASH_LEN,
+            "ID should be YYYYMMDD- plus the hash: {}",
+            id
+        );
+        const { assert!(Notice::ID_HASH_LEN >= 12) };
+    }
+
+    #[test]
+    fn test_content_hash_tracks_edits() {
+        let notice = sample_notice();
+        assert_eq!(notice.content_hash(), sample_notice().content_hash());
+
+        let mut edited = sample_notice();
+        edited.title = "Test Title (수정)".to_string();
+        assert_ne!(notice.content_hash(), edited.content_hash());
+        assert_eq!(notice.canonical_id(), edited.canonical_id());
+
+        let mut pinned = sample_notice();
+        pinned.is_pinned = true;
+        assert_ne!(notice.content_hash(), pinned.content_hash());
+
+        let output = NoticeOutput::from(&notice);
+        assert_eq!(output.metadata.content_hash, Some(notice.content_hash()));
+    }
+
+    #[test]
+    fn test_canonical_id_differs_by_link() {
+        let first = sample_notice();
+        let mut second = sample_notice();
+        second.link = format!("{}&page=2", first.link);
+        assert_ne!(first.canonical_id(), second.canonical_id());
     }
 
     #[test]
@@ -261,6 +482,59 @@ mod tests {
         assert_eq!(first, second);
     }
 
+    #[test]
+    fn test_canonical_id_ignores_query_order_and_tracking() {
+        let mut first = sample_notice();
+        let mut second = sample_notice();
+        first.link = "https://example.com/board/view?page=1&id=5".to_string();
+        second.link = "https://EXAMPLE.com/board/view?id=5&page=1&utm_source=kakao#top".to_string();
+        let id = |notice: &Notice, params: &[String]| {
+            notice.canonical_id_with(IdOptions {
+                link_params: Some(params),
+                ..IdOptions::default()
+            })
+        };
+        assert_eq!(id(&first, &[]), id(&second, &[]));
+        assert_ne!(first.link, second.link);
+
+        second.link = "https://example.com/board/view?id=5&page=1&token=abc".to_string();
+        assert_ne!(id(&first, &[]), id(&second, &[]));
+        let token = ["token".to_string()];
+        assert_eq!(id(&first, &token), id(&second, &token));
+    }
+
+    #[test]
+    fn test_canonical_id_hashes_link_verbatim_by_default() {
+        let mut first = sample_notice();
+        let mut second = sample_notice();
+        first.link = "https://example.com/board/view?page=1&id=5".to_string();
+        second.link = "https://example.com/board/view?id=5&page=1".to_string();
+        assert_ne!(first.canonical_id(), second.canonical_id());
+        assert_eq!(
+            first.canonical_id(),
+            first.canonical_id_with(IdOptions::default())
+        );
+    }
+
+    #[test]
+    fn test_include_college_separates_colleges() {
+        let first = sample_notice();
+        let mut second = sample_notice();
+        second.college = "OtherCollege".to_string();
+
+        // Legacy scheme: same department ID in two colleges collides
+        assert_eq!(first.canonical_id(), second.canonical_id());
+
+        let options = IdOptions {
+            include_college: true,
+            ..IdOptions::default()
+        };
+        assert_ne!(
+            first.canonical_id_with(options),
+            second.canonical_id_with(options)
+        );
+    }
+
     #[test]
     fn test_normalized_date() {
         let mut notice = sample_notice();
@@ -275,6 +549,59 @@ mod tests {
         assert_eq!(notice.normalized_date(), "2024-01-15");
     }
 
+    #[test]
+    fn test_normalized_date_korean_and_relative() {
+        let now = "2025-01-15T09:30:00Z".parse::<DateTime<Utc>>().unwrap();
+        let mut notice = sample_notice();
+        let mut normalize = |date: &str| {
+            notice.date = date.to_string();
+            notice.normalized_date_at(now)
+        };
+
+        assert_eq!(normalize("2025년 1월 15일"), "2025-01-15");
+        assert_eq!(normalize("2024년 12월 3일 (화)"), "2024-12-03");
+        assert_eq!(normalize("25년 01월 02일"), "2025-01-02");
+        assert_eq!(normalize("오늘"), "2025-01-15");
+        assert_eq!(normalize("어제"), "2025-01-14");
+        assert_eq!(normalize("3일 전"), "2025-01-12");
+        assert_eq!(normalize("10시간 전"), "2025-01-14");
+        assert_eq!(normalize("2주 전"), "2025-01-01");
+        // Unknown formats keep the existing fallback
+        assert_eq!(normalize("2025년 13월 1일"), "2025년 13월 1일");
+        assert_eq!(normalize("방금"), "방금");
+    }
+
+    #[test]
+    fn test_relative_date_resolved_once() {
+        let start = "2025-01-15T09:30:00Z".parse::<DateTime<Utc>>().unwrap();
+        let mut notice = sample_notice();
+        notice.date = "3일 전".to_string();
+        notice.resolve_relative_date(start);
+        assert_eq!(notice.date, "2025-01-12");
+
+        // Later normalization no longer depends on the clock
+        let id = notice.canonical_id();
+        assert_eq!(notice.normalized_date(), "2025-01-12");
+        assert_eq!(notice.canonical_id(), id);
+
+        notice.date = "2024.01.15".to_string();
+        notice.resolve_relative_date(start);
+        assert_eq!(notice.date, "2024.01.15");
+    }
+
+    #[test]
+    fn test_validate() {
+        let mut notice = sample_notice();
+        assert!(notice.validate().is_ok());
+
+        notice.link = "javascript:void(0)".to_string();
+        assert!(notice.validate().is_err());
+
+        notice = sample_notice();
+        notice.title = "  ".to_string();
+        assert!(notice.validate().is_err());
+    }
+
     #[test]
     fn test_archive_period() {
         let notice = sample_notice();
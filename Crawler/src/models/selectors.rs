@@ -14,10 +14,24 @@ pub struct CmsSelectors {
     /// Selector for the date element within a row
     pub date_selector: String,
 
+    /// Selectors tried in order, relative to the row, when `date_selector`
+    /// finds no date (e.g. `td:nth-child(4)` for a column that moves)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub date_fallbacks: Vec<String>,
+
     /// Selector for the author element within a row
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub author_selector: Option<String>,
 
+    /// Selectors tried in order, relative to the row, when `author_selector`
+    /// is unset or finds no text
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub author_fallbacks: Vec<String>,
+
+    /// Selector for the view-count element within a row
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub views_selector: Option<String>,
+
     /// Selector for the notice body content on the detail page
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub body_selector: Option<String>,
@@ -29,22 +43,89 @@ pub struct CmsSelectors {
     /// Optional selector for the link element (if different from title)
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub link_selector: Option<String>,
+
+    /// Attributes tried in order when `attr_name` yields no usable link.
+    /// Values of `onclick` (or `javascript:` links) are scanned for an embedded URL.
+    #[serde(
+        default = "default_link_attr_fallbacks",
+        skip_serializing_if = "is_default_link_attr_fallbacks"
+    )]
+    pub link_attr_fallbacks: Vec<String>,
+
+    /// Selector marking a pinned row: the row itself or an element inside it
+    /// (e.g. `img[alt="공지"]`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pinned_selector: Option<String>,
+
+    /// Class on the row element marking a pinned row (e.g. `notice`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pinned_class: Option<String>,
+
+    /// Selector for attachment links within a row (e.g. `a.file`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub attachment_selector: Option<String>,
+
+    /// Selector for the "next page" link on a list page
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pagination_selector: Option<String>,
+
+    /// Next-page URL template with a `{page}` placeholder (e.g. `?page={page}`),
+    /// resolved against the board URL; used when `pagination_selector` is unset
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next_url_pattern: Option<String>,
+
+    /// Maximum list pages fetched per crawl
+    #[serde(
+        default = "default_max_pages",
+        skip_serializing_if = "is_default_max_pages"
+    )]
+    pub max_pages: usize,
 }
 
 fn default_attr_name() -> String {
     "href".to_string()
 }
 
+fn default_link_attr_fallbacks() -> Vec<String> {
+    vec![
+        "data-url".to_string(),
+        "data-href".to_string(),
+        "onclick".to_string(),
+    ]
+}
+
+fn is_default_link_attr_fallbacks(attrs: &[String]) -> bool {
+    attrs == default_link_attr_fallbacks().as_slice()
+}
+
+fn default_max_pages() -> usize {
+    1
+}
+
+fn is_default_max_pages(pages: &usize) -> bool {
+    *pages == default_max_pages()
+}
+
 impl Default for CmsSelectors {
     fn default() -> Self {
         Self {
             row_selector: "tr".to_string(),
             title_selector: "a".to_string(),
             date_selector: "td:last-child".to_string(),
+            date_fallbacks: Vec::new(),
             author_selector: None,
+            author_fallbacks: Vec::new(),
+            views_selector: None,
             body_selector: None,
             attr_name: default_attr_name(),
             link_selector: None,
+            link_attr_fallbacks: default_link_attr_fallbacks(),
+            pinned_selector: None,
+            pinned_class: None,
+            attachment_selector: None,
+            pagination_selector: None,
+            next_url_pattern: None,
+            max_pages: default_max_pages(),
         }
     }
 }
@@ -61,10 +142,20 @@ impl CmsSelectors {
             row_selector: row.into(),
             title_selector: title.into(),
             date_selector: date.into(),
+            date_fallbacks: Vec::new(),
             author_selector: None,
+            author_fallbacks: Vec::new(),
+            views_selector: None,
             body_selector: None,
             attr_name: attr.into(),
             link_selector: None,
+            link_attr_fallbacks: default_link_attr_fallbacks(),
+            pinned_selector: None,
+            pinned_class: None,
+            attachment_selector: None,
+            pagination_selector: None,
+            next_url_pattern: None,
+            max_pages: default_max_pages(),
         }
     }
 
@@ -75,10 +166,37 @@ impl CmsSelectors {
             row_selector: "table tr:has(a)".to_string(),
             title_selector: "a".to_string(),
             date_selector: "td:last-child".to_string(),
+            date_fallbacks: Vec::new(),
             author_selector: None,
+            author_fallbacks: Vec::new(),
+            views_selector: None,
             body_selector: None,
             attr_name: "href".to_string(),
             link_selector: None,
+            link_attr_fallbacks: default_link_attr_fallbacks(),
+            pinned_selector: None,
+            pinned_class: None,
+            attachment_selector: None,
+            pagination_selector: None,
+            next_url_pattern: None,
+            max_pages: default_max_pages(),
+        }
+    }
+
+    /// Selectors for a feed-backed board ([`BoardSource::Rss`]).
+    ///
+    /// Feed items are read from their RSS/Atom elements rather than matched
+    /// as CSS, so these name the elements for reference only.
+    ///
+    /// [`BoardSource::Rss`]: crate::models::BoardSource::Rss
+    pub fn rss() -> Self {
+        Self {
+            row_selector: "item, entry".to_string(),
+            title_selector: "title".to_string(),
+            date_selector: "pubDate, published, updated".to_string(),
+            author_selector: Some("author".to_string()),
+            link_selector: Some("link".to_string()),
+            ..Self::fallback()
         }
     }
 }
@@ -8,31 +8,65 @@
 //! > If the number of crawled items drops by more than **20%** compared
 //! > to the previous run, the write operation is aborted.
 
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+use serde::{Deserialize, Serialize};
+
 use crate::error::{AppError, Result};
-use crate::models::NoticeOutput;
+use crate::models::{CircuitBreakerConfig, NoticeOutput, PerCampusMode};
 
-/// Circuit breaker configuration.
-#[derive(Debug, Clone)]
-pub struct CircuitBreakerConfig {
-    /// Maximum allowed drop percentage (0-100). Default: 20%
-    pub max_drop_percent: u8,
-    /// Minimum notice count to trigger circuit breaker check.
-    /// Below this threshold, the check is skipped (for new deployments).
-    pub min_baseline: usize,
-    /// Allow empty results when previous was also empty
-    pub allow_cold_start: bool,
+/// Circuit breaker state persisted between runs.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CircuitBreakerState {
+    /// Number of consecutive runs that produced no notices
+    pub consecutive_empty: u8,
+    /// Consecutive empty runs per campus (campuses with notices are absent)
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub campus_empty: BTreeMap<String, u8>,
+    /// Notices per campus in the last written crawl, across all months.
+    /// `None` until a write has recorded them.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub written_counts: Option<BTreeMap<String, usize>>,
 }
 
-impl Default for CircuitBreakerConfig {
-    fn default() -> Self {
+impl CircuitBreakerState {
+    /// State after a run that produced `current_count` notices.
+    pub fn advance(&self, current_count: usize) -> Self {
         Self {
-            max_drop_percent: 20,
-            min_baseline: 10,
-            allow_cold_start: true,
+            consecutive_empty: if current_count == 0 {
+                self.consecutive_empty.saturating_add(1)
+            } else {
+                0
+            },
+            ..self.clone()
+        }
+    }
+
+    /// State after a run with `counts` notices per checked campus.
+    pub fn advance_campuses(mut self, counts: &BTreeMap<String, usize>) -> Self {
+        for (campus, &count) in counts {
+            if count == 0 {
+                let runs = self.campus_empty.entry(campus.clone()).or_default();
+                *runs = runs.saturating_add(1);
+            } else {
+                self.campus_empty.remove(campus);
+            }
         }
+        self
     }
 }
 
+/// Per-campus write decision from [`CircuitBreaker::check_campuses`].
+#[derive(Debug, Default)]
+pub struct CampusCheck {
+    /// Campuses whose stored notices are kept in place of this crawl's,
+    /// because they tripped (`Any`) or are empty within the grace window
+    pub held: BTreeSet<String>,
+    /// Set when the whole write must be aborted (`All` with every campus
+    /// tripped)
+    pub abort: Option<AppError>,
+}
+
 /// Circuit breaker for preventing bad data updates.
 #[derive(Debug, Clone)]
 pub struct CircuitBreaker {
@@ -55,6 +89,17 @@ pub enum CircuitBreakerResult {
         previous_count: usize,
         drop_percent: f64,
     },
+    /// Notice count grew beyond `max_gain_percent` - abort write
+    SpikeTriggered {
+        current_count: usize,
+        previous_count: usize,
+        gain_percent: f64,
+    },
+    /// Empty result tolerated within the grace window - skip the write
+    EmptyGrace {
+        consecutive_empty: u8,
+        grace_runs: u8,
+    },
     /// Empty result - critical failure
     EmptyResult,
 }
@@ -78,9 +123,84 @@ impl CircuitBreaker {
         current: &[NoticeOutput],
         previous: &[NoticeOutput],
     ) -> CircuitBreakerResult {
-        let current_count = current.len();
-        let previous_count = previous.len();
+        self.check_counts(current.len(), previous.len())
+    }
+
+    /// Check each campus (by `metadata.campus`) on its own.
+    ///
+    /// Campuses present in either snapshot are included, so a campus that
+    /// vanished from the current crawl reports `EmptyResult`.
+    pub fn check_by_campus(
+        &self,
+        current: &[NoticeOutput],
+        previous: &[NoticeOutput],
+    ) -> HashMap<String, CircuitBreakerResult> {
+        let mut counts: HashMap<&str, (usize, usize)> = HashMap::new();
+        for notice in current {
+            counts.entry(&notice.metadata.campus).or_default().0 += 1;
+        }
+        for notice in previous {
+            counts.entry(&notice.metadata.campus).or_default().1 += 1;
+        }
+
+        counts
+            .into_iter()
+            .map(|(campus, (curr, prev))| (campus.to_string(), self.check_counts(curr, prev)))
+            .collect()
+    }
+
+    /// Apply the per-campus check according to `per_campus`.
+    ///
+    /// Only the campuses in `current` are checked, so a campus dropped from
+    /// the config is not reported as empty. `previous` holds the counts of
+    /// the last written crawl and `state` the per-campus empty runs for the
+    /// grace window. Nothing is held or aborted when `per_campus` is off.
+    pub fn check_campuses(
+        &self,
+        current: &BTreeMap<String, usize>,
+        previous: &BTreeMap<String, usize>,
+        state: &CircuitBreakerState,
+    ) -> CampusCheck {
+        let mut check = CampusCheck::default();
+        if self.config.per_campus == PerCampusMode::Off {
+            return check;
+        }
+
+        let mut tripped = Vec::new();
+        for (campus, &count) in current {
+            let previous_count = previous.get(campus).copied().unwrap_or(0);
+            let empty_runs = state.campus_empty.get(campus).copied().unwrap_or(0);
+            let result = self.apply_grace(self.check_counts(count, previous_count), empty_runs);
+            if matches!(result, CircuitBreakerResult::EmptyGrace { .. }) {
+                log::warn!(
+                    "Campus {} came back empty, keeping its stored notices",
+                    campus
+                );
+                check.held.insert(campus.clone());
+            } else if let Err(e) = self.evaluate(result) {
+                log::error!("Circuit breaker tripped for campus {}: {}", campus, e);
+                tripped.push((campus.clone(), e));
+            }
+        }
+
+        match self.config.per_campus {
+            PerCampusMode::Off => {}
+            PerCampusMode::Any => check.held.extend(tripped.into_iter().map(|(c, _)| c)),
+            PerCampusMode::All => {
+                if !current.is_empty() && tripped.len() == current.len() {
+                    check.abort = tripped.into_iter().next().map(|(_, e)| e);
+                }
+            }
+        }
+        check
+    }
 
+    /// Core threshold check on notice counts.
+    pub fn check_counts(
+        &self,
+        current_count: usize,
+        previous_count: usize,
+    ) -> CircuitBreakerResult {
         // Case 1: Empty current result
         if current_count == 0 {
             if previous_count == 0 && self.config.allow_cold_start {
@@ -108,6 +228,22 @@ impl CircuitBreaker {
             }
         }
 
+        // Case 4: Check gain percentage
+        if let Some(max_gain) = self.config.max_gain_percent
+            && current_count > previous_count
+        {
+            let gain = current_count - previous_count;
+            let gain_percent = (gain as f64 / previous_count as f64) * 100.0;
+
+            if gain_percent > max_gain as f64 {
+                return CircuitBreakerResult::SpikeTriggered {
+                    current_count,
+                    previous_count,
+                    gain_percent,
+                };
+            }
+        }
+
         // Safe to proceed
         CircuitBreakerResult::Safe {
             current_count,
@@ -115,9 +251,54 @@ impl CircuitBreaker {
         }
     }
 
+    /// Check with state from previous runs, tolerating transient empty results.
+    ///
+    /// An empty result that would be `EmptyResult` becomes `EmptyGrace` until
+    /// `empty_grace_runs` consecutive empty runs have been seen.
+    pub fn check_with_state(
+        &self,
+        current: &[NoticeOutput],
+        previous: &[NoticeOutput],
+        state: &CircuitBreakerState,
+    ) -> CircuitBreakerResult {
+        self.check_counts_with_state(current.len(), previous.len(), state)
+    }
+
+    /// [`check_with_state`](Self::check_with_state) on notice counts.
+    pub fn check_counts_with_state(
+        &self,
+        current_count: usize,
+        previous_count: usize,
+        state: &CircuitBreakerState,
+    ) -> CircuitBreakerResult {
+        self.apply_grace(
+            self.check_counts(current_count, previous_count),
+            state.consecutive_empty,
+        )
+    }
+
+    /// Turn `EmptyResult` into `EmptyGrace` while `empty_runs` is within
+    /// `empty_grace_runs`.
+    fn apply_grace(&self, result: CircuitBreakerResult, empty_runs: u8) -> CircuitBreakerResult {
+        match result {
+            CircuitBreakerResult::EmptyResult if empty_runs < self.config.empty_grace_runs => {
+                CircuitBreakerResult::EmptyGrace {
+                    consecutive_empty: empty_runs + 1,
+                    grace_runs: self.config.empty_grace_runs,
+                }
+            }
+            result => result,
+        }
+    }
+
     /// Validate and return Ok if safe, Err if circuit breaker triggered.
     pub fn validate(&self, current: &[NoticeOutput], previous: &[NoticeOutput]) -> Result<()> {
-        match self.check(current, previous) {
+        self.evaluate(self.check(current, previous))
+    }
+
+    /// Convert a check result into Ok if safe, Err if circuit breaker triggered.
+    pub fn evaluate(&self, result: CircuitBreakerResult) -> Result<()> {
+        match result {
             CircuitBreakerResult::Safe {
                 current_count,
                 previous_count,
@@ -155,6 +336,37 @@ impl CircuitBreaker {
                     threshold_percent: self.config.max_drop_percent,
                 })
             }
+            CircuitBreakerResult::SpikeTriggered {
+                current_count,
+                previous_count,
+                gain_percent,
+            } => {
+                let threshold_percent = self.config.max_gain_percent.unwrap_or_default();
+                log::error!(
+                    "Circuit breaker: SPIKE! {} → {} notices ({:.1}% gain > {}% threshold)",
+                    previous_count,
+                    current_count,
+                    gain_percent,
+                    threshold_percent
+                );
+                Err(AppError::CircuitBreakerSpike {
+                    current_count,
+                    previous_count,
+                    gain_percent,
+                    threshold_percent,
+                })
+            }
+            CircuitBreakerResult::EmptyGrace {
+                consecutive_empty,
+                grace_runs,
+            } => {
+                log::warn!(
+                    "Circuit breaker: EMPTY RESULT tolerated ({} of {} grace runs)",
+                    consecutive_empty,
+                    grace_runs
+                );
+                Ok(())
+            }
             CircuitBreakerResult::EmptyResult => {
                 log::error!("Circuit breaker: EMPTY RESULT - aborting write");
                 Err(AppError::EmptyCrawlResult)
@@ -185,8 +397,12 @@ mod tests {
                     college: "".into(),
                     department_name: "Dept".into(),
                     board_name: "Board".into(),
+                    author: String::new(),
                     date: "2026-02-02".into(),
                     pinned: false,
+                    lang: None,
+                    has_attachment: false,
+                    content_hash: None,
                 },
             })
             .collect()
@@ -228,6 +444,37 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_spike_triggered_when_gain_configured() {
+        let cb = CircuitBreaker::with_config(CircuitBreakerConfig {
+            max_gain_percent: Some(200),
+            ..CircuitBreakerConfig::default()
+        });
+        let previous = make_notices(100);
+        let current = make_notices(400); // 300% gain
+
+        assert!(matches!(
+            cb.check(&current, &previous),
+            CircuitBreakerResult::SpikeTriggered { gain_percent, .. } if gain_percent == 300.0
+        ));
+        assert!(matches!(
+            cb.validate(&current, &previous).unwrap_err(),
+            AppError::CircuitBreakerSpike {
+                threshold_percent: 200,
+                ..
+            }
+        ));
+
+        // Within the threshold, without it, or below baseline: unchanged
+        assert!(cb.validate(&make_notices(250), &previous).is_ok());
+        assert!(CircuitBreaker::new().validate(&current, &previous).is_ok());
+        assert!(cb.validate(&current, &make_notices(5)).is_ok());
+        assert!(matches!(
+            cb.check(&make_notices(70), &previous),
+            CircuitBreakerResult::Triggered { .. }
+        ));
+    }
+
     #[test]
     fn test_cold_start() {
         let cb = CircuitBreaker::new();
@@ -277,4 +524,140 @@ mod tests {
             AppError::CircuitBreakerTriggered { .. }
         ));
     }
+
+    #[test]
+    fn test_empty_grace_exhausted_after_n_runs() {
+        let cb = CircuitBreaker::with_config(CircuitBreakerConfig {
+            empty_grace_runs: 2,
+            ..CircuitBreakerConfig::default()
+        });
+        let previous = make_notices(100);
+        let mut state = CircuitBreakerState::default();
+
+        for run in 1..=2 {
+            assert!(matches!(
+                cb.check_with_state(&[], &previous, &state),
+                CircuitBreakerResult::EmptyGrace { consecutive_empty, .. } if consecutive_empty == run
+            ));
+            state = state.advance(0);
+        }
+
+        assert!(matches!(
+            cb.check_with_state(&[], &previous, &state),
+            CircuitBreakerResult::EmptyResult
+        ));
+    }
+
+    #[test]
+    fn test_empty_grace_resets_on_non_empty_run() {
+        let cb = CircuitBreaker::with_config(CircuitBreakerConfig {
+            empty_grace_runs: 1,
+            ..CircuitBreakerConfig::default()
+        });
+        let previous = make_notices(100);
+
+        let state = CircuitBreakerState::default().advance(0);
+        assert_eq!(state.consecutive_empty, 1);
+        assert!(matches!(
+            cb.check_with_state(&[], &previous, &state),
+            CircuitBreakerResult::EmptyResult
+        ));
+
+        let state = state.advance(100);
+        assert_eq!(state, CircuitBreakerState::default());
+        assert!(matches!(
+            cb.check_with_state(&[], &previous, &state),
+            CircuitBreakerResult::EmptyGrace { .. }
+        ));
+    }
+
+    fn counts(entries: &[(&str, usize)]) -> BTreeMap<String, usize> {
+        entries
+            .iter()
+            .map(|(campus, count)| (campus.to_string(), *count))
+            .collect()
+    }
+
+    fn campus_notices(campus: &str, count: usize) -> Vec<NoticeOutput> {
+        make_notices(count)
+            .into_iter()
+            .map(|mut n| {
+                n.id = format!("{}_{}", campus, n.id);
+                n.metadata.campus = campus.to_string();
+                n
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_per_campus_drop_masked_globally() {
+        let previous = [campus_notices("신촌", 100), campus_notices("미래", 100)].concat();
+        let current = [campus_notices("신촌", 200), campus_notices("미래", 0)].concat();
+        let breaker = |per_campus| {
+            CircuitBreaker::with_config(CircuitBreakerConfig {
+                per_campus,
+                ..CircuitBreakerConfig::default()
+            })
+        };
+
+        // Globally the count is unchanged
+        assert!(CircuitBreaker::new().validate(&current, &previous).is_ok());
+
+        let results = breaker(PerCampusMode::Any).check_by_campus(&current, &previous);
+        assert!(matches!(results["신촌"], CircuitBreakerResult::Safe { .. }));
+        assert!(matches!(results["미래"], CircuitBreakerResult::EmptyResult));
+
+        let previous = counts(&[("신촌", 100), ("미래", 100)]);
+        let current = counts(&[("신촌", 200), ("미래", 0)]);
+        let state = CircuitBreakerState::default();
+
+        let check = breaker(PerCampusMode::Off).check_campuses(&current, &previous, &state);
+        assert!(check.held.is_empty() && check.abort.is_none());
+
+        // "any" keeps the stored notices of the tripped campus only
+        let check = breaker(PerCampusMode::Any).check_campuses(&current, &previous, &state);
+        assert_eq!(check.held, BTreeSet::from(["미래".to_string()]));
+        assert!(check.abort.is_none());
+
+        let check = breaker(PerCampusMode::All).check_campuses(&current, &previous, &state);
+        assert!(check.held.is_empty() && check.abort.is_none());
+        let all_dropped = counts(&[("신촌", 10), ("미래", 0)]);
+        let check = breaker(PerCampusMode::All).check_campuses(&all_dropped, &previous, &state);
+        assert!(check.abort.is_some());
+    }
+
+    #[test]
+    fn test_per_campus_grace_and_removed_campus() {
+        let breaker = CircuitBreaker::with_config(CircuitBreakerConfig {
+            per_campus: PerCampusMode::All,
+            empty_grace_runs: 1,
+            ..CircuitBreakerConfig::default()
+        });
+        // 국제 was removed from the config, so the caller no longer lists it
+        let previous = counts(&[("신촌", 100), ("미래", 100), ("국제", 50)]);
+        let current = counts(&[("신촌", 100), ("미래", 0)]);
+
+        let state = CircuitBreakerState::default();
+        let check = breaker.check_campuses(&current, &previous, &state);
+        assert_eq!(check.held, BTreeSet::from(["미래".to_string()]));
+        assert!(check.abort.is_none());
+
+        // Grace used up: 미래 now trips, which "all" tolerates while 신촌 is fine
+        let state = state.advance_campuses(&current);
+        assert_eq!(state.campus_empty["미래"], 1);
+        let check = breaker.check_campuses(&current, &previous, &state);
+        assert!(check.held.is_empty() && check.abort.is_none());
+
+        let state = state.advance_campuses(&counts(&[("미래", 5)]));
+        assert!(state.campus_empty.is_empty());
+    }
+
+    #[test]
+    fn test_no_grace_by_default() {
+        let cb = CircuitBreaker::new();
+        assert!(matches!(
+            cb.check_with_state(&[], &make_notices(100), &CircuitBreakerState::default()),
+            CircuitBreakerResult::EmptyResult
+        ));
+    }
 }
@@ -5,26 +5,42 @@
 
 use std::sync::Arc;
 
-use chrono::Utc;
+use chrono::{DateTime, Utc};
 use reqwest::Client;
 
 use crate::error::Result;
-use crate::models::{Campus, Config, CrawlStats};
+use crate::models::{Campus, Config, CrawlOutcome, CrawlStats, Notice};
+use crate::pipeline::write_diff_artifact;
 use crate::services::NoticeCrawler;
 use crate::storage::NoticeStorage;
 
+/// Hook for transforming crawled notices before they are stored.
+///
+/// Invoked once per run, after deduplication and before the storage write.
+/// Use it to translate titles, redact PII, attach tags, etc.
+pub trait NoticePostProcessor: Send + Sync {
+    /// Transform the crawled notices.
+    fn process(&self, notices: Vec<Notice>) -> Vec<Notice>;
+}
+
 /// Run the notice crawler with full pipeline.
 ///
 /// This function:
 /// 1. Crawls notices from all discovered boards
-/// 2. Validates the result with Circuit Breaker
-/// 3. Calculates diff for notifications
-/// 4. Writes Hot/Cold data with Inverted Index
+/// 2. Applies the optional post-processor (identity when `None`)
+/// 3. Validates the result with Circuit Breaker
+/// 4. Calculates diff for notifications
+/// 5. Writes Hot/Cold data with Inverted Index
+///
+/// With `dry_run`, steps 3-5 are replaced by a logged summary of the
+/// outcome and storage is never written.
 pub async fn run_crawler(
     config: Arc<Config>,
     storage: &impl NoticeStorage,
     campuses: &[Campus],
     client: &Client,
+    post_processor: Option<&dyn NoticePostProcessor>,
+    dry_run: bool,
 ) -> Result<()> {
     let start_time = Utc::now();
 
@@ -42,11 +58,100 @@ pub async fn run_crawler(
     log::info!("Fetching notices from boards...");
 
     // Initialize the crawler with Config and Client
-    let crawler = NoticeCrawler::new(Arc::clone(&config), client.clone())?;
+    let mut crawler = NoticeCrawler::new(Arc::clone(&config), client.clone())?;
+    if config.crawler.conditional_requests {
+        crawler = crawler.with_http_cache(storage.load_http_cache().await?);
+    }
 
     // Run the crawler to fetch all notices
     let outcome = crawler.fetch_all(campuses).await?;
+
+    // Health is recorded even when the circuit breaker later rejects the write
+    let health =
+        outcome.health_report_with_threshold(campuses, config.crawler.health_min_success_rate);
+    for campus in health.unhealthy() {
+        log::warn!(
+            "Campus {} unhealthy: {}/{} boards fetched ({:.0}%)",
+            campus.campus,
+            campus.board_total - campus.board_failures,
+            campus.board_total,
+            campus.board_success_rate * 100.0
+        );
+    }
+    if !dry_run {
+        storage.save_health_report(&health).await?;
+    }
+
+    store_outcome(
+        storage,
+        campuses,
+        outcome,
+        start_time,
+        post_processor,
+        dry_run,
+    )
+    .await
+}
+
+/// Number of notice titles shown in a dry-run summary.
+const DRY_RUN_SAMPLE_TITLES: usize = 5;
+
+/// Log what a run would have stored.
+fn log_dry_run(outcome: &CrawlOutcome) {
+    log::info!(
+        "Dry run: {} notices from {}/{} boards ({} row and {} detail failures); nothing written",
+        outcome.notices.len(),
+        outcome.board_total.saturating_sub(outcome.board_failures),
+        outcome.board_total,
+        outcome.notice_failures,
+        outcome.detail_failures
+    );
+    for notice in outcome.notices.iter().take(DRY_RUN_SAMPLE_TITLES) {
+        log::info!(
+            "  [{}] {} ({})",
+            notice.board_name,
+            notice.title,
+            notice.date
+        );
+    }
+    for error in &outcome.errors {
+        log::warn!(
+            "  {:?} {}: {}",
+            error.stage,
+            error.url.as_deref().unwrap_or("-"),
+            error.message
+        );
+    }
+}
+
+/// Post-process a crawl outcome and write it to storage.
+async fn store_outcome(
+    storage: &impl NoticeStorage,
+    campuses: &[Campus],
+    mut outcome: CrawlOutcome,
+    start_time: DateTime<Utc>,
+    post_processor: Option<&dyn NoticePostProcessor>,
+    dry_run: bool,
+) -> Result<()> {
+    if let Some(processor) = post_processor {
+        let notices = std::mem::take(&mut outcome.notices);
+        outcome.notices = processor.process(notices);
+    }
+
+    // Drop invalid notices so they don't inflate counts seen by the circuit breaker
+    let invalid = outcome.retain_valid();
+    if invalid > 0 {
+        log::warn!("Dropped {} invalid notices before writing", invalid);
+    }
+
+    if dry_run {
+        log_dry_run(&outcome);
+        return Ok(());
+    }
+
     let end_time = Utc::now();
+    let total_depts: usize = campuses.iter().map(|c| c.department_count()).sum();
+    let total_boards: usize = campuses.iter().map(|c| c.board_count()).sum();
 
     // Calculate success rates
     let calc_rate = |total: usize, fail: usize| -> f32 {
@@ -57,7 +162,11 @@ pub async fn run_crawler(
         }
     };
 
+    let boards_fetched = outcome.board_total.saturating_sub(outcome.board_failures);
+    let elapsed_secs = (end_time - start_time).as_seconds_f32();
+
     let stats = CrawlStats {
+        schema_version: CrawlStats::SCHEMA_VERSION,
         start_time,
         end_time,
         notice_count: outcome.notices.len(),
@@ -72,6 +181,21 @@ pub async fn run_crawler(
         detail_total: outcome.detail_total,
         detail_failures: outcome.detail_failures,
         detail_success_rate: calc_rate(outcome.detail_total, outcome.detail_failures),
+        bytes_downloaded: outcome.bytes_downloaded,
+        bytes_parsed: outcome.bytes_parsed,
+        total_fetch_ms: outcome.total_fetch_ms,
+        total_parse_ms: outcome.total_parse_ms,
+        total_wait_ms: outcome.total_wait_ms,
+        avg_board_fetch_ms: outcome
+            .total_fetch_ms
+            .checked_div(boards_fetched as u64)
+            .unwrap_or(0),
+        notices_per_second: if elapsed_secs > 0.0 {
+            outcome.notices.len() as f32 / elapsed_secs
+        } else {
+            0.0
+        },
+        notices_filtered: outcome.notices_filtered,
     };
 
     // Write using Hot/Cold storage pattern with Circuit Breaker
@@ -83,11 +207,41 @@ pub async fn run_crawler(
         return Ok(());
     }
 
+    if metadata.write_skipped {
+        log::warn!("Empty crawl result within grace window - previous snapshot kept.");
+        return Ok(());
+    }
+
+    if !metadata.held_campuses.is_empty() {
+        log::warn!(
+            "Kept stored notices for campuses failing the circuit breaker: {}",
+            metadata.held_campuses.join(", ")
+        );
+    }
+
+    // Validators are only kept once the notices they vouch for are stored
+    if !outcome.http_cache.entries.is_empty() {
+        storage.save_http_cache(&outcome.http_cache).await?;
+    }
+
     log::info!(
         "Saved {} hot notices + {} cold archive files",
         metadata.hot_count,
         metadata.cold_files_updated
     );
+    log::info!(
+        "Transferred {} bytes on the wire, parsed {} bytes",
+        stats.bytes_downloaded,
+        stats.bytes_parsed
+    );
+    log::info!(
+        "Board lists: {} ms fetching, {} ms parsing, {} ms waiting ({} ms per board, {:.1} notices/s)",
+        stats.total_fetch_ms,
+        stats.total_parse_ms,
+        stats.total_wait_ms,
+        stats.avg_board_fetch_ms,
+        stats.notices_per_second
+    );
 
     // Log diff information for potential notifications
     if let Some(ref diff) = metadata.diff {
@@ -111,6 +265,8 @@ pub async fn run_crawler(
         } else {
             log::info!("No changes detected since last crawl");
         }
+
+        write_diff_artifact(storage, diff).await?;
     }
 
     log::info!("Crawler completed in {:.2?}", end_time - start_time);
@@ -126,3 +282,224 @@ pub async fn run_crawler(
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use async_trait::async_trait;
+
+    use super::*;
+    use crate::models::{HealthReport, HttpCache, NoticeOutput};
+    use crate::pipeline::{DiffResult, IndexManifest, InvertedIndex};
+    use crate::storage::{PruneResult, SnapshotInfo, WriteMetadata, WriteOptions};
+
+    /// Storage that records the notices it was asked to write.
+    #[derive(Default)]
+    struct RecordingStorage {
+        written: Mutex<CrawlOutcome>,
+    }
+
+    #[async_trait]
+    impl NoticeStorage for RecordingStorage {
+        async fn write_notices(
+            &self,
+            outcome: &CrawlOutcome,
+            campuses: &[Campus],
+            stats: &CrawlStats,
+        ) -> Result<WriteMetadata> {
+            self.write_notices_with_options(outcome, campuses, stats, &WriteOptions::safe())
+                .await
+        }
+
+        async fn write_notices_with_options(
+            &self,
+            outcome: &CrawlOutcome,
+            _campuses: &[Campus],
+            _stats: &CrawlStats,
+            _options: &WriteOptions,
+        ) -> Result<WriteMetadata> {
+            *self.written.lock().unwrap() = outcome.clone();
+            Ok(WriteMetadata {
+                hot_count: outcome.notices.len(),
+                cold_files_updated: 0,
+                archive_keys: Vec::new(),
+                timestamp: Utc::now(),
+                diff: None,
+                circuit_breaker_triggered: false,
+                write_skipped: false,
+                held_campuses: Vec::new(),
+            })
+        }
+
+        async fn load_current(&self) -> Result<Vec<NoticeOutput>> {
+            Ok(Vec::new())
+        }
+
+        async fn load_archive(&self, _year: i32, _month: u32) -> Result<Vec<NoticeOutput>> {
+            Ok(Vec::new())
+        }
+
+        async fn load_snapshot(&self, _version: &str) -> Result<Option<Vec<NoticeOutput>>> {
+            Ok(None)
+        }
+
+        async fn list_snapshots(&self) -> Result<Vec<SnapshotInfo>> {
+            Ok(Vec::new())
+        }
+
+        async fn load_index(&self) -> Result<Option<InvertedIndex>> {
+            Ok(None)
+        }
+
+        async fn save_index(&self, _index: &InvertedIndex) -> Result<()> {
+            Ok(())
+        }
+
+        async fn load_index_manifest(&self) -> Result<Option<IndexManifest>> {
+            Ok(None)
+        }
+
+        async fn load_index_shard(&self, _shard: &str) -> Result<Option<InvertedIndex>> {
+            Ok(None)
+        }
+
+        async fn load_campus_index(&self, _campus: &str) -> Result<Option<InvertedIndex>> {
+            Ok(None)
+        }
+
+        async fn save_campus_index(&self, _campus: &str, _index: &InvertedIndex) -> Result<()> {
+            Ok(())
+        }
+
+        async fn save_diff(&self, _diff: &DiffResult) -> Result<()> {
+            Ok(())
+        }
+
+        async fn load_diff(&self) -> Result<Option<DiffResult>> {
+            Ok(None)
+        }
+
+        async fn load_http_cache(&self) -> Result<HttpCache> {
+            Ok(HttpCache::default())
+        }
+
+        async fn save_http_cache(&self, _cache: &HttpCache) -> Result<()> {
+            Ok(())
+        }
+
+        async fn save_health_report(&self, _report: &HealthReport) -> Result<()> {
+            Ok(())
+        }
+
+        async fn prune_to_size(&self, _max_bytes: u64) -> Result<PruneResult> {
+            Ok(PruneResult::default())
+        }
+    }
+
+    struct UppercaseTitles;
+
+    impl NoticePostProcessor for UppercaseTitles {
+        fn process(&self, notices: Vec<Notice>) -> Vec<Notice> {
+            notices
+                .into_iter()
+                .map(|mut n| {
+                    n.title = n.title.to_uppercase();
+                    n
+                })
+                .collect()
+        }
+    }
+
+    fn sample_notice() -> Notice {
+        Notice {
+            campus: "TestCampus".to_string(),
+            college: "TestCollege".to_string(),
+            department_id: "dept1".to_string(),
+            department_name: "Department".to_string(),
+            board_id: "notice".to_string(),
+            board_name: "공지사항".to_string(),
+            title: "Exam schedule".to_string(),
+            author: "Admin".to_string(),
+            date: "2024-01-15".to_string(),
+            views: None,
+            link: "https://example.com/notice/1".to_string(),
+            source_id: None,
+            is_pinned: false,
+            date_inferred: false,
+            lang: None,
+            attachments: Vec::new(),
+        }
+    }
+
+    fn sample_outcome() -> CrawlOutcome {
+        CrawlOutcome {
+            notices: vec![sample_notice()],
+            notice_total: 1,
+            ..CrawlOutcome::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_post_processor_applied_before_storage() {
+        let storage = RecordingStorage::default();
+        store_outcome(
+            &storage,
+            &[],
+            sample_outcome(),
+            Utc::now(),
+            Some(&UppercaseTitles),
+            false,
+        )
+        .await
+        .unwrap();
+
+        let written = storage.written.lock().unwrap();
+        assert_eq!(written.notices.len(), 1);
+        assert_eq!(written.notices[0].title, "EXAM SCHEDULE");
+    }
+
+    #[tokio::test]
+    async fn test_no_post_processor_is_identity() {
+        let storage = RecordingStorage::default();
+        store_outcome(&storage, &[], sample_outcome(), Utc::now(), None, false)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            storage.written.lock().unwrap().notices[0].title,
+            "Exam schedule"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_dry_run_skips_storage() {
+        let storage = RecordingStorage::default();
+        store_outcome(&storage, &[], sample_outcome(), Utc::now(), None, true)
+            .await
+            .unwrap();
+
+        let written = storage.written.lock().unwrap();
+        assert!(written.notices.is_empty());
+        assert_eq!(written.notice_total, 0);
+    }
+
+    #[tokio::test]
+    async fn test_invalid_notices_removed_before_write() {
+        let mut outcome = sample_outcome();
+        let mut broken = sample_notice();
+        broken.link = "#".to_string();
+        outcome.notices.push(broken);
+        outcome.notice_total = 2;
+
+        let storage = RecordingStorage::default();
+        store_outcome(&storage, &[], outcome, Utc::now(), None, false)
+            .await
+            .unwrap();
+
+        let written = storage.written.lock().unwrap();
+        assert_eq!(written.notices.len(), 1);
+        assert_eq!(written.notice_total, 2);
+        assert_eq!(written.notice_failures, 1);
+    }
+}
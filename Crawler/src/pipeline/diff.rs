@@ -10,7 +10,9 @@ use std::collections::{HashMap, HashSet};
 
 use serde::{Deserialize, Serialize};
 
+use crate::error::Result;
 use crate::models::{Diff, NoticeOutput};
+use crate::storage::NoticeStorage;
 
 /// Extended diff result with full notice data.
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -21,6 +23,20 @@ pub struct DiffResult {
     pub added_notices: Vec<NoticeOutput>,
     /// Full notice objects for updated notices
     pub updated_notices: Vec<NoticeOutput>,
+    /// What changed in each updated notice, in `diff.updated` order
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub updated_changes: Vec<UpdatedNotice>,
+}
+
+/// Fields that changed in an updated notice.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UpdatedNotice {
+    /// Notice ID
+    pub id: String,
+    /// Changed fields: `title`, `date`, `pinned` (when pinned changes are
+    /// compared) or `content` (an edit seen only through `content_hash`,
+    /// such as the author)
+    pub changed_fields: Vec<String>,
 }
 
 impl DiffResult {
@@ -35,13 +51,20 @@ impl DiffResult {
     pub fn change_count(&self) -> usize {
         self.diff.added.len() + self.diff.updated.len() + self.diff.removed.len()
     }
+
+    /// Compact form with IDs only, for consumers that don't need payloads.
+    pub fn to_summary(&self) -> Diff {
+        self.diff.clone()
+    }
 }
 
 /// Calculator for computing diffs between snapshots.
 #[derive(Debug, Clone, Default)]
 pub struct DiffCalculator {
-    /// Whether to detect updates (title changes for same ID)
+    /// Whether to detect updates (title/date changes for same ID)
     detect_updates: bool,
+    /// Whether a change of only the pinned flag is ignored
+    ignore_pinned: bool,
 }
 
 impl DiffCalculator {
@@ -49,6 +72,7 @@ impl DiffCalculator {
     pub fn new() -> Self {
         Self {
             detect_updates: true,
+            ignore_pinned: true,
         }
     }
 
@@ -56,9 +80,49 @@ impl DiffCalculator {
     pub fn additions_only() -> Self {
         Self {
             detect_updates: false,
+            ignore_pinned: true,
         }
     }
 
+    /// Ignore pinned-status flips when detecting updates (the default).
+    ///
+    /// Admins rotate highlighted posts often, so a notice whose only change
+    /// is `pinned` is not reported as updated. Pass `false` to report such
+    /// flips with a `pinned` changed field.
+    pub fn ignore_pinned_changes(mut self, ignore: bool) -> Self {
+        self.ignore_pinned = ignore;
+        self
+    }
+
+    /// Fields that changed in a notice present in both snapshots.
+    ///
+    /// Title, date and (when enabled) pinned state are compared directly;
+    /// other edits (author) are caught by `content_hash` when both snapshots carry one.
+    /// Empty when the notice is unchanged.
+    fn changed_fields(&self, prev: &NoticeOutput, curr: &NoticeOutput) -> Vec<&'static str> {
+        let mut changed = Vec::new();
+        if prev.title != curr.title {
+            changed.push("title");
+        }
+        if prev.metadata.date != curr.metadata.date {
+            changed.push("date");
+        }
+        let pinned_changed = prev.metadata.pinned != curr.metadata.pinned;
+        if pinned_changed && !self.ignore_pinned {
+            changed.push("pinned");
+        }
+        // The hash covers the pinned flag, so it says nothing more after a flip
+        if changed.is_empty()
+            && !pinned_changed
+            && let (Some(prev_hash), Some(curr_hash)) =
+                (&prev.metadata.content_hash, &curr.metadata.content_hash)
+            && prev_hash != curr_hash
+        {
+            changed.push("content");
+        }
+        changed
+    }
+
     /// Calculate the diff between previous and current snapshots.
     pub fn calculate(&self, previous: &[NoticeOutput], current: &[NoticeOutput]) -> DiffResult {
         let prev_map: HashMap<&str, &NoticeOutput> =
@@ -87,25 +151,30 @@ impl DiffCalculator {
             .map(|id| id.to_string())
             .collect();
 
-        // Updated: in both but title changed
-        let (updated, updated_notices) = if self.detect_updates {
+        // Updated: in both but content changed
+        let (updated, updated_notices, updated_changes) = if self.detect_updates {
             let common: Vec<&str> = prev_ids.intersection(&curr_ids).copied().collect();
             let mut updated_ids = Vec::new();
             let mut updated_notices = Vec::new();
+            let mut updated_changes = Vec::new();
 
             for id in common {
                 let prev = prev_map.get(id).unwrap();
                 let curr = curr_map.get(id).unwrap();
 
-                // Check if title changed (could expand to other fields)
-                if prev.title != curr.title {
+                let changed = self.changed_fields(prev, curr);
+                if !changed.is_empty() {
                     updated_ids.push(id.to_string());
                     updated_notices.push((*curr).clone());
+                    updated_changes.push(UpdatedNotice {
+                        id: id.to_string(),
+                        changed_fields: changed.into_iter().map(String::from).collect(),
+                    });
                 }
             }
-            (updated_ids, updated_notices)
+            (updated_ids, updated_notices, updated_changes)
         } else {
-            (Vec::new(), Vec::new())
+            (Vec::new(), Vec::new(), Vec::new())
         };
 
         DiffResult {
@@ -116,6 +185,7 @@ impl DiffCalculator {
             },
             added_notices,
             updated_notices,
+            updated_changes,
         }
     }
 }
@@ -125,10 +195,29 @@ pub fn calculate_diff(previous: &[NoticeOutput], current: &[NoticeOutput]) -> Di
     DiffCalculator::new().calculate(previous, current)
 }
 
+/// Persist the full diff (including notice payloads) as the notifier's input.
+///
+/// Written to `notify_diff.json`, separate from the snapshot itself, so the
+/// notifier never has to recompute the diff or fetch notice bodies. The
+/// compact [`DiffResult::to_summary`] form goes to `diff.json`.
+pub async fn write_diff_artifact(storage: &impl NoticeStorage, diff: &DiffResult) -> Result<()> {
+    storage.save_diff(diff).await?;
+    log::info!(
+        "Diff artifact written: {} added, {} updated, {} removed",
+        diff.added_notices.len(),
+        diff.updated_notices.len(),
+        diff.diff.removed.len()
+    );
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
+    use tempfile::TempDir;
+
     use super::*;
     use crate::models::NoticeMetadata;
+    use crate::storage::LocalStorage;
 
     fn make_notice(id: &str, title: &str) -> NoticeOutput {
         NoticeOutput {
@@ -140,8 +229,12 @@ mod tests {
                 college: "".into(),
                 department_name: "Dept".into(),
                 board_name: "Board".into(),
+                author: String::new(),
                 date: "2026-02-02".into(),
                 pinned: false,
+                lang: None,
+                has_attachment: false,
+                content_hash: None,
             },
         }
     }
@@ -234,4 +327,129 @@ mod tests {
         assert!(result.diff.added.is_empty());
         assert_eq!(result.diff.removed.len(), 1);
     }
+
+    #[test]
+    fn test_ignore_pinned_changes() {
+        let prev = vec![make_notice("001", "Title"), make_notice("002", "Old")];
+        let mut curr = vec![make_notice("001", "Title"), make_notice("002", "New")];
+        curr[0].metadata.pinned = true;
+        curr[1].metadata.pinned = true;
+
+        let result = calculate_diff(&prev, &curr);
+        assert_eq!(result.diff.updated, vec!["002"]);
+        assert_eq!(result.updated_notices.len(), 1);
+        assert_eq!(result.updated_changes[0].changed_fields, vec!["title"]);
+    }
+
+    #[test]
+    fn test_pinned_flip_is_update_when_compared() {
+        let prev = vec![make_notice("001", "Title")];
+        let mut curr = prev.clone();
+        curr[0].metadata.pinned = true;
+
+        let result = DiffCalculator::new()
+            .ignore_pinned_changes(false)
+            .calculate(&prev, &curr);
+        assert_eq!(result.diff.updated, vec!["001"]);
+        assert_eq!(result.updated_changes[0].changed_fields, vec!["pinned"]);
+    }
+
+    #[test]
+    fn test_pinned_and_date_changes_report_fields() {
+        let prev = vec![
+            make_notice("001", "Pinned"),
+            make_notice("002", "Dated"),
+            make_notice("003", "Both"),
+        ];
+        let mut curr = prev.clone();
+        curr[0].metadata.pinned = true;
+        curr[1].metadata.date = "2026-02-03".into();
+        curr[2].title = "Both (edited)".into();
+        curr[2].metadata.date = "2026-02-03".into();
+
+        let result = DiffCalculator::new()
+            .ignore_pinned_changes(false)
+            .calculate(&prev, &curr);
+        let mut changes = result.updated_changes.clone();
+        changes.sort_by(|a, b| a.id.cmp(&b.id));
+        let fields: Vec<(&str, Vec<String>)> = changes
+            .iter()
+            .map(|c| (c.id.as_str(), c.changed_fields.clone()))
+            .collect();
+        assert_eq!(
+            fields,
+            [
+                ("001", vec!["pinned".to_string()]),
+                ("002", vec!["date".to_string()]),
+                ("003", vec!["title".to_string(), "date".to_string()]),
+            ]
+        );
+        assert_eq!(result.diff.updated.len(), 3);
+
+        let additions = DiffCalculator::additions_only().calculate(&prev, &curr);
+        assert!(additions.diff.updated.is_empty());
+        assert!(additions.updated_changes.is_empty());
+    }
+
+    #[test]
+    fn test_content_hash_change_is_update() {
+        let with_hash = |hash: Option<&str>| {
+            let mut notice = make_notice("001", "Title");
+            notice.metadata.content_hash = hash.map(str::to_string);
+            vec![notice]
+        };
+
+        let result = calculate_diff(&with_hash(Some("aaaa")), &with_hash(Some("bbbb")));
+        assert_eq!(result.diff.updated, vec!["001"]);
+
+        // Snapshots written before content hashes fall back to the title
+        let result = calculate_diff(&with_hash(None), &with_hash(Some("bbbb")));
+        assert!(result.diff.updated.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_diff_artifact_round_trip_keeps_payloads() {
+        let tmp = TempDir::new().unwrap();
+        let storage = LocalStorage::new(tmp.path());
+
+        let previous = vec![make_notice("1", "Old"), make_notice("2", "Gone")];
+        let current = vec![make_notice("1", "Renamed"), make_notice("3", "New")];
+        let diff = calculate_diff(&previous, &current);
+
+        write_diff_artifact(&storage, &diff).await.unwrap();
+        let loaded = storage.load_diff().await.unwrap().unwrap();
+
+        assert_eq!(loaded.diff.added, ["3"]);
+        assert_eq!(loaded.diff.updated, ["1"]);
+        assert_eq!(loaded.diff.removed, ["2"]);
+        assert_eq!(loaded.added_notices[0].title, "New");
+        assert_eq!(loaded.added_notices[0].link, "https://example.com/3");
+        assert_eq!(loaded.updated_notices[0].title, "Renamed");
+        assert!(tmp.path().join("notify_diff.json").exists());
+
+        let summary: Diff =
+            serde_json::from_slice(&std::fs::read(tmp.path().join("diff.json")).unwrap()).unwrap();
+        assert_eq!(summary.added, ["3"]);
+        assert_eq!(summary.updated, ["1"]);
+        assert_eq!(summary.removed, ["2"]);
+    }
+
+    #[test]
+    fn test_rich_and_summary_serialization() {
+        let previous = vec![make_notice("1", "Old"), make_notice("2", "Gone")];
+        let current = vec![make_notice("1", "Renamed"), make_notice("3", "New")];
+        let diff = calculate_diff(&previous, &current);
+
+        let rich = serde_json::to_value(&diff).unwrap();
+        assert_eq!(rich["diff"]["added"], serde_json::json!(["3"]));
+        assert_eq!(rich["added_notices"][0]["title"], "New");
+        assert_eq!(rich["updated_notices"][0]["title"], "Renamed");
+
+        let summary = serde_json::to_value(diff.to_summary()).unwrap();
+        let mut keys: Vec<_> = summary.as_object().unwrap().keys().collect();
+        keys.sort();
+        assert_eq!(keys, ["added", "removed", "updated"]);
+        assert_eq!(summary["updated"], serde_json::json!(["1"]));
+        assert_eq!(summary["removed"], serde_json::json!(["2"]));
+    }
 }
@@ -0,0 +1,375 @@
+//! Configuration diagnostics.
+//!
+//! Runs a battery of checks explaining why a crawl might return nothing:
+//! config, sitemap, board coverage, a live sample fetch and the stored
+//! snapshot. Each check reports pass/fail with a remediation hint.
+
+use std::path::Path;
+use std::sync::Arc;
+
+use reqwest::Client;
+
+use crate::models::{Campus, Config, Department};
+use crate::services::NoticeCrawler;
+use crate::storage::NoticeStorage;
+
+/// Outcome of a single diagnostic check.
+#[derive(Debug, Clone)]
+pub struct Check {
+    /// Short check name
+    pub name: &'static str,
+    /// Whether the check passed
+    pub passed: bool,
+    /// What was found
+    pub detail: String,
+    /// How to fix a failure
+    pub hint: Option<&'static str>,
+}
+
+impl Check {
+    fn pass(name: &'static str, detail: impl Into<String>) -> Self {
+        Self {
+            name,
+            passed: true,
+            detail: detail.into(),
+            hint: None,
+        }
+    }
+
+    fn fail(name: &'static str, detail: impl Into<String>, hint: &'static str) -> Self {
+        Self {
+            name,
+            passed: false,
+            detail: detail.into(),
+            hint: Some(hint),
+        }
+    }
+}
+
+/// All checks from one doctor run, in execution order.
+#[derive(Debug, Clone, Default)]
+pub struct DoctorReport {
+    pub checks: Vec<Check>,
+}
+
+impl DoctorReport {
+    /// Checks that failed.
+    pub fn failures(&self) -> impl Iterator<Item = &Check> {
+        self.checks.iter().filter(|c| !c.passed)
+    }
+
+    /// Whether every check passed.
+    pub fn is_healthy(&self) -> bool {
+        self.failures().next().is_none()
+    }
+}
+
+/// Run every check against the given config, sitemap and storage.
+pub async fn run_doctor(
+    config: Arc<Config>,
+    client: &Client,
+    storage: &impl NoticeStorage,
+    sitemap_path: &Path,
+) -> DoctorReport {
+    let (sitemap, campuses) = check_sitemap(sitemap_path);
+    let checks = vec![
+        check_config(&config),
+        sitemap,
+        check_boards(&campuses),
+        check_sample_board(config, client, &campuses).await,
+        check_storage(storage).await,
+    ];
+    DoctorReport { checks }
+}
+
+/// The config passes validation.
+pub fn check_config(config: &Config) -> Check {
+    match config.validate() {
+        Ok(()) => Check::pass("config", "config is valid"),
+        Err(e) => Check::fail(
+            "config",
+            e.to_string(),
+            "fix the reported field in config.toml (see `validate`)",
+        ),
+    }
+}
+
+/// The sitemap exists and parses. Returns the loaded campuses (empty on failure).
+pub fn check_sitemap(path: &Path) -> (Check, Vec<Campus>) {
+    if !path.exists() {
+        let check = Check::fail(
+            "sitemap",
+            format!("{} not found", path.display()),
+            "run `map` to discover departments and boards",
+        );
+        return (check, Vec::new());
+    }
+
+    match Campus::load_all(path) {
+        Ok(campuses) => {
+            let detail = format!("{} campuses in {}", campuses.len(), path.display());
+            (Check::pass("sitemap", detail), campuses)
+        }
+        Err(e) => {
+            let check = Check::fail(
+                "sitemap",
+                format!("{} does not parse: {}", path.display(), e),
+                "fix the JSON by hand or regenerate it with `map --force`",
+            );
+            (check, Vec::new())
+        }
+    }
+}
+
+/// Every campus has at least one enabled board.
+pub fn check_boards(campuses: &[Campus]) -> Check {
+    const HINT: &str = "re-run `map`, resolve `mapReview.json` items or `board enable` boards";
+
+    if campuses.is_empty() {
+        return Check::fail("boards", "no campuses to crawl", HINT);
+    }
+
+    let empty: Vec<&str> = campuses
+        .iter()
+        .filter(|c| {
+            !c.all_departments()
+                .iter()
+                .any(|d| d.dept.boards.iter().any(|b| b.enabled))
+        })
+        .map(|c| c.campus.as_str())
+        .collect();
+
+    if empty.is_empty() {
+        let total: usize = campuses.iter().map(|c| c.board_count()).sum();
+        Check::pass("boards", format!("{} boards", total))
+    } else {
+        Check::fail(
+            "boards",
+            format!("no enabled boards for: {}", empty.join(", ")),
+            HINT,
+        )
+    }
+}
+
+/// The first enabled board fetches and yields rows.
+pub async fn check_sample_board(
+    config: Arc<Config>,
+    client: &Client,
+    campuses: &[Campus],
+) -> Check {
+    let Some(sample) = sample_campus(campuses) else {
+        return Check::fail(
+            "sample fetch",
+            "no enabled board to sample",
+            "fix the `boards` check first",
+        );
+    };
+    let board = &sample.departments[0].boards[0];
+    let label = format!("{} ({})", board.name, board.url);
+
+    let outcome = match NoticeCrawler::new(config, client.clone()) {
+        Ok(crawler) => crawler.fetch_all(std::slice::from_ref(&sample)).await,
+        Err(e) => Err(e),
+    };
+
+    match outcome {
+        Err(e) => Check::fail(
+            "sample fetch",
+            format!("{}: {}", label, e),
+            "check network access and crawler settings",
+        ),
+        Ok(outcome) if outcome.board_failures > 0 => {
+            let reason = outcome
+                .errors
+                .first()
+                .map_or_else(String::new, |e| e.message.clone());
+            Check::fail(
+                "sample fetch",
+                format!("{}: {}", label, reason),
+                "check the board URL is reachable from this host",
+            )
+        }
+        Ok(outcome) if outcome.notice_total == 0 => Check::fail(
+            "sample fetch",
+            format!("{}: no rows matched", label),
+            "the board's row_selector does not match; update its selectors or CMS patterns",
+        ),
+        Ok(outcome) => Check::pass(
+            "sample fetch",
+            format!("{}: {} rows", label, outcome.notice_total),
+        ),
+    }
+}
+
+/// A campus holding only the first enabled board, so the probe crawls one
+/// board rather than the whole sitemap. That is still a full board crawl:
+/// robots.txt, every configured list page and any detail pages.
+fn sample_campus(campuses: &[Campus]) -> Option<Campus> {
+    campuses.iter().find_map(|campus| {
+        campus.all_departments().into_iter().find_map(|d| {
+            let board = d.dept.boards.iter().find(|b| b.enabled)?;
+            Some(Campus {
+                campus: campus.campus.clone(),
+                colleges: Vec::new(),
+                departments: vec![Department {
+                    boards: vec![board.clone()],
+                    ..d.dept.clone()
+                }],
+                source_url: None,
+            })
+        })
+    })
+}
+
+/// The stored snapshot and index (if any) can be read.
+pub async fn check_storage(storage: &impl NoticeStorage) -> Check {
+    const HINT: &str = "remove or restore the corrupt file; the next crawl rewrites it";
+
+    let current = match storage.load_current().await {
+        Ok(notices) => notices,
+        Err(e) => {
+            return Check::fail(
+                "storage",
+                format!("current snapshot unreadable: {}", e),
+                HINT,
+            );
+        }
+    };
+    if let Err(e) = storage.load_index().await {
+        return Check::fail("storage", format!("index unreadable: {}", e), HINT);
+    }
+
+    if current.is_empty() {
+        Check::pass("storage", "no snapshot yet")
+    } else {
+        Check::pass(
+            "storage",
+            format!("current snapshot has {} notices", current.len()),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::TempDir;
+
+    use super::*;
+    use crate::models::{Board, BoardSource, CmsSelectors, CrawlerConfig, FetchConfig};
+    use crate::storage::LocalStorage;
+    use crate::utils::test_server::{TestResponse, TestServer};
+
+    fn campus_with_board(url: &str, enabled: bool) -> Campus {
+        Campus {
+            campus: "신촌캠퍼스".to_string(),
+            colleges: Vec::new(),
+            departments: vec![Department {
+                id: "dept".to_string(),
+                name: "학생처".to_string(),
+                url: "https://example.com".to_string(),
+                boards: vec![Board {
+                    id: "notice".to_string(),
+                    name: "공지사항".to_string(),
+                    url: url.to_string(),
+                    selectors: CmsSelectors::default(),
+                    content_root_selector: None,
+                    link_base: None,
+                    enabled,
+                    fetch: FetchConfig::default(),
+                    source: BoardSource::Html,
+                }],
+            }],
+            source_url: None,
+        }
+    }
+
+    fn fast_config() -> Arc<Config> {
+        Arc::new(Config {
+            crawler: CrawlerConfig {
+                request_delay_ms: 0,
+                board_retry_base_ms: 0,
+                ..CrawlerConfig::default()
+            },
+            ..Config::default()
+        })
+    }
+
+    #[test]
+    fn test_check_config() {
+        assert!(check_config(&Config::default()).passed);
+
+        let mut broken = Config::default();
+        broken.crawler.max_concurrent = 0;
+        let check = check_config(&broken);
+        assert!(!check.passed);
+        assert!(check.hint.is_some());
+    }
+
+    #[test]
+    fn test_check_sitemap() {
+        let tmp = TempDir::new().unwrap();
+        let path = tmp.path().join("siteMap.json");
+
+        let (check, campuses) = check_sitemap(&path);
+        assert!(!check.passed);
+        assert!(campuses.is_empty());
+
+        std::fs::write(&path, "{ not json").unwrap();
+        assert!(!check_sitemap(&path).0.passed);
+
+        let sitemap = vec![campus_with_board("https://example.com/notice", true)];
+        std::fs::write(&path, serde_json::to_string(&sitemap).unwrap()).unwrap();
+        let (check, campuses) = check_sitemap(&path);
+        assert!(check.passed);
+        assert_eq!(campuses.len(), 1);
+    }
+
+    #[test]
+    fn test_check_boards() {
+        assert!(!check_boards(&[]).passed);
+        assert!(check_boards(&[campus_with_board("https://example.com/n", true)]).passed);
+
+        let check = check_boards(&[campus_with_board("https://example.com/n", false)]);
+        assert!(!check.passed);
+        assert!(check.detail.contains("신촌캠퍼스"));
+    }
+
+    #[tokio::test]
+    async fn test_check_sample_board() {
+        let server = TestServer::start(|req| match req.path.as_str() {
+            "/ok" => TestResponse::html(
+                r#"<table><tr><td><a href="/view?id=1">Notice</a></td><td>2024-01-01</td></tr></table>"#,
+            ),
+            "/empty" => TestResponse::html("<p>maintenance</p>"),
+            _ => TestResponse {
+                status: 500,
+                ..TestResponse::html("down")
+            },
+        });
+        let client = Client::new();
+        let probe = |path: &str| vec![campus_with_board(&server.url(path), true)];
+
+        let healthy = check_sample_board(fast_config(), &client, &probe("/ok")).await;
+        assert!(healthy.passed, "{}", healthy.detail);
+
+        let empty = check_sample_board(fast_config(), &client, &probe("/empty")).await;
+        assert!(!empty.passed);
+        assert!(empty.detail.contains("no rows"));
+
+        let down = check_sample_board(fast_config(), &client, &probe("/down")).await;
+        assert!(!down.passed);
+
+        assert!(!check_sample_board(fast_config(), &client, &[]).await.passed);
+    }
+
+    #[tokio::test]
+    async fn test_check_storage() {
+        let tmp = TempDir::new().unwrap();
+        let storage = LocalStorage::new(tmp.path());
+        assert!(check_storage(&storage).await.passed);
+
+        std::fs::write(tmp.path().join("current.json"), "{ truncated").unwrap();
+        let check = check_storage(&storage).await;
+        assert!(!check.passed);
+        assert!(check.detail.contains("current snapshot"));
+    }
+}
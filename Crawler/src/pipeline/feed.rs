@@ -0,0 +1,331 @@
+//! Feed generation.
+//!
+//! Renders a board's notices as an RSS 2.0 channel or a JSON Feed 1.1
+//! document for feed readers, and the site's notice pages as a sitemap.
+
+use chrono::{DateTime, FixedOffset, NaiveDate};
+use serde_json::json;
+
+use crate::models::NoticeOutput;
+use crate::utils::export::{
+    ExportFile, SITEMAP_MAX_BYTES, SITEMAP_MAX_URLS, escape_xml, split_sitemap,
+};
+
+/// Default number of items kept in a feed.
+pub const FEED_MAX_ITEMS: usize = 50;
+
+/// Build an RSS 2.0 feed with the newest [`FEED_MAX_ITEMS`] notices.
+pub fn build_rss(notices: &[NoticeOutput], channel_title: &str, base_link: &str) -> String {
+    build_rss_with_limit(notices, channel_title, base_link, FEED_MAX_ITEMS)
+}
+
+/// Build an RSS 2.0 feed with at most `max_items` notices, newest first.
+///
+/// Items are ordered by `metadata.date`. `pubDate` is midnight KST of that
+/// date; notices whose date does not parse come last and get none.
+pub fn build_rss_with_limit(
+    notices: &[NoticeOutput],
+    channel_title: &str,
+    base_link: &str,
+    max_items: usize,
+) -> String {
+    let mut xml = String::from(concat!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>"#,
+        "\n",
+        r#"<rss version="2.0">"#,
+        "\n<channel>\n"
+    ));
+    xml.push_str(&format!(
+        "  <title>{}</title>\n  <link>{}</link>\n  <description>{}</description>\n",
+        escape_xml(channel_title),
+        escape_xml(base_link),
+        escape_xml(channel_title)
+    ));
+    for (date, notice) in newest_first(notices, max_items) {
+        xml.push_str(&item_entry(notice, date));
+    }
+    xml.push_str("</channel>\n</rss>\n");
+    xml
+}
+
+/// Build a JSON Feed 1.1 document with the newest [`FEED_MAX_ITEMS`] notices.
+pub fn build_json_feed(notices: &[NoticeOutput], title: &str, home_page_url: &str) -> String {
+    build_json_feed_with_limit(notices, title, home_page_url, FEED_MAX_ITEMS)
+}
+
+/// Build a JSON Feed 1.1 document with at most `max_items` notices, ordered
+/// like [`build_rss_with_limit`].
+///
+/// `date_published` is midnight KST of `metadata.date`, omitted when the
+/// date does not parse.
+pub fn build_json_feed_with_limit(
+    notices: &[NoticeOutput],
+    title: &str,
+    home_page_url: &str,
+    max_items: usize,
+) -> String {
+    let items: Vec<_> = newest_first(notices, max_items)
+        .into_iter()
+        .map(|(date, notice)| {
+            let mut item = json!({
+                "id": notice.id,
+                "url": notice.link,
+                "title": notice.title,
+            });
+            if let Some(published) = date.and_then(published_at) {
+                item["date_published"] = json!(published.to_rfc3339());
+            }
+            item
+        })
+        .collect();
+
+    let feed = json!({
+        "version": "https://jsonfeed.org/version/1.1",
+        "title": title,
+        "home_page_url": home_page_url,
+        "items": items,
+    });
+    serde_json::to_string_pretty(&feed).expect("JSON values always serialize")
+}
+
+/// Up to `max_items` notices, newest first; undated notices sort last.
+fn newest_first(
+    notices: &[NoticeOutput],
+    max_items: usize,
+) -> Vec<(Option<NaiveDate>, &NoticeOutput)> {
+    let mut sorted: Vec<(Option<NaiveDate>, &NoticeOutput)> =
+        notices.iter().map(|n| (parse_date(n), n)).collect();
+    // `None` < `Some`, so reversing puts undated notices last
+    sorted.sort_by_key(|&(date, _)| std::cmp::Reverse(date));
+    sorted.truncate(max_items);
+    sorted
+}
+
+fn parse_date(notice: &NoticeOutput) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(&notice.metadata.date, "%Y-%m-%d").ok()
+}
+
+/// Publication time of a notice dated `date`: midnight KST, the boards'
+/// local time.
+fn published_at(date: NaiveDate) -> Option<DateTime<FixedOffset>> {
+    let kst = FixedOffset::east_opt(9 * 3600)?;
+    date.and_hms_opt(0, 0, 0)?.and_local_timezone(kst).single()
+}
+
+fn item_entry(notice: &NoticeOutput, date: Option<NaiveDate>) -> String {
+    let pub_date = date
+        .and_then(published_at)
+        .map(|dt| format!("<pubDate>{}</pubDate>", dt.to_rfc2822()))
+        .unwrap_or_default();
+    format!(
+        "  <item><title>{}</title><link>{}</link>{}<guid isPermaLink=\"false\">{}</guid></item>\n",
+        escape_xml(&notice.title),
+        escape_xml(&notice.link),
+        pub_date,
+        escape_xml(&notice.id)
+    )
+}
+
+/// Build `sitemap.xml` for our notice detail pages (`{base_url}/notice/{id}`),
+/// with `<lastmod>` from `metadata.date`.
+///
+/// Within protocol limits this is the `<urlset>` itself; past them it is the
+/// sitemap index, and [`build_sitemap_xml_files`] returns the parts as well.
+pub fn build_sitemap_xml(notices: &[NoticeOutput], base_url: &str) -> String {
+    build_sitemap_xml_files(notices, base_url)
+        .swap_remove(0)
+        .contents
+}
+
+/// All files of the detail-page sitemap: `sitemap.xml` first, followed by
+/// `sitemap-N.xml` parts when more than [`SITEMAP_MAX_URLS`] URLs or
+/// [`SITEMAP_MAX_BYTES`] bytes would go in one file.
+pub fn build_sitemap_xml_files(notices: &[NoticeOutput], base_url: &str) -> Vec<ExportFile> {
+    build_sitemap_xml_files_with_limits(notices, base_url, SITEMAP_MAX_URLS, SITEMAP_MAX_BYTES)
+}
+
+/// [`build_sitemap_xml_files`] with custom per-file limits.
+pub fn build_sitemap_xml_files_with_limits(
+    notices: &[NoticeOutput],
+    base_url: &str,
+    max_urls: usize,
+    max_bytes: usize,
+) -> Vec<ExportFile> {
+    let base = base_url.trim_end_matches('/');
+    split_sitemap(notices, base, max_urls, max_bytes, |notice| {
+        format!("{}/notice/{}", base, notice.id)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::sitemap::unescape_xml;
+    use crate::utils::test_fixtures::notice;
+
+    /// Parse `xml`, panicking unless it is well-formed, into the text of
+    /// each leaf element keyed by its path (`rss/channel/title`).
+    fn parse_xml(xml: &str) -> Vec<(String, String)> {
+        let mut stack: Vec<&str> = Vec::new();
+        let mut leaves = Vec::new();
+        for token in xmlparser::Tokenizer::from(xml) {
+            match token.unwrap_or_else(|e| panic!("{}: {}", e, xml)) {
+                xmlparser::Token::ElementStart { local, .. } => stack.push(local.as_str()),
+                xmlparser::Token::ElementEnd { end, .. } => match end {
+                    xmlparser::ElementEnd::Open => {}
+                    xmlparser::ElementEnd::Close(_, local) => {
+                        assert_eq!(stack.pop(), Some(local.as_str()), "{}", xml);
+                    }
+                    xmlparser::ElementEnd::Empty => {
+                        stack.pop();
+                    }
+                },
+                xmlparser::Token::Text { text } if !text.trim().is_empty() => {
+                    let text = text.as_str();
+                    for (at, _) in text.match_indices('&') {
+                        let entity = &text[at..];
+                        assert!(
+                            ["&amp;", "&lt;", "&gt;", "&quot;", "&apos;"]
+                                .iter()
+                                .any(|e| entity.starts_with(e)),
+                            "bare '&' in {}",
+                            text
+                        );
+                    }
+                    leaves.push((stack.join("/"), unescape_xml(text)));
+                }
+                _ => {}
+            }
+        }
+        assert!(stack.is_empty(), "unclosed elements {:?}", stack);
+        leaves
+    }
+
+    /// Text of every element at `path`, in document order.
+    fn texts<'a>(leaves: &'a [(String, String)], path: &str) -> Vec<&'a str> {
+        leaves
+            .iter()
+            .filter(|(p, _)| p == path)
+            .map(|(_, text)| text.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn test_rss_is_well_formed_and_sorted() {
+        let mut notices = vec![
+            notice(1, "2024-01-02"),
+            notice(2, "2024-03-05"),
+            notice(3, "unknown"),
+        ];
+        notices[1].title = "Notice <2> & more".to_string();
+        let xml = build_rss(&notices, "학생처 공지", "https://example.com/notice");
+
+        assert!(xml.starts_with(r#"<?xml version="1.0" encoding="UTF-8"?>"#));
+        let leaves = parse_xml(&xml);
+        assert_eq!(texts(&leaves, "rss/channel/title"), ["학생처 공지"]);
+        assert_eq!(
+            texts(&leaves, "rss/channel/item/title"),
+            ["Notice <2> & more", "Notice 1", "Notice 3"]
+        );
+        assert_eq!(
+            texts(&leaves, "rss/channel/item/link")[0],
+            "https://example.com/view?id=2&page=1"
+        );
+        assert_eq!(
+            texts(&leaves, "rss/channel/item/pubDate"),
+            [
+                "Tue, 5 Mar 2024 00:00:00 +0900",
+                "Tue, 2 Jan 2024 00:00:00 +0900"
+            ]
+        );
+        assert_eq!(
+            texts(&leaves, "rss/channel/item/guid"),
+            ["id_2", "id_1", "id_3"]
+        );
+    }
+
+    #[test]
+    fn test_json_feed_items() {
+        let notices = vec![
+            notice(1, "2024-01-02"),
+            notice(2, "2024-03-05"),
+            notice(3, "unknown"),
+        ];
+        let feed = build_json_feed_with_limit(&notices, "학생처 공지", "https://example.com", 2);
+        let value: serde_json::Value = serde_json::from_str(&feed).unwrap();
+
+        assert_eq!(value["version"], "https://jsonfeed.org/version/1.1");
+        assert_eq!(value["title"], "학생처 공지");
+        let items = value["items"].as_array().unwrap();
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0]["id"], "id_2");
+        assert_eq!(items[0]["url"], "https://example.com/view?id=2&page=1");
+        assert_eq!(items[0]["date_published"], "2024-03-05T00:00:00+09:00");
+
+        let all = build_json_feed(&notices, "Feed", "https://example.com");
+        let value: serde_json::Value = serde_json::from_str(&all).unwrap();
+        assert_eq!(value["items"].as_array().unwrap().len(), 3);
+        assert!(value["items"][2].get("date_published").is_none());
+    }
+
+    #[test]
+    fn test_rss_item_cap() {
+        let notices: Vec<_> = (1..=5)
+            .map(|i| notice(i, &format!("2024-01-0{}", i)))
+            .collect();
+        let xml = build_rss_with_limit(&notices, "Feed", "https://example.com", 2);
+        assert_eq!(xml.matches("<item>").count(), 2);
+        assert!(xml.contains("id_5") && xml.contains("id_4"));
+        assert!(!xml.contains("id_3"));
+    }
+
+    #[test]
+    fn test_sitemap_xml_points_at_detail_pages() {
+        let notices = vec![notice(1, "2026-02-01"), notice(2, "unknown")];
+        let xml = build_sitemap_xml(&notices, "https://uring.example.com/");
+
+        assert!(xml.contains(r#"<urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">"#));
+        assert!(xml.contains(
+            "<url><loc>https://uring.example.com/notice/id_1</loc><lastmod>2026-02-01</lastmod></url>"
+        ));
+        assert!(xml.contains("<url><loc>https://uring.example.com/notice/id_2</loc></url>"));
+        assert!(!xml.contains("example.com/view"));
+        parse_xml(&xml);
+    }
+
+    #[test]
+    fn test_sitemap_xml_splits_past_limit() {
+        let notices: Vec<_> = (0..3).map(|i| notice(i, "2026-02-01")).collect();
+        let files = build_sitemap_xml_files_with_limits(
+            &notices,
+            "https://uring.example.com",
+            2,
+            SITEMAP_MAX_BYTES,
+        );
+
+        let names: Vec<_> = files.iter().map(|f| f.name.as_str()).collect();
+        assert_eq!(names, ["sitemap.xml", "sitemap-1.xml", "sitemap-2.xml"]);
+        assert!(files[0].contents.contains("<sitemapindex"));
+        assert!(
+            files[0]
+                .contents
+                .contains("<loc>https://uring.example.com/sitemap-2.xml</loc>")
+        );
+        assert!(files[2].contents.contains("/notice/id_2</loc>"));
+        for file in &files {
+            parse_xml(&file.contents);
+        }
+
+        // Within limits the root file is the urlset itself
+        assert_eq!(
+            build_sitemap_xml(&notices, "https://uring.example.com"),
+            build_sitemap_xml_files_with_limits(
+                &notices,
+                "https://uring.example.com",
+                3,
+                SITEMAP_MAX_BYTES
+            )[0]
+            .contents
+        );
+    }
+}
@@ -7,39 +7,29 @@
 //! > mapping keywords to Announcement IDs.
 //! >
 //! > Example: `{"scholarship": ["id_001", "id_005"], "dorm": ["id_002"]}`
+//!
+//! Large indices can instead be split into hash-bucketed shards
+//! (`index/<shard>.json`) described by a small manifest
+//! (`index/manifest.json`). A token's shard is derived from its hash, so a
+//! client downloads only the shards its query touches.
 
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
 
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use unicode_segmentation::UnicodeSegmentation;
 
-use crate::models::NoticeOutput;
+use crate::models::{IndexConfig, NoticeOutput, TokenizeMode};
 
-/// Configuration for index generation.
-#[derive(Debug, Clone)]
-pub struct IndexConfig {
-    /// Minimum token length to include (default: 2)
-    pub min_token_length: usize,
-    /// Maximum tokens per notice (default: 50)
-    pub max_tokens_per_notice: usize,
-    /// Include metadata fields in indexing (campus, department, board)
-    pub index_metadata: bool,
-}
-
-impl Default for IndexConfig {
-    fn default() -> Self {
-        Self {
-            min_token_length: 2,
-            max_tokens_per_notice: 50,
-            index_metadata: true,
-        }
-    }
-}
+/// BM25 term-frequency saturation.
+const BM25_K1: f32 = 1.2;
+/// BM25 length normalization strength.
+const BM25_B: f32 = 0.75;
 
 /// Inverted index for full-text search.
 ///
 /// Maps normalized keywords to sets of notice IDs.
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
 pub struct InvertedIndex {
     /// Version for cache busting
     pub version: u32,
@@ -49,6 +39,218 @@ pub struct InvertedIndex {
     pub token_count: usize,
     /// The inverted index: keyword -> list of notice IDs
     pub index: HashMap<String, Vec<String>>,
+    /// Tokenization used to build the index; queries must use the same
+    #[serde(default, skip_serializing_if = "TokenizeMode::is_default")]
+    pub tokenize: TokenizeMode,
+    /// Token count of each notice, when built with `store_ranking_stats`.
+    /// Document frequencies are the posting list lengths, so they are not
+    /// stored separately
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub doc_lengths: BTreeMap<String, u32>,
+}
+
+impl InvertedIndex {
+    /// Notice IDs containing every token of `query` (AND semantics).
+    ///
+    /// The query is tokenized like indexed text: with this index's
+    /// [`TokenizeMode`] and the other settings of `config`, which should be
+    /// the configuration the index was built with. A query with no usable
+    /// tokens matches nothing.
+    pub fn search(&self, query: &str, config: &IndexConfig) -> Vec<String> {
+        self.ranked_matches(query, config, true)
+    }
+
+    /// Notice IDs containing any token of `query` (OR semantics), most
+    /// matching tokens first.
+    pub fn search_or(&self, query: &str, config: &IndexConfig) -> Vec<String> {
+        self.ranked_matches(query, config, false)
+    }
+
+    /// Notices matching any query token, scored with BM25, best first.
+    ///
+    /// Without stored ranking stats every notice is treated as average
+    /// length, so scores reduce to the summed IDF of the matched tokens.
+    pub fn search_ranked(
+        &self,
+        query: &str,
+        config: &IndexConfig,
+        limit: usize,
+    ) -> Vec<(String, f32)> {
+        let mut tokens = self.query_tokens(query, config);
+        tokens.sort();
+        tokens.dedup();
+
+        let total_docs = self.notice_count.max(1) as f32;
+        let avg_len = match self.doc_lengths.len() {
+            0 => 1.0,
+            n => self.doc_lengths.values().map(|&l| l as f32).sum::<f32>() / n as f32,
+        };
+
+        let mut scores: HashMap<&str, f32> = HashMap::new();
+        for ids in tokens.iter().filter_map(|token| self.index.get(token)) {
+            let doc_freq = ids.len() as f32;
+            let idf = ((total_docs - doc_freq + 0.5) / (doc_freq + 0.5) + 1.0).ln();
+            for id in ids {
+                let doc_len = self.doc_lengths.get(id).map_or(avg_len, |&l| l as f32);
+                // Posting lists are sets, so each matched token counts once
+                let norm = BM25_K1 * (1.0 - BM25_B + BM25_B * doc_len / avg_len);
+                *scores.entry(id.as_str()).or_default() += idf * (BM25_K1 + 1.0) / (1.0 + norm);
+            }
+        }
+
+        let mut ranked: Vec<(String, f32)> = scores
+            .into_iter()
+            .map(|(id, score)| (id.to_string(), score))
+            .collect();
+        ranked.sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        ranked.truncate(limit);
+        ranked
+    }
+
+    /// Tokenize a query the way this index was built.
+    fn query_tokens(&self, query: &str, config: &IndexConfig) -> Vec<String> {
+        query_tokens(query, self.tokenize, config)
+    }
+
+    /// Count matching query tokens per notice and sort by count, then ID.
+    fn ranked_matches(&self, query: &str, config: &IndexConfig, require_all: bool) -> Vec<String> {
+        let mut tokens = self.query_tokens(query, config);
+        tokens.sort();
+        tokens.dedup();
+        if tokens.is_empty() {
+            return Vec::new();
+        }
+
+        let mut counts: HashMap<&str, usize> = HashMap::new();
+        for ids in tokens.iter().filter_map(|token| self.index.get(token)) {
+            for id in ids {
+                *counts.entry(id.as_str()).or_default() += 1;
+            }
+        }
+
+        let mut matches: Vec<(&str, usize)> = counts
+            .into_iter()
+            .filter(|&(_, count)| !require_all || count == tokens.len())
+            .collect();
+        matches.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+        matches.into_iter().map(|(id, _)| id.to_string()).collect()
+    }
+
+    /// Split the index into at most `shard_count` shards (clamped to 1..=256).
+    ///
+    /// Each shard is an `InvertedIndex` over the tokens hashed to it (see
+    /// [`shard_name`]), with the ranking stats of the notices it references.
+    pub fn shard(&self, shard_count: usize) -> (IndexManifest, BTreeMap<String, InvertedIndex>) {
+        let manifest = IndexManifest {
+            version: self.version,
+            shard_count: shard_count.clamp(1, 256),
+            notice_count: self.notice_count,
+            token_count: self.token_count,
+            tokenize: self.tokenize,
+        };
+        let mut shards: BTreeMap<String, InvertedIndex> = BTreeMap::new();
+
+        for (token, ids) in &self.index {
+            let shard = shards
+                .entry(manifest.shard_for(token))
+                .or_insert_with(|| InvertedIndex {
+                    version: self.version,
+                    notice_count: self.notice_count,
+                    tokenize: self.tokenize,
+                    ..InvertedIndex::default()
+                });
+            shard.index.insert(token.clone(), ids.clone());
+            shard.token_count += 1;
+            for id in ids {
+                if let Some(&len) = self.doc_lengths.get(id) {
+                    shard.doc_lengths.insert(id.clone(), len);
+                }
+            }
+        }
+
+        (manifest, shards)
+    }
+
+    /// Reassemble an index (or the part of it a query needs) from shards
+    /// described by `manifest`.
+    ///
+    /// Counts come from the manifest, so ranking over a subset of shards
+    /// uses the same document totals as the full index.
+    pub fn from_shards(
+        manifest: &IndexManifest,
+        shards: impl IntoIterator<Item = InvertedIndex>,
+    ) -> Self {
+        let mut index = InvertedIndex {
+            version: manifest.version,
+            notice_count: manifest.notice_count,
+            token_count: manifest.token_count,
+            tokenize: manifest.tokenize,
+            ..InvertedIndex::default()
+        };
+        for shard in shards {
+            index.index.extend(shard.index);
+            index.doc_lengths.extend(shard.doc_lengths);
+        }
+        index
+    }
+}
+
+/// Description of a sharded index (`index/manifest.json`).
+///
+/// Tokens are assigned to shards by hash, so the manifest only records the
+/// shard count; it stays small however large the index grows.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct IndexManifest {
+    /// Version of the source index
+    pub version: u32,
+    /// Number of hash buckets the tokens are spread over
+    pub shard_count: usize,
+    /// Total number of indexed notices
+    pub notice_count: usize,
+    /// Total number of unique tokens
+    pub token_count: usize,
+    /// Tokenization used to build the index; queries must use the same
+    #[serde(default, skip_serializing_if = "TokenizeMode::is_default")]
+    pub tokenize: TokenizeMode,
+}
+
+impl IndexManifest {
+    /// Name of the shard that holds `token` if it is indexed.
+    pub fn shard_for(&self, token: &str) -> String {
+        shard_name(token, self.shard_count)
+    }
+
+    /// Names of every shard the index may have (empty buckets are not written).
+    pub fn shard_names(&self) -> Vec<String> {
+        (0..self.shard_count.clamp(1, 256))
+            .map(|bucket| format!("{:02x}", bucket))
+            .collect()
+    }
+
+    /// Whether `name` is a shard file stem (two lowercase hex digits).
+    pub fn is_shard_name(name: &str) -> bool {
+        name.len() == 2
+            && name
+                .chars()
+                .all(|c| c.is_ascii_digit() || ('a'..='f').contains(&c))
+    }
+}
+
+/// Shard name for `token`: a stable hash bucket as two hex digits.
+pub fn shard_name(token: &str, shard_count: usize) -> String {
+    let digest = Sha256::digest(token.as_bytes());
+    let bucket = usize::from(digest[0]) % shard_count.clamp(1, 256);
+    format!("{:02x}", bucket)
+}
+
+/// Tokenize a search query for an index built with `tokenize`, using the
+/// other settings of `config`.
+pub fn query_tokens(query: &str, tokenize_mode: TokenizeMode, config: &IndexConfig) -> Vec<String> {
+    let config = IndexConfig {
+        tokenize: tokenize_mode,
+        ..config.clone()
+    };
+    tokenize(query, &config)
 }
 
 /// Builder for constructing an inverted index.
@@ -56,6 +258,7 @@ pub struct IndexBuilder {
     config: IndexConfig,
     index: HashMap<String, HashSet<String>>,
     notice_count: usize,
+    doc_lengths: BTreeMap<String, u32>,
 }
 
 impl IndexBuilder {
@@ -70,6 +273,7 @@ impl IndexBuilder {
             config,
             index: HashMap::new(),
             notice_count: 0,
+            doc_lengths: BTreeMap::new(),
         }
     }
 
@@ -93,6 +297,9 @@ impl IndexBuilder {
 
         // Limit tokens per notice
         tokens.truncate(self.config.max_tokens_per_notice);
+        if self.config.store_ranking_stats {
+            self.doc_lengths.insert(id.clone(), tokens.len() as u32);
+        }
 
         // Add to index
         for token in tokens {
@@ -125,20 +332,14 @@ impl IndexBuilder {
             notice_count: self.notice_count,
             token_count,
             index,
+            tokenize: self.config.tokenize,
+            doc_lengths: self.doc_lengths,
         }
     }
 
     /// Tokenize a string into normalized keywords.
     fn tokenize(&self, text: &str) -> Vec<String> {
-        let normalized = text.to_lowercase();
-
-        // Use unicode-aware word segmentation
-        normalized
-            .unicode_words()
-            .filter(|word| word.len() >= self.config.min_token_length)
-            .filter(|word| !is_stopword(word))
-            .map(String::from)
-            .collect()
+        tokenize(text, &self.config)
     }
 }
 
@@ -148,6 +349,74 @@ impl Default for IndexBuilder {
     }
 }
 
+/// Tokenize a string into normalized keywords, shared by indexing and search.
+fn tokenize(text: &str, config: &IndexConfig) -> Vec<String> {
+    let normalized = text.to_lowercase();
+    let is_word = |word: &str| word.len() >= config.min_token_length && !is_stopword(word);
+
+    // Use unicode-aware word segmentation
+    let words = normalized.unicode_words();
+    match config.tokenize {
+        TokenizeMode::UnicodeWords => words.filter(|w| is_word(w)).map(String::from).collect(),
+        TokenizeMode::CharNgram { n } => {
+            let mut tokens = Vec::new();
+            for word in words {
+                for (cjk, run) in script_runs(word) {
+                    if cjk {
+                        tokens.extend(char_ngrams(run, n.max(1)));
+                    } else if is_word(run) {
+                        tokens.push(run.to_string());
+                    }
+                }
+            }
+            tokens
+        }
+    }
+}
+
+/// Split `word` into maximal runs of CJK and non-CJK characters.
+fn script_runs(word: &str) -> Vec<(bool, &str)> {
+    let mut runs: Vec<(bool, &str)> = Vec::new();
+    let mut start = 0;
+    let mut current = None;
+    for (i, c) in word.char_indices() {
+        let cjk = is_cjk(c);
+        match current {
+            Some(prev) if prev != cjk => {
+                runs.push((prev, &word[start..i]));
+                start = i;
+            }
+            _ => {}
+        }
+        current = Some(cjk);
+    }
+    if let Some(cjk) = current {
+        runs.push((cjk, &word[start..]));
+    }
+    runs
+}
+
+/// Overlapping `n`-character grams of `run`; a shorter run is kept whole.
+fn char_ngrams(run: &str, n: usize) -> Vec<String> {
+    let chars: Vec<char> = run.chars().collect();
+    if chars.len() <= n {
+        return vec![run.to_string()];
+    }
+    chars.windows(n).map(|w| w.iter().collect()).collect()
+}
+
+/// Hangul, CJK ideographs and kana.
+fn is_cjk(c: char) -> bool {
+    matches!(c,
+        '\u{1100}'..='\u{11FF}'
+        | '\u{3040}'..='\u{30FF}'
+        | '\u{3130}'..='\u{318F}'
+        | '\u{3400}'..='\u{4DBF}'
+        | '\u{4E00}'..='\u{9FFF}'
+        | '\u{AC00}'..='\u{D7A3}'
+    )
+}
+
 /// Check if a word is a common stopword (Korean/English).
 fn is_stopword(word: &str) -> bool {
     const STOPWORDS: &[&str] = &[
@@ -165,7 +434,12 @@ fn is_stopword(word: &str) -> bool {
 
 /// Build an inverted index from a list of notices.
 pub fn build_index(notices: &[NoticeOutput]) -> InvertedIndex {
-    let mut builder = IndexBuilder::new();
+    build_index_with_config(notices, &IndexConfig::default())
+}
+
+/// Build an inverted index from a list of notices with custom configuration.
+pub fn build_index_with_config(notices: &[NoticeOutput], config: &IndexConfig) -> InvertedIndex {
+    let mut builder = IndexBuilder::with_config(config.clone());
     builder.add_notices(notices);
     builder.build()
 }
@@ -185,8 +459,12 @@ mod tests {
                 college: "공과대학".into(),
                 department_name: "컴퓨터공학과".into(),
                 board_name: "학사공지".into(),
+                author: String::new(),
                 date: "2026-02-02".into(),
                 pinned: false,
+                lang: None,
+                has_attachment: false,
+                content_hash: None,
             },
         }
     }
@@ -236,6 +514,174 @@ mod tests {
         assert!(index.index.contains_key("fox"));
     }
 
+    #[test]
+    fn test_search_and_or() {
+        let notices = vec![
+            sample_notice("001", "장학금 신청 안내"),
+            sample_notice("002", "기숙사 입사 신청"),
+            sample_notice("003", "장학금 수령 방법"),
+        ];
+        let index = build_index(&notices);
+        let config = IndexConfig::default();
+
+        assert_eq!(index.search("장학금 신청", &config), ["001"]);
+        assert_eq!(index.search("Scholarship", &config), Vec::<String>::new());
+        assert_eq!(index.search("the", &config), Vec::<String>::new());
+        assert_eq!(
+            index.search_or("장학금 신청", &config),
+            ["001", "002", "003"]
+        );
+        assert_eq!(
+            index.search_or("기숙사 입사 장학금", &config),
+            ["002", "001", "003"]
+        );
+    }
+
+    fn ngram_index(notices: &[NoticeOutput], n: usize) -> InvertedIndex {
+        let mut builder = IndexBuilder::with_config(IndexConfig {
+            tokenize: TokenizeMode::CharNgram { n },
+            ..IndexConfig::default()
+        });
+        builder.add_notices(notices);
+        builder.build()
+    }
+
+    #[test]
+    fn test_char_ngram_tokenization() {
+        let config = IndexConfig {
+            tokenize: TokenizeMode::CharNgram { n: 2 },
+            ..IndexConfig::default()
+        };
+        assert_eq!(tokenize("장학금", &config), ["장학", "학금"]);
+        assert_eq!(
+            tokenize("The 2026학년도 Scholarship", &config),
+            ["2026", "학년", "년도", "scholarship"]
+        );
+        assert_eq!(tokenize("및 금", &config), ["및", "금"]);
+    }
+
+    #[test]
+    fn test_bigram_query_matches_compound_title() {
+        let notices = vec![
+            sample_notice("001", "2026학년도 장학금신청 안내"),
+            sample_notice("002", "기숙사 입사 신청"),
+        ];
+
+        // Word mode keeps the compound whole, so a substring misses it
+        let config = IndexConfig::default();
+        assert!(build_index(&notices).search("장학금", &config).is_empty());
+
+        let index = ngram_index(&notices, 2);
+        assert_eq!(index.search("장학금", &config), ["001"]);
+        assert_eq!(index.search("신청", &config), ["001", "002"]);
+
+        let trigram = ngram_index(&notices, 3);
+        assert_eq!(trigram.search("장학금", &config), ["001"]);
+        assert!(trigram.index.contains_key("학금신"));
+
+        let json = serde_json::to_value(&index).unwrap();
+        assert_eq!(json["tokenize"]["mode"], "char_ngram");
+        let restored: InvertedIndex = serde_json::from_value(json).unwrap();
+        assert_eq!(restored.search("장학금", &config), ["001"]);
+    }
+
+    #[test]
+    fn test_rare_term_outranks_common_term() {
+        let notices = vec![
+            sample_notice("001", "장학금 수령 방법"),
+            sample_notice("002", "장학금 신청"),
+            sample_notice("003", "장학금 발표"),
+            sample_notice("004", "기숙사 입사 안내"),
+        ];
+        let mut builder = IndexBuilder::with_config(IndexConfig {
+            store_ranking_stats: true,
+            ..IndexConfig::default()
+        });
+        builder.add_notices(&notices);
+        let index = builder.build();
+        assert_eq!(index.doc_lengths.len(), 4);
+        let config = IndexConfig::default();
+
+        let ranked = index.search_ranked("장학금 기숙사", &config, 10);
+        assert_eq!(ranked.len(), 4);
+        assert_eq!(ranked[0].0, "004");
+        assert!(ranked[0].1 > ranked[1].1);
+        assert_eq!(index.search_ranked("장학금 기숙사", &config, 2).len(), 2);
+
+        // Without stats the index JSON has no lengths, but ranking still works
+        let plain = build_index(&notices);
+        let json = serde_json::to_value(&plain).unwrap();
+        assert!(json.get("doc_lengths").is_none());
+        assert_eq!(plain.search_ranked("장학금 기숙사", &config, 1)[0].0, "004");
+    }
+
+    #[test]
+    fn test_sharded_lookup_matches_unsharded() {
+        let notices = vec![
+            sample_notice("001", "장학금 신청 안내"),
+            sample_notice("002", "기숙사 입사 신청"),
+            sample_notice("003", "scholarship deadline"),
+        ];
+        let index = build_index(&notices);
+        let (manifest, shards) = index.shard(4);
+
+        assert!(shards.len() > 1 && shards.len() <= 4);
+        assert_eq!(manifest.shard_count, 4);
+        assert_eq!(
+            shards.values().map(|s| s.token_count).sum::<usize>(),
+            index.token_count
+        );
+        // The manifest holds no per-token data
+        assert!(serde_json::to_string(&manifest).unwrap().len() < 100);
+
+        for (token, ids) in &index.index {
+            let name = manifest.shard_for(token);
+            assert_eq!(name, shard_name(token, 4));
+            assert_eq!(&shards[&name].index[token], ids);
+        }
+
+        let rebuilt = InvertedIndex::from_shards(&manifest, shards.into_values());
+        assert_eq!(rebuilt, index);
+    }
+
+    #[test]
+    fn test_query_over_loaded_shards_matches_full_index() {
+        let notices = vec![
+            sample_notice("001", "장학금 신청 안내"),
+            sample_notice("002", "기숙사 입사 신청"),
+            sample_notice("003", "장학금 기숙사 scholarship"),
+        ];
+        let index = build_index(&notices);
+        let (manifest, mut shards) = index.shard(8);
+
+        let query = "장학금 신청";
+        let config = IndexConfig::default();
+        let needed: Vec<InvertedIndex> = query_tokens(query, manifest.tokenize, &config)
+            .iter()
+            .filter_map(|token| shards.remove(&manifest.shard_for(token)))
+            .collect();
+        let partial = InvertedIndex::from_shards(&manifest, needed);
+
+        assert_eq!(partial.search(query, &config), index.search(query, &config));
+        assert_eq!(
+            partial.search_ranked(query, &config, 3),
+            index.search_ranked(query, &config, 3)
+        );
+    }
+
+    #[test]
+    fn test_query_uses_configured_min_token_length() {
+        let config = IndexConfig {
+            min_token_length: 3,
+            ..IndexConfig::default()
+        };
+        let index = build_index_with_config(&[sample_notice("001", "ab cde")], &config);
+
+        // "ab" was never indexed, so a query keeping it would match nothing
+        assert_eq!(index.search("ab cde", &config), ["001"]);
+        assert!(index.search("ab cde", &IndexConfig::default()).is_empty());
+    }
+
     #[test]
     fn test_min_token_length() {
         let notices = vec![sample_notice("001", "a b cd efg")];
@@ -2,12 +2,12 @@
 //!
 //! Department and board discovery pipeline.
 
-use std::sync::Arc;
+use std::path::Path;
 
 use futures::{StreamExt, stream};
 use reqwest::Client;
 
-use crate::error::Result;
+use crate::error::{AppError, Result};
 use crate::models::{Campus, Config, ManualReviewItem};
 use crate::services::{BoardDiscoveryService, DepartmentCrawler, SelectorDetector};
 
@@ -31,7 +31,11 @@ pub async fn run_mapper(config: &Config, client: &Client) -> Result<MapperResult
     // Step 1: Departments Discovery
     log::info!("[1/2] Discovering departments");
 
-    let dept_crawler = DepartmentCrawler::new(client);
+    let dept_crawler = DepartmentCrawler::new(client)
+        .with_heading_selectors(config.discovery.heading_selectors.clone())
+        .with_main_content_selectors(config.discovery.main_content_selectors.clone())
+        .with_id_prefix(config.discovery.id_prefix.clone())
+        .with_concurrency(config.crawler.max_concurrent);
     let mut campuses = dept_crawler.crawl_all(&config.campuses).await?;
 
     if campuses.is_empty() {
@@ -45,46 +49,18 @@ pub async fn run_mapper(config: &Config, client: &Client) -> Result<MapperResult
     // Step 2: Boards Discovery (Parallel Processing)
     log::info!("[2/2] Discovering boards");
 
-    let selector_detector = SelectorDetector::new(config.cms_patterns.clone());
-    let board_service = Arc::new(BoardDiscoveryService::new(
-        client,
-        config.keywords.clone(),
-        selector_detector,
-        &config.discovery,
-    ));
+    // One service for all campuses, so detected CMS patterns are shared
+    let board_service = board_service(config, client);
 
     let mut all_manual_reviews: Vec<ManualReviewItem> = Vec::new();
 
-    for campus in &mut campuses {
-        log::info!("Processing campus: {}", campus.campus);
-
-        for college in &mut campus.colleges {
-            let departments = std::mem::take(&mut college.departments);
-
-            let (processed_depts, reviews): (Vec<_>, Vec<_>) = stream::iter(departments)
-                .map(|mut dept| {
-                    let service = Arc::clone(&board_service);
-                    let campus_name = campus.campus.clone();
-                    let dept_name = dept.name.clone();
-
-                    async move {
-                        log::info!("Scanning: {}", dept_name);
-
-                        let result = service.discover(&campus_name, &dept.name, &dept.url).await;
-                        dept.boards = result.boards;
-
-                        log::info!("Found {} boards for {}", dept.boards.len(), dept_name);
-                        (dept, result.manual_review)
-                    }
-                })
-                .buffer_unordered(CONCURRENCY_LIMIT)
-                .collect::<Vec<_>>()
-                .await
-                .into_iter()
-                .unzip();
-
-            college.departments = processed_depts;
-            all_manual_reviews.extend(reviews.into_iter().flatten());
+    if config.discovery.concurrent_campuses {
+        let reviews = discover_boards(&board_service, campuses.iter_mut()).await;
+        all_manual_reviews.extend(reviews);
+    } else {
+        for campus in &mut campuses {
+            let reviews = discover_boards(&board_service, std::iter::once(campus)).await;
+            all_manual_reviews.extend(reviews);
         }
     }
 
@@ -109,3 +85,397 @@ pub async fn run_mapper(config: &Config, client: &Client) -> Result<MapperResult
         manual_reviews: all_manual_reviews,
     })
 }
+
+/// Discover the boards of every college department in `campuses`, scanning
+/// up to [`CONCURRENCY_LIMIT`] departments at once across all of them.
+async fn discover_boards<'c>(
+    service: &BoardDiscoveryService<'_>,
+    campuses: impl Iterator<Item = &'c mut Campus>,
+) -> Vec<ManualReviewItem> {
+    let mut colleges = Vec::new();
+    let mut jobs = Vec::new();
+    for campus in campuses {
+        log::info!("Processing campus: {}", campus.campus);
+        for college in &mut campus.colleges {
+            let departments = std::mem::take(&mut college.departments);
+            jobs.extend(
+                departments
+                    .into_iter()
+                    .map(|dept| (colleges.len(), campus.campus.clone(), dept)),
+            );
+            colleges.push(college);
+        }
+    }
+
+    let results: Vec<_> = stream::iter(jobs)
+        .map(|(college_index, campus_name, mut dept)| async move {
+            log::info!("Scanning: {}", dept.name);
+
+            let result = service.discover(&campus_name, &dept.name, &dept.url).await;
+            dept.boards = result.boards;
+
+            log::info!("Found {} boards for {}", dept.boards.len(), dept.name);
+            (college_index, dept, result.manual_review)
+        })
+        .buffer_unordered(CONCURRENCY_LIMIT)
+        .collect()
+        .await;
+
+    let mut reviews = Vec::new();
+    for (college_index, dept, review) in results {
+        colleges[college_index].departments.push(dept);
+        reviews.extend(review);
+    }
+    reviews
+}
+
+fn board_service<'a>(config: &Config, client: &'a Client) -> BoardDiscoveryService<'a> {
+    let selector_detector = SelectorDetector::new(config.cms_patterns.clone());
+    BoardDiscoveryService::new(
+        client,
+        config.keywords.clone(),
+        selector_detector,
+        &config.discovery,
+    )
+}
+
+/// Result of resolving manual review items.
+#[derive(Debug, Default)]
+pub struct ReviewResolution {
+    /// Items whose department was re-discovered and removed from the list
+    pub resolved: Vec<ManualReviewItem>,
+    /// Items that still need review, with an updated reason
+    pub pending: Vec<ManualReviewItem>,
+    /// Boards added to the sitemap
+    pub boards_added: usize,
+}
+
+/// Re-discover departments of review items matching `target` (name or URL).
+///
+/// The homepage is taken from the review item when it holds a valid URL
+/// (operators fix it there), otherwise from the sitemap department. Boards
+/// found are merged into the department, which must exist in `campuses`,
+/// and the item is removed from `reviews`; items that fail again stay in
+/// the list with the new reason.
+pub async fn resolve_reviews(
+    config: &Config,
+    client: &Client,
+    campuses: &mut [Campus],
+    reviews: &mut Vec<ManualReviewItem>,
+    target: &str,
+) -> Result<ReviewResolution> {
+    let (matched, rest): (Vec<_>, Vec<_>) = std::mem::take(reviews)
+        .into_iter()
+        .partition(|item| item.name == target || item.url == target);
+    *reviews = rest;
+
+    if matched.is_empty() {
+        return Err(AppError::config(format!(
+            "No manual review item matches '{}'",
+            target
+        )));
+    }
+
+    let service = board_service(config, client);
+    let mut resolution = ReviewResolution::default();
+
+    for item in matched {
+        let Some(dept) = campuses
+            .iter_mut()
+            .filter(|c| c.campus == item.campus)
+            .find_map(|c| c.department_mut(&item.name))
+        else {
+            log::warn!("Department '{}' not found in sitemap", item.name);
+            resolution.pending.push(item.clone());
+            reviews.push(item);
+            continue;
+        };
+
+        let url = if item.url.starts_with("http") {
+            item.url.clone()
+        } else {
+            dept.url.clone()
+        };
+
+        let result = service.discover(&item.campus, &item.name, &url).await;
+        if let Some(review) = result.manual_review {
+            log::warn!("{} still needs review: {}", review.name, review.reason);
+            resolution.pending.push(review.clone());
+            reviews.push(review);
+            continue;
+        }
+
+        let before = dept.boards.len();
+        dept.boards =
+            BoardDiscoveryService::merge_boards(std::mem::take(&mut dept.boards), result.boards);
+        dept.url = url;
+        resolution.boards_added += dept.boards.len() - before;
+        log::info!("Resolved {}: {} boards", item.name, dept.boards.len());
+        resolution.resolved.push(item);
+    }
+
+    Ok(resolution)
+}
+
+/// Persist a mapper result: the sitemap, plus manual review items if any.
+///
+/// Returns `true` when a review file was written.
+pub fn write_mapper_result(
+    result: &MapperResult,
+    sitemap_path: &Path,
+    review_path: &Path,
+) -> Result<bool> {
+    let json = serde_json::to_string_pretty(&result.campuses)?;
+    std::fs::write(sitemap_path, json)?;
+    log::info!("Sitemap saved to {}", sitemap_path.display());
+
+    if result.manual_reviews.is_empty() {
+        return Ok(false);
+    }
+
+    let review_json = serde_json::to_string_pretty(&result.manual_reviews)?;
+    std::fs::write(review_path, review_json)?;
+    log::warn!(
+        "{} items need manual review. See {}",
+        result.manual_reviews.len(),
+        review_path.display()
+    );
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use flate2::{Compression, write::GzEncoder};
+    use tempfile::TempDir;
+
+    use super::*;
+    use crate::models::{CampusInfo, DiscoveryConfig};
+    use crate::utils::test_server::{TestResponse, TestServer};
+
+    fn fixture_server() -> TestServer {
+        TestServer::start(|req| {
+            let base = format!("http://{}", req.headers["host"]);
+            let body = match req.path.as_str() {
+                "/campus" => format!(
+                    r#"<html><body><main>
+                    <h1>공과대학</h1>
+                    <h1>전기전자공학부</h1><a href="{base}/ee">홈페이지</a>
+                    <h1>화학공학과</h1>
+                    </main></body></html>"#
+                ),
+                "/ee" => {
+                    r#"<html><body><nav><a href="/ee/notice">공지사항</a></nav></body></html>"#
+                        .to_string()
+                }
+                _ => "<html><body><table><tr><td>empty</td></tr></table></body></html>".to_string(),
+            };
+            TestResponse::html(body)
+        })
+    }
+
+    fn fixture_config(server: &TestServer) -> Config {
+        Config {
+            campuses: vec![CampusInfo {
+                name: "신촌캠퍼스".to_string(),
+                url: server.url("/campus"),
+                fallback_urls: Vec::new(),
+            }],
+            ..Config::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_run_mapper_returns_campuses_and_reviews() {
+        let server = fixture_server();
+        let result = run_mapper(&fixture_config(&server), &Client::new())
+            .await
+            .unwrap();
+
+        assert_eq!(result.campuses.len(), 1);
+        let college = &result.campuses[0].colleges[0];
+        assert_eq!(college.name, "공과대학");
+
+        let ee = college
+            .departments
+            .iter()
+            .find(|d| d.name == "전기전자공학부")
+            .unwrap();
+        assert_eq!(ee.boards.len(), 1);
+        assert_eq!(ee.boards[0].id, "notice");
+        assert_eq!(ee.boards[0].url, server.url("/ee/notice"));
+
+        assert_eq!(result.manual_reviews.len(), 1);
+        assert_eq!(result.manual_reviews[0].name, "화학공학과");
+        assert_eq!(result.manual_reviews[0].reason, "Homepage URL is invalid");
+    }
+
+    #[tokio::test]
+    async fn test_resolve_review_removes_item_after_discovery() {
+        let server = fixture_server();
+        let config = fixture_config(&server);
+        let client = Client::new();
+        let MapperResult {
+            mut campuses,
+            manual_reviews: mut reviews,
+        } = run_mapper(&config, &client).await.unwrap();
+
+        // The operator fixes the homepage URL in the review list
+        reviews[0].url = server.url("/ee");
+
+        let resolution =
+            resolve_reviews(&config, &client, &mut campuses, &mut reviews, "화학공학과")
+                .await
+                .unwrap();
+
+        assert_eq!(resolution.resolved.len(), 1);
+        assert!(resolution.pending.is_empty());
+        assert_eq!(resolution.boards_added, 1);
+        assert!(reviews.is_empty());
+
+        let dept = campuses[0].department_mut("화학공학과").unwrap();
+        assert_eq!(dept.url, server.url("/ee"));
+        assert_eq!(dept.boards[0].id, "notice");
+
+        let missing = resolve_reviews(&config, &client, &mut campuses, &mut reviews, "화학공학과");
+        assert!(missing.await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_selector_detection_shared_across_campuses() {
+        for concurrent in [false, true] {
+            assert_selector_detection_shared(concurrent).await;
+        }
+    }
+
+    async fn assert_selector_detection_shared(concurrent: bool) {
+        let server = TestServer::start(|req| {
+            let base = format!("http://{}", req.headers["host"]);
+            let path = req.path.as_str();
+            let body = match path {
+                "/campus1" | "/campus2" => {
+                    let dept = if path == "/campus1" { "d1" } else { "d2" };
+                    format!(
+                        r#"<main><h1>공과대학</h1><h1>학과 {dept}</h1><a href="{base}/{dept}">홈페이지</a></main>"#
+                    )
+                }
+                "/d1" | "/d2" => format!(r#"<nav><a href="{path}/notice">공지사항</a></nav>"#),
+                _ => r#"<div class="xe-list-board"><table></table></div>"#.to_string(),
+            };
+            TestResponse::html(body)
+        });
+        let config = Config {
+            campuses: ["/campus1", "/campus2"]
+                .iter()
+                .map(|path| CampusInfo {
+                    name: path.trim_start_matches('/').to_string(),
+                    url: server.url(path),
+                    fallback_urls: Vec::new(),
+                })
+                .collect(),
+            discovery: DiscoveryConfig {
+                concurrent_campuses: concurrent,
+                ..DiscoveryConfig::default()
+            },
+            ..Config::default()
+        };
+
+        let result = run_mapper(&config, &Client::new()).await.unwrap();
+
+        let xe = Config::default()
+            .cms_patterns
+            .into_iter()
+            .find(|p| p.name == "xe_board")
+            .unwrap();
+        let boards: Vec<_> = result
+            .campuses
+            .iter()
+            .flat_map(|c| c.all_departments())
+            .flat_map(|d| &d.dept.boards)
+            .collect();
+        assert_eq!(boards.len(), 2);
+        assert!(
+            boards
+                .iter()
+                .all(|b| b.selectors.row_selector == xe.row_selector)
+        );
+
+        // Only one board page was fetched for detection, even when both
+        // departments miss the cache at once
+        let board_fetches = server
+            .requests()
+            .iter()
+            .filter(|r| r.path.ends_with("/notice"))
+            .count();
+        assert_eq!(board_fetches, 1);
+    }
+
+    #[tokio::test]
+    async fn test_xml_sitemap_boards_discovered() {
+        let server = TestServer::start(|req| {
+            let base = format!("http://{}", req.headers["host"]);
+            match req.path.as_str() {
+                "/campus" => TestResponse::html(format!(
+                    r#"<main><h1>공과대학</h1><h1>전기전자공학부</h1><a href="{base}/ee">홈페이지</a></main>"#
+                )),
+                "/sitemap.xml" => TestResponse::html(format!(
+                    "<sitemapindex><sitemap><loc>{base}/bbs.xml.gz</loc></sitemap></sitemapindex>"
+                )),
+                "/bbs.xml.gz" => {
+                    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+                    let urlset = format!(
+                        concat!(
+                            "<urlset>",
+                            "<url><loc>{base}/bbs/notice/view.do?articleNo=7</loc></url>",
+                            "<url><loc>{base}/bbs/notice/list.do?page=2</loc></url>",
+                            "<url><loc>{base}/bbs/notice/list.do</loc></url>",
+                            "<url><loc>{base}/bbs/notice/123</loc></url>",
+                            "<url><loc>{base}/about</loc></url>",
+                            "</urlset>"
+                        ),
+                        base = base
+                    );
+                    encoder.write_all(urlset.as_bytes()).unwrap();
+                    TestResponse {
+                        body: encoder.finish().unwrap(),
+                        ..TestResponse::html("")
+                    }
+                }
+                _ => TestResponse::html("<table><tr><td>empty</td></tr></table>"),
+            }
+        });
+        let mut config = fixture_config(&server);
+        config.discovery.probe_xml_sitemap = true;
+
+        let result = run_mapper(&config, &Client::new()).await.unwrap();
+
+        let boards: Vec<_> = result.campuses[0]
+            .all_departments()
+            .into_iter()
+            .flat_map(|d| d.dept.boards.clone())
+            .collect();
+        assert_eq!(boards.len(), 1);
+        assert_eq!(boards[0].id, "notice");
+        assert_eq!(boards[0].url, server.url("/bbs/notice/list.do"));
+    }
+
+    #[tokio::test]
+    async fn test_write_mapper_result() {
+        let server = fixture_server();
+        let result = run_mapper(&fixture_config(&server), &Client::new())
+            .await
+            .unwrap();
+
+        let tmp = TempDir::new().unwrap();
+        let sitemap_path = tmp.path().join("siteMap.json");
+        let review_path = tmp.path().join("mapReview.json");
+        assert!(write_mapper_result(&result, &sitemap_path, &review_path).unwrap());
+
+        let campuses = Campus::load_all(&sitemap_path).unwrap();
+        assert_eq!(campuses[0].board_count(), 1);
+        let reviews: Vec<ManualReviewItem> =
+            serde_json::from_str(&std::fs::read_to_string(&review_path).unwrap()).unwrap();
+        assert_eq!(reviews.len(), 1);
+    }
+}
@@ -6,20 +6,35 @@
 //! - `run_crawler`: Fetch notices from discovered boards
 //! - `circuit_breaker`: Prevent data corruption on abnormal drops
 //! - `diff`: Calculate changes between snapshots for notifications
+//! - `doctor`: Diagnose misconfigurations behind empty crawls
+//! - `feed`: Render a board's notices as an RSS or JSON feed
 //! - `index`: Build inverted index for serverless search
 
 pub mod circuit_breaker;
 pub mod crawl;
 pub mod diff;
+pub mod doctor;
+pub mod feed;
 pub mod index;
 
 #[cfg(feature = "map")]
 pub mod map;
 
-pub use circuit_breaker::{CircuitBreaker, CircuitBreakerConfig, CircuitBreakerResult};
-pub use crawl::run_crawler;
-pub use diff::{DiffCalculator, DiffResult, calculate_diff};
-pub use index::{IndexBuilder, IndexConfig, InvertedIndex, build_index};
+pub use circuit_breaker::{CampusCheck, CircuitBreaker, CircuitBreakerResult, CircuitBreakerState};
+pub use crawl::{NoticePostProcessor, run_crawler};
+pub use diff::{DiffCalculator, DiffResult, UpdatedNotice, calculate_diff, write_diff_artifact};
+pub use doctor::{Check, DoctorReport, run_doctor};
+pub use feed::{
+    FEED_MAX_ITEMS, build_json_feed, build_json_feed_with_limit, build_rss, build_rss_with_limit,
+    build_sitemap_xml, build_sitemap_xml_files, build_sitemap_xml_files_with_limits,
+};
+pub use index::{
+    IndexBuilder, IndexManifest, InvertedIndex, build_index, build_index_with_config, query_tokens,
+    shard_name,
+};
+
+// Configuration sections live in `models`; re-exported for existing paths
+pub use crate::models::{CircuitBreakerConfig, IndexConfig, PerCampusMode, TokenizeMode};
 
 #[cfg(feature = "map")]
-pub use map::{MapperResult, run_mapper};
+pub use map::{MapperResult, ReviewResolution, resolve_reviews, run_mapper, write_mapper_result};
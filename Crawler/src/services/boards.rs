@@ -4,6 +4,7 @@
 //! and matching against known keywords.
 
 use std::collections::{HashMap, HashSet};
+use std::sync::LazyLock;
 
 use futures::future;
 use regex::Regex;
@@ -12,16 +13,24 @@ use scraper::{Html, Selector};
 
 use crate::error::Result;
 use crate::models::{
-    Board, BoardDiscoveryResult, CmsSelectors, DiscoveryConfig, KeywordMapping, ManualReviewItem,
+    Board, BoardDiscoveryResult, BoardSource, CmsSelectors, DiscoveryConfig, FetchConfig,
+    KeywordMapping, ManualReviewItem,
 };
-use crate::services::SelectorDetector;
-use crate::utils::{get_domain, http::fetch_page_async, resolve};
+use crate::services::{DetectedCms, SelectorCache, SelectorDetector};
+use crate::utils::http::{PageLimits, fetch_page_async};
+use crate::utils::rss::feed_links;
+use crate::utils::sitemap::{SitemapLimits, collect_sitemap_urls};
+use crate::utils::{extract_notice_id, get_domain, resolve};
+
+/// Upper bound on boards taken from one department's XML sitemap.
+const MAX_SITEMAP_BOARDS: usize = 20;
 
 /// Service for discovering boards on department websites.
 pub struct BoardDiscoveryService<'a> {
     client: &'a Client,
     keywords: Vec<KeywordMapping>,
     selector_detector: SelectorDetector,
+    selector_cache: SelectorCache,
     config: DiscoveryConfig,
 }
 
@@ -37,6 +46,7 @@ impl<'a> BoardDiscoveryService<'a> {
             client,
             keywords,
             selector_detector,
+            selector_cache: SelectorCache::new(),
             config: config.clone(),
         }
     }
@@ -77,10 +87,14 @@ impl<'a> BoardDiscoveryService<'a> {
 
         let default_selectors = self.selector_detector.detect(&document, dept_url);
 
+        // Feeds advertised in <head> are the most reliable board source
+        let feed_boards = self.feed_boards(&document, dept_url);
+
         // Extract boards from homepage
         let homepage_boards = self
             .extract_boards(&document, dept_url, &default_selectors)
             .await;
+        let homepage_boards = Self::merge_boards(feed_boards, homepage_boards);
 
         // Try sitemap and merge results (instead of fallback-only)
         let sitemap_boards = if let Some(sitemap_doc) = self.find_sitemap(&document, dept_url).await
@@ -94,6 +108,12 @@ impl<'a> BoardDiscoveryService<'a> {
         // Merge boards from both sources, deduplicating by URL
         result.boards = Self::merge_boards(homepage_boards, sitemap_boards);
 
+        if self.config.probe_xml_sitemap
+            && let Some(xml_boards) = self.xml_sitemap_boards(dept_url, &default_selectors).await
+        {
+            result.boards = Self::merge_boards(std::mem::take(&mut result.boards), xml_boards);
+        }
+
         // If no boards found at all, mark for manual review
         if result.boards.is_empty() {
             result.manual_review = Some(ManualReviewItem {
@@ -112,7 +132,7 @@ impl<'a> BoardDiscoveryService<'a> {
     }
 
     /// Merge boards from multiple sources, deduplicating by URL.
-    fn merge_boards(primary: Vec<Board>, secondary: Vec<Board>) -> Vec<Board> {
+    pub(crate) fn merge_boards(primary: Vec<Board>, secondary: Vec<Board>) -> Vec<Board> {
         use std::collections::HashSet;
         let mut seen_urls: HashSet<String> = HashSet::new();
         let mut merged = Vec::new();
@@ -146,18 +166,148 @@ impl<'a> BoardDiscoveryService<'a> {
                 continue;
             }
 
-            if let Some(href) = element.value().attr("href") {
-                if let Some(sitemap_url) = resolve(base_url, href) {
-                    if let Ok(sitemap_doc) = fetch_page_async(self.client, &sitemap_url).await {
-                        log::debug!("Found sitemap: {}", sitemap_url);
-                        return Some(sitemap_doc);
-                    }
-                }
+            if let Some(href) = element.value().attr("href")
+                && let Some(sitemap_url) = resolve(base_url, href)
+                && let Ok(sitemap_doc) = fetch_page_async(self.client, &sitemap_url).await
+            {
+                log::debug!("Found sitemap: {}", sitemap_url);
+                return Some(sitemap_doc);
             }
         }
         None
     }
 
+    /// Boards from the site's XML sitemap (`/sitemap.xml`, else `/sitemap.xml.gz`).
+    ///
+    /// Sitemaps carry no link text, so a URL becomes a board when a path
+    /// segment equals a keyword ID (e.g. `/bbs/notice/list.do` -> `notice`).
+    /// Sitemaps list articles too, so only list pages (see
+    /// [`Self::is_list_like`]) qualify, one per keyword, preferring URLs
+    /// without a query, and at most [`MAX_SITEMAP_BOARDS`] in total. Returns
+    /// `None` when no URL qualifies.
+    async fn xml_sitemap_boards(
+        &self,
+        dept_url: &str,
+        default_selectors: &Option<CmsSelectors>,
+    ) -> Option<Vec<Board>> {
+        let limits = SitemapLimits {
+            max_depth: self.config.max_sitemap_depth,
+            max_fetches: self.config.max_sitemap_fetches,
+            max_bytes: PageLimits::default().max_body_bytes,
+        };
+
+        let mut urls = Vec::new();
+        for path in ["/sitemap.xml", "/sitemap.xml.gz"] {
+            if let Some(sitemap_url) = resolve(dept_url, path) {
+                urls = collect_sitemap_urls(self.client, &sitemap_url, &limits).await;
+            }
+            if !urls.is_empty() {
+                break;
+            }
+        }
+
+        let base_domain = get_domain(dept_url);
+        let mut candidates: Vec<(&KeywordMapping, String)> = urls
+            .into_iter()
+            .filter(|url| {
+                get_domain(url) == base_domain
+                    && self.is_valid_board_link("", url)
+                    && Self::is_list_like(url)
+            })
+            .filter_map(|url| Some((self.keyword_for_path(&url)?, url)))
+            .collect();
+        // Stable: sitemap order is kept among URLs with or without a query
+        candidates.sort_by_key(|(_, url)| url.contains('?'));
+
+        let mut seen_ids = HashSet::new();
+        candidates.retain(|(mapping, _)| seen_ids.insert(mapping.id.clone()));
+        candidates.truncate(MAX_SITEMAP_BOARDS);
+        if candidates.is_empty() {
+            return None;
+        }
+
+        let detections = candidates
+            .iter()
+            .map(|(_, url)| self.detect_board_selectors(url, default_selectors));
+        let selectors = future::join_all(detections).await;
+
+        let boards: Vec<Board> = candidates
+            .into_iter()
+            .zip(selectors)
+            .filter_map(|((mapping, url), selectors)| {
+                Some(Board {
+                    id: mapping.id.clone(),
+                    name: mapping.display_name.clone(),
+                    url,
+                    selectors: selectors?,
+                    content_root_selector: None,
+                    link_base: None,
+                    enabled: true,
+                    fetch: FetchConfig::default(),
+                    source: BoardSource::Html,
+                })
+            })
+            .collect();
+        (!boards.is_empty()).then_some(boards)
+    }
+
+    /// Whether a sitemap URL looks like a board list rather than an article:
+    /// it carries no notice ID (`?articleNo=5`, `/view/5`) and is not a
+    /// view/detail page.
+    fn is_list_like(url: &str) -> bool {
+        static ARTICLE_PAGE: LazyLock<Regex> =
+            LazyLock::new(|| Regex::new(r"(?i)(view|read|detail|article)[^/]*$").unwrap());
+        let path = url.split(['?', '#']).next().unwrap_or(url);
+        extract_notice_id(url).is_none() && !ARTICLE_PAGE.is_match(path)
+    }
+
+    /// Boards for RSS/Atom feeds linked from the page head.
+    ///
+    /// Only a feed whose title contains a keyword becomes a board, taking
+    /// that keyword's ID and name; site-wide or unrelated feeds (news, blog
+    /// comments) are ignored.
+    fn feed_boards(&self, document: &Html, dept_url: &str) -> Vec<Board> {
+        let mut id_counts: HashMap<String, usize> = HashMap::new();
+        feed_links(document, dept_url)
+            .into_iter()
+            .filter(|feed| self.is_valid_board_link(&feed.title, &feed.url))
+            .filter_map(|feed| {
+                let mapping = self.keywords.iter().find(|m| m.matches(&feed.title))?;
+                Some((mapping.id.clone(), mapping.display_name.clone(), feed))
+            })
+            .map(|(base_id, name, feed)| {
+                let count = id_counts.entry(base_id.clone()).or_insert(0);
+                *count += 1;
+                let id = match *count {
+                    1 => base_id,
+                    n => format!("{}_{}", base_id, n),
+                };
+                Board {
+                    id,
+                    name,
+                    url: feed.url,
+                    selectors: CmsSelectors::rss(),
+                    content_root_selector: None,
+                    link_base: None,
+                    enabled: true,
+                    fetch: FetchConfig::default(),
+                    source: BoardSource::Rss,
+                }
+            })
+            .collect()
+    }
+
+    /// Keyword mapping whose ID equals a path segment of `url`.
+    fn keyword_for_path(&self, url: &str) -> Option<&KeywordMapping> {
+        let parsed = url::Url::parse(url).ok()?;
+        let segments: Vec<String> = parsed
+            .path()
+            .split(['/', '.', '_', '-'])
+            .map(str::to_lowercase)
+            .collect();
+        self.keywords.iter().find(|m| segments.contains(&m.id))
+    }
+
     fn is_valid_board_link(&self, text: &str, href: &str) -> bool {
         if self
             .config
@@ -196,10 +346,10 @@ impl<'a> BoardDiscoveryService<'a> {
                     continue;
                 }
 
-                if let (Some(base_dom), Some(link_dom)) = (&base_domain, get_domain(&full_url)) {
-                    if base_dom != &link_dom {
-                        continue;
-                    }
+                if let (Some(base_dom), Some(link_dom)) = (&base_domain, get_domain(&full_url))
+                    && base_dom != &link_dom
+                {
+                    continue;
                 }
 
                 if seen_urls.insert(full_url.clone()) {
@@ -216,7 +366,7 @@ impl<'a> BoardDiscoveryService<'a> {
         let results: Vec<_> = future::join_all(board_futures).await;
         results
             .into_iter()
-            .filter_map(|b| b)
+            .flatten()
             .fold(Vec::new(), |mut acc, mut board| {
                 let count = id_counts.entry(board.id.clone()).or_insert(0);
                 *count += 1;
@@ -234,7 +384,7 @@ impl<'a> BoardDiscoveryService<'a> {
         url: String,
         default_selectors: &Option<CmsSelectors>,
     ) -> Option<Board> {
-        let mapping = self.keywords.iter().find(|m| text.contains(&m.keyword))?;
+        let mapping = self.keywords.iter().find(|m| m.matches(&text))?;
         let selectors = self.detect_board_selectors(&url, default_selectors).await?;
         let board_name = if text.is_empty() {
             mapping.display_name.clone()
@@ -246,6 +396,11 @@ impl<'a> BoardDiscoveryService<'a> {
             name: board_name,
             url,
             selectors,
+            content_root_selector: None,
+            link_base: None,
+            enabled: true,
+            fetch: FetchConfig::default(),
+            source: BoardSource::Html,
         })
     }
 
@@ -258,12 +413,27 @@ impl<'a> BoardDiscoveryService<'a> {
             return Some(selectors.clone());
         }
 
-        if let Ok(board_doc) = fetch_page_async(self.client, url).await {
-            if let Some(selectors) = self.selector_detector.detect(&board_doc, url) {
-                return Some(selectors);
+        // Board pages on a domain whose CMS is already cached are not
+        // fetched again for detection
+        let detected = match get_domain(url) {
+            Some(domain) => {
+                let pattern = self.selector_detector.url_pattern(url);
+                self.selector_cache
+                    .get_or_detect(&domain, pattern, || self.detect_page(url))
+                    .await
             }
+            None => self.detect_page(url).await,
+        };
+        if let Some(detected) = detected {
+            return Some(detected.selectors);
         }
 
         Some(CmsSelectors::fallback())
     }
+
+    /// Fetch a board page and detect its CMS.
+    async fn detect_page(&self, url: &str) -> Option<DetectedCms> {
+        let board_doc = fetch_page_async(self.client, url).await.ok()?;
+        self.selector_detector.detect_named(&board_doc, url)
+    }
 }
@@ -6,6 +6,7 @@ use futures::stream::{self, StreamExt, TryStreamExt};
 use regex::Regex;
 use reqwest::Client;
 use scraper::{ElementRef, Html, Selector};
+use sha2::{Digest, Sha256};
 
 use crate::error::Result;
 use crate::models::{Campus, CampusInfo, College, Department};
@@ -14,42 +15,116 @@ use crate::utils::http::fetch_page_async;
 /// Service for crawling campus department information.
 pub struct DepartmentCrawler<'a> {
     client: &'a Client,
+    heading_selectors: Vec<String>,
+    main_content_selectors: Vec<String>,
+    id_prefix: String,
+    concurrency: usize,
 }
 
 /// Implementation of DepartmentCrawler
 impl<'a> DepartmentCrawler<'a> {
     /// Create a new department crawler.
     pub fn new(client: &'a Client) -> Self {
-        Self { client }
+        Self {
+            client,
+            heading_selectors: vec!["h1".to_string()],
+            main_content_selectors: vec!["main".to_string()],
+            id_prefix: "yonsei".to_string(),
+            concurrency: 5,
+        }
+    }
+
+    /// Set the selectors tried, in order, for the campus page's main content.
+    pub fn with_main_content_selectors(mut self, selectors: Vec<String>) -> Self {
+        self.main_content_selectors = selectors;
+        self
+    }
+
+    /// Set the prefix of generated department IDs (default: `yonsei`).
+    pub fn with_id_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.id_prefix = prefix.into();
+        self
+    }
+
+    /// Set how many campuses are crawled at once (default: 5).
+    pub fn with_concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    /// Set the heading selectors tried, in order, for college/department names.
+    pub fn with_heading_selectors(mut self, selectors: Vec<String>) -> Self {
+        self.heading_selectors = selectors;
+        self
     }
 
-    /// Crawl all campuses and return their departments.
+    /// Crawl all campuses concurrently and return their departments in
+    /// config order.
     pub async fn crawl_all(&self, campuses: &[CampusInfo]) -> Result<Vec<Campus>> {
         stream::iter(campuses)
             .map(|info| self.crawl_campus(info))
-            .buffer_unordered(5) // Concurrently crawl up to 5 campuses
+            .buffered(self.concurrency)
             .try_collect()
             .await
     }
 
-    /// Crawl a single campus.
+    /// Crawl a single campus, trying the primary URL then each fallback.
+    ///
+    /// The first listing that yields departments wins and is recorded in
+    /// `source_url`. If every URL fails, the last error is returned.
     async fn crawl_campus(&self, info: &CampusInfo) -> Result<Campus> {
-        log::info!("Crawling {}...", info.name);
-        let document = fetch_page_async(self.client, &info.url).await?;
+        let mut empty = None;
+        let mut last_error = None;
+
+        for url in info.urls() {
+            match self.crawl_campus_url(info, url).await {
+                Ok(campus) if campus.department_count() > 0 => return Ok(campus),
+                Ok(campus) => {
+                    log::warn!("No departments at {} for {}", url, info.name);
+                    empty.get_or_insert(campus);
+                }
+                Err(e) => {
+                    log::warn!("Failed to crawl {} for {}: {}", url, info.name, e);
+                    last_error = Some(e);
+                }
+            }
+        }
+
+        match (empty, last_error) {
+            (Some(campus), _) => Ok(campus),
+            (None, Some(e)) => Err(e),
+            (None, None) => unreachable!("CampusInfo::urls always yields the primary URL"),
+        }
+    }
+
+    /// Crawl a single campus listing page.
+    async fn crawl_campus_url(&self, info: &CampusInfo, url: &str) -> Result<Campus> {
+        log::info!("Crawling {} ({})...", info.name, url);
+        let document = fetch_page_async(self.client, url).await?;
 
         let mut campus = Campus {
             campus: info.name.clone(),
             colleges: Vec::new(),
             departments: Vec::new(),
+            source_url: Some(url.to_string()),
         };
 
-        let Some(main_elem) = self.find_main_content(&document) else {
-            log::error!("Cannot find main content area for {}", info.name);
+        let Some(main_elem) = self.find_main_content(&document, url) else {
+            log::error!(
+                "Cannot find main content area for {} (tried {})",
+                info.name,
+                self.main_content_selectors.join(", ")
+            );
             return Ok(campus);
         };
 
-        // Extract departments and group by college
-        let dept_info = self.extract_departments_from_main(main_elem, &document);
+        // Extract departments with the first heading selector that finds any
+        let dept_info = self
+            .heading_selectors
+            .iter()
+            .map(|heading| self.extract_departments_from_main(main_elem, heading))
+            .find(|found| !found.is_empty())
+            .unwrap_or_default();
         self.group_into_colleges(&mut campus, dept_info);
 
         let count = campus.department_count();
@@ -58,9 +133,17 @@ impl<'a> DepartmentCrawler<'a> {
         Ok(campus)
     }
 
-    fn find_main_content<'b>(&self, document: &'b Html) -> Option<ElementRef<'b>> {
-        let main_selector = Selector::parse("main").ok()?;
-        document.select(&main_selector).next()
+    /// Find the main content area with the first selector that matches.
+    fn find_main_content<'b>(&self, document: &'b Html, url: &str) -> Option<ElementRef<'b>> {
+        self.main_content_selectors.iter().find_map(|raw| {
+            let Ok(selector) = Selector::parse(raw) else {
+                log::warn!("Invalid main content selector '{}'", raw);
+                return None;
+            };
+            let found = document.select(&selector).next()?;
+            log::debug!("Main content of {} matched '{}'", url, raw);
+            Some(found)
+        })
     }
 
     fn group_into_colleges(&self, campus: &mut Campus, dept_info: Vec<(String, String, String)>) {
@@ -91,7 +174,7 @@ impl<'a> DepartmentCrawler<'a> {
                 log::warn!("No homepage URL found for {}", dept_name);
             }
 
-            let dept_id = Self::generate_department_id(&dept_name, &dept_url);
+            let dept_id = self.generate_department_id(&dept_name, &dept_url);
             campus.colleges[college_idx].departments.push(Department {
                 id: dept_id,
                 name: dept_name,
@@ -101,24 +184,31 @@ impl<'a> DepartmentCrawler<'a> {
         }
     }
 
-    /// Extract departments from main element.
+    /// Extract departments from main element, using `heading` for
+    /// college/department names.
     fn extract_departments_from_main(
         &self,
         main_elem: ElementRef,
-        _document: &Html,
+        heading: &str,
     ) -> Vec<(String, String, String)> {
-        // Use composite selector to match h1 and a tags in document order
-        let Ok(selector) = Selector::parse("h1, a") else {
+        // Use composite selector to match headings and a tags in document order
+        let (Ok(heading_selector), Ok(selector)) = (
+            Selector::parse(heading),
+            Selector::parse(&format!("{}, a", heading)),
+        ) else {
+            log::warn!("Invalid heading selector: {}", heading);
             return Vec::new();
         };
 
         // Pattern to match college names ending with "대학"
         // Allow spaces between Korean characters (e.g., "소프트웨어디지털 헬스케어융합대학")
-        let college_pattern = Regex::new(r"^([가-힣A-Za-z]+(?:\s*[가-힣A-Za-z]+)*대학)\s*$").unwrap();
-        
+        let college_pattern =
+            Regex::new(r"^([가-힣A-Za-z]+(?:\s*[가-힣A-Za-z]+)*대학)\s*$").unwrap();
+
         // Pattern to match "대학명 학과명" format (e.g., "소프트웨어디지털헬스케어융합대학 소프트웨어학부")
-        let college_dept_pattern = Regex::new(r"^([가-힣A-Za-z]+(?:\s*[가-힣A-Za-z]+)*대학)\s+(.+)$").unwrap();
-        
+        let college_dept_pattern =
+            Regex::new(r"^([가-힣A-Za-z]+(?:\s*[가-힣A-Za-z]+)*대학)\s+(.+)$").unwrap();
+
         let mut results: Vec<(String, String, String)> = Vec::new();
 
         let mut current_college = String::new();
@@ -126,9 +216,7 @@ impl<'a> DepartmentCrawler<'a> {
 
         // Iterate over descendants of the main element
         for element in main_elem.select(&selector) {
-            let tag = element.value().name();
-
-            if tag == "h1" {
+            if heading_selector.matches(&element) {
                 // If there was a pending department without a URL, mark it as NOT_FOUND
                 if let Some(dept_name) = pending_dept.take() {
                     results.push((current_college.clone(), dept_name, "NOT_FOUND".to_string()));
@@ -143,15 +231,16 @@ impl<'a> DepartmentCrawler<'a> {
                 if let Some(caps) = college_dept_pattern.captures(&text) {
                     let college_name = caps.get(1).map(|m| m.as_str()).unwrap_or("");
                     let dept_name = caps.get(2).map(|m| m.as_str()).unwrap_or("");
-                    
+
                     // Normalize college name by removing extra spaces
-                    let normalized_college = college_name.split_whitespace().collect::<Vec<_>>().join("");
-                    
+                    let normalized_college =
+                        college_name.split_whitespace().collect::<Vec<_>>().join("");
+
                     // Update current college if different
                     if current_college != normalized_college {
                         current_college = normalized_college;
                     }
-                    
+
                     // Set pending department
                     pending_dept = Some(dept_name.trim().to_string());
                 } else if college_pattern.is_match(&text) {
@@ -162,7 +251,7 @@ impl<'a> DepartmentCrawler<'a> {
                     // Simple department name (without college prefix)
                     pending_dept = Some(text);
                 }
-            } else if tag == "a" {
+            } else {
                 // Only interested in links if we have a pending department
                 if pending_dept.is_none() {
                     continue;
@@ -173,12 +262,12 @@ impl<'a> DepartmentCrawler<'a> {
                     continue;
                 }
 
-                if let Some(href) = element.value().attr("href") {
-                    if href.starts_with("http") && !href.starts_with('#') {
-                        if let Some(dept_name) = pending_dept.take() {
-                            results.push((current_college.clone(), dept_name, href.to_string()));
-                        }
-                    }
+                if let Some(href) = element.value().attr("href")
+                    && href.starts_with("http")
+                    && !href.starts_with('#')
+                    && let Some(dept_name) = pending_dept.take()
+                {
+                    results.push((current_college.clone(), dept_name, href.to_string()));
                 }
             }
         }
@@ -201,17 +290,265 @@ impl<'a> DepartmentCrawler<'a> {
         text.trim().to_string()
     }
 
-    /// Generate a unique department ID from name or URL.
-    fn generate_department_id(name: &str, url: &str) -> String {
-        if url != "NOT_FOUND" {
-            if let Ok(re) = Regex::new(r"https?://([^.]+)\.yonsei\.ac\.kr") {
-                if let Some(caps) = re.captures(url) {
-                    if let Some(subdomain) = caps.get(1) {
-                        return format!("yonsei_{}", subdomain.as_str().to_lowercase());
-                    }
+    /// Generate a department ID from the homepage URL, or the name when
+    /// there is no homepage.
+    ///
+    /// The subdomain of the site's registrable domain becomes the key: `me`
+    /// for `me.yonsei.ac.kr` or `cs.stanford.edu`, `cs_mirae` for
+    /// `cs.mirae.yonsei.ac.kr`. Bare hosts and IP addresses get a short URL
+    /// hash instead.
+    fn generate_department_id(&self, name: &str, url: &str) -> String {
+        let key = match url::Url::parse(url) {
+            Ok(parsed) if url != "NOT_FOUND" => match parsed.host_str().and_then(subdomain_key) {
+                Some(label) => label,
+                None => {
+                    let digest = Sha256::digest(url.as_bytes());
+                    hex::encode(digest)[..8].to_string()
                 }
-            }
+            },
+            _ => name.to_lowercase().replace(' ', "_"),
+        };
+        if self.id_prefix.is_empty() {
+            key
+        } else {
+            format!("{}_{}", self.id_prefix, key)
         }
-        format!("yonsei_{}", name.to_lowercase().replace(' ', "_"))
+    }
+}
+
+/// A host's subdomain labels joined with `_`, lowercased.
+///
+/// Two-letter country TLDs with a short second level (`ac.kr`, `co.uk`)
+/// count as a single suffix. A leading `www` is dropped when more labels
+/// follow; a lone `www` is kept, so `www.yonsei.ac.kr` keeps the
+/// `yonsei_www` ID of earlier sitemaps. Returns `None` for bare hosts and
+/// IP addresses.
+fn subdomain_key(host: &str) -> Option<String> {
+    if host.parse::<std::net::IpAddr>().is_ok() {
+        return None;
+    }
+    let labels: Vec<&str> = host.trim_end_matches('.').split('.').collect();
+    let registrable_len = match labels.as_slice() {
+        [.., second, tld] if tld.len() == 2 && second.len() <= 3 => 3,
+        _ => 2,
+    };
+    let subdomains = match &labels[..labels.len().saturating_sub(registrable_len)] {
+        ["www", rest @ ..] if !rest.is_empty() => rest,
+        subdomains => subdomains,
+    };
+    if subdomains.is_empty() || subdomains.iter().any(|label| label.is_empty()) {
+        return None;
+    }
+    Some(subdomains.join("_").to_lowercase())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::test_server::{TestResponse, TestServer};
+
+    #[tokio::test]
+    async fn test_fallback_url_used_when_primary_fails() {
+        let server = TestServer::start(|req| match req.path.as_str() {
+            "/mirror" => TestResponse::html(
+                r#"<main><h1>공과대학</h1><h1>기계공학부</h1><a href="https://me.example.com">홈페이지</a></main>"#,
+            ),
+            _ => TestResponse {
+                status: 500,
+                ..TestResponse::html("down")
+            },
+        });
+        let info = CampusInfo {
+            name: "신촌캠퍼스".to_string(),
+            url: server.url("/primary"),
+            fallback_urls: vec![server.url("/mirror")],
+        };
+
+        let client = Client::new();
+        let campus = DepartmentCrawler::new(&client)
+            .crawl_campus(&info)
+            .await
+            .unwrap();
+
+        assert_eq!(campus.department_count(), 1);
+        assert_eq!(campus.colleges[0].departments[0].name, "기계공학부");
+        assert_eq!(campus.source_url, Some(server.url("/mirror")));
+    }
+
+    #[test]
+    fn test_department_id_from_subdomain() {
+        let client = Client::new();
+        let crawler = DepartmentCrawler::new(&client);
+        assert_eq!(
+            crawler.generate_department_id("기계공학부", "https://me.yonsei.ac.kr/main"),
+            "yonsei_me"
+        );
+        assert_eq!(
+            crawler.generate_department_id("Computer Science", "https://www.cs.stanford.edu/"),
+            "yonsei_cs"
+        );
+        // IDs of existing sitemaps are kept for single-label hosts
+        assert_eq!(
+            crawler.generate_department_id("학생처", "https://www.yonsei.ac.kr/sc/"),
+            "yonsei_www"
+        );
+        // Parent labels keep multi-level hosts apart from cs.yonsei.ac.kr
+        assert_eq!(
+            crawler.generate_department_id("컴퓨터정보통신", "https://cs.mirae.yonsei.ac.kr/"),
+            "yonsei_cs_mirae"
+        );
+
+        let generic = DepartmentCrawler::new(&client).with_id_prefix("snu");
+        assert_eq!(
+            generic.generate_department_id("Math", "https://math.snu.ac.kr"),
+            "snu_math"
+        );
+        assert_eq!(
+            DepartmentCrawler::new(&client)
+                .with_id_prefix("")
+                .generate_department_id("Math", "https://math.snu.ac.kr"),
+            "math"
+        );
+    }
+
+    #[test]
+    fn test_department_id_fallbacks() {
+        let client = Client::new();
+        let crawler = DepartmentCrawler::new(&client);
+        assert_eq!(
+            crawler.generate_department_id("Student Affairs", "NOT_FOUND"),
+            "yonsei_student_affairs"
+        );
+
+        // No subdomain: hash the URL so departments on one host stay distinct
+        let a = crawler.generate_department_id("A", "https://yonsei.ac.kr/sc/a");
+        let b = crawler.generate_department_id("B", "https://yonsei.ac.kr/sc/b");
+        assert_ne!(a, b);
+        assert!(a.starts_with("yonsei_") && a.len() == "yonsei_".len() + 8);
+        assert_eq!(
+            a,
+            crawler.generate_department_id("A", "https://yonsei.ac.kr/sc/a")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_main_content_selector_fallback() {
+        let server = TestServer::start(|_| {
+            TestResponse::html(
+                r#"<div id="content"><h1>공과대학</h1><h1>기계공학부</h1><a href="https://me.example.com">홈페이지</a></div>"#,
+            )
+        });
+        let info = CampusInfo {
+            name: "신촌캠퍼스".to_string(),
+            url: server.url("/campus"),
+            fallback_urls: Vec::new(),
+        };
+        let client = Client::new();
+
+        let campus = DepartmentCrawler::new(&client)
+            .crawl_campus(&info)
+            .await
+            .unwrap();
+        assert_eq!(campus.department_count(), 0);
+
+        let campus = DepartmentCrawler::new(&client)
+            .with_main_content_selectors(vec!["main".to_string(), "div#content".to_string()])
+            .crawl_campus(&info)
+            .await
+            .unwrap();
+        assert_eq!(campus.department_count(), 1);
+        assert_eq!(campus.colleges[0].departments[0].name, "기계공학부");
+    }
+
+    #[tokio::test]
+    async fn test_crawl_all_keeps_config_order() {
+        let server = TestServer::start(|req| {
+            if req.path == "/slow" {
+                std::thread::sleep(std::time::Duration::from_millis(200));
+            }
+            TestResponse::html(
+                r#"<h1>공과대학</h1><a href="https://me.example.com">기계공학부</a>"#,
+            )
+        });
+        let info = |name: &str, path: &str| CampusInfo {
+            name: name.to_string(),
+            url: server.url(path),
+            fallback_urls: Vec::new(),
+        };
+        let campuses = [info("신촌캠퍼스", "/slow"), info("미래캠퍼스", "/fast")];
+
+        let client = Client::new();
+        let result = DepartmentCrawler::new(&client)
+            .with_concurrency(2)
+            .crawl_all(&campuses)
+            .await
+            .unwrap();
+
+        let names: Vec<&str> = result.iter().map(|c| c.campus.as_str()).collect();
+        assert_eq!(names, ["신촌캠퍼스", "미래캠퍼스"]);
+    }
+
+    #[tokio::test]
+    async fn test_h2_headings_used_when_h1_yields_nothing() {
+        let server = TestServer::start(|_| {
+            TestResponse::html(
+                r#"<main><h1>학과 안내</h1>
+                <h2>공과대학</h2>
+                <h2>기계공학부</h2><a href="https://me.example.com">홈페이지</a>
+                <h2>전기전자공학부</h2><a href="https://ee.example.com">홈페이지</a>
+                <h2>이과대학</h2>
+                <h2>수학과</h2><a href="https://math.example.com">홈페이지</a>
+                </main>"#,
+            )
+        });
+        let info = CampusInfo {
+            name: "신촌캠퍼스".to_string(),
+            url: server.url("/campus"),
+            fallback_urls: Vec::new(),
+        };
+
+        let client = Client::new();
+        let crawler = DepartmentCrawler::new(&client)
+            .with_heading_selectors(crate::models::DiscoveryConfig::default().heading_selectors);
+        let campus = crawler.crawl_campus(&info).await.unwrap();
+
+        let grouped: Vec<(&str, Vec<&str>)> = campus
+            .colleges
+            .iter()
+            .map(|c| {
+                let depts = c.departments.iter().map(|d| d.name.as_str()).collect();
+                (c.name.as_str(), depts)
+            })
+            .collect();
+        assert_eq!(
+            grouped,
+            [
+                ("공과대학", vec!["기계공학부", "전기전자공학부"]),
+                ("이과대학", vec!["수학과"]),
+            ]
+        );
+        assert_eq!(
+            campus.colleges[1].departments[0].url,
+            "https://math.example.com"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_all_urls_failing_returns_error() {
+        let server = TestServer::start(|_| TestResponse {
+            status: 500,
+            ..TestResponse::html("down")
+        });
+        let info = CampusInfo {
+            name: "신촌캠퍼스".to_string(),
+            url: server.url("/primary"),
+            fallback_urls: vec![server.url("/mirror")],
+        };
+
+        let client = Client::new();
+        let result = DepartmentCrawler::new(&client).crawl_campus(&info).await;
+
+        assert!(result.is_err());
+        assert_eq!(server.requests().len(), 2);
     }
 }
@@ -18,4 +18,4 @@ pub use boards::BoardDiscoveryService;
 #[cfg(feature = "map")]
 pub use departments::DepartmentCrawler;
 pub use notices::NoticeCrawler;
-pub use selectors::SelectorDetector;
+pub use selectors::{DetectedCms, SelectorCache, SelectorDetector};
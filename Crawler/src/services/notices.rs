@@ -2,19 +2,51 @@
 //!
 //! Fetches notices from department boards using configured CSS selectors.
 
-use std::collections::{HashMap, HashSet};
-use std::sync::Arc;
-use std::time::Duration;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::{Arc, LazyLock};
+use std::time::{Duration, Instant};
 
+use chrono::{DateTime, NaiveDate, Utc};
 use futures::stream::{self, StreamExt};
+use regex::Regex;
 use reqwest::Client;
-use scraper::Selector;
+use scraper::{Html, Selector};
 
 use crate::error::{AppError, Result};
 use crate::models::{
-    Board, Campus, Config, CrawlError, CrawlOutcome, CrawlStage, DepartmentRef, Notice,
+    Board, BoardSource, Campus, CleaningConfig, Config, CrawlError, CrawlErrorCode, CrawlOutcome,
+    CrawlStage, DedupScope, DepartmentRef, HttpCache, HttpCacheEntry, HttpValidators, IdOptions,
+    Notice,
 };
-use crate::utils::{extract_notice_id, http, resolve_url};
+use crate::utils::rate_limit::DomainRateLimiter;
+use crate::utils::region::RegionSelector;
+use crate::utils::robots::{RobotsRules, fetch_robots};
+use crate::utils::rss;
+use crate::utils::slow_start::{DEFAULT_RAMP_REQUESTS, SlowStart};
+use crate::utils::{
+    detect_lang, extract_notice_id_with, extract_script_url, get_domain, http, normalize_text,
+    resolve, resolve_url,
+};
+
+/// Longest `Retry-After` honored; a server asking for more ends the retries.
+const MAX_RETRY_AFTER: Duration = Duration::from_secs(60);
+
+/// Words (lowercase) that mark a pinned row in the number column, as text or
+/// in an icon's `alt` or file name (`ico_notice.gif`).
+const PINNED_MARKERS: &[&str] = &["공지", "중요", "필독", "notice", "important", "pinned"];
+
+/// Identifies one board of one department: `(campus, department_id,
+/// board_id)`. Board IDs are keyword mapping IDs shared across departments,
+/// so they only identify a board together with its department.
+type BoardKey = (String, String, String);
+
+fn board_key(dept_ref: DepartmentRef<'_>, board: &Board) -> BoardKey {
+    (
+        dept_ref.campus.to_string(),
+        dept_ref.dept.id.clone(),
+        board.id.clone(),
+    )
+}
 
 /// Board selectors for notice extraction.
 #[derive(Clone)]
@@ -22,8 +54,15 @@ struct BoardSelectors {
     row: Selector,
     title: Selector,
     date: Selector,
+    date_fallbacks: Vec<Selector>,
     author: Option<Selector>,
+    author_fallbacks: Vec<Selector>,
+    views: Option<Selector>,
     link: Option<Selector>,
+    content_root: Option<RegionSelector>,
+    pinned: Option<Selector>,
+    pagination: Option<Selector>,
+    attachment: Option<Selector>,
 }
 
 /// Result of fetching a board's notice list.
@@ -31,23 +70,56 @@ struct BoardListResult {
     notices: Vec<Notice>,
     row_total: usize,
     row_failures: usize,
+    wire_bytes: u64,
+    decoded_bytes: u64,
+    /// Validators of the first list page
+    validators: HttpValidators,
+    /// Time spent on list page requests, excluding waits and parsing
+    fetch_time: Duration,
+    /// Time spent decoding, parsing and extracting rows from list pages
+    parse_time: Duration,
+    /// Request delays and retry backoff between list page requests
+    wait_time: Duration,
+    /// Errors of list pages after the first, which end paging early
+    page_errors: Vec<CrawlError>,
 }
 
 /// Service for crawling notices from department boards.
 pub struct NoticeCrawler {
     config: Arc<Config>,
     client: Client,
+    rate_limiter: Option<DomainRateLimiter>,
+    http_cache: HttpCache,
 }
 
 /// Implementation of NoticeCrawler
 impl NoticeCrawler {
     /// Create a new notice crawler with the given configuration.
     pub fn new(config: Arc<Config>, client: Client) -> Result<Self> {
-        Ok(Self { config, client })
+        let rate_limiter = config
+            .crawler
+            .request_interval()?
+            .map(DomainRateLimiter::new);
+        Ok(Self {
+            config,
+            client,
+            rate_limiter,
+            http_cache: HttpCache::default(),
+        })
+    }
+
+    /// Use the previous run's cache for conditional board list requests.
+    ///
+    /// Only consulted when `crawler.conditional_requests` is enabled.
+    pub fn with_http_cache(mut self, http_cache: HttpCache) -> Self {
+        self.http_cache = http_cache;
+        self
     }
 
     /// Fetch all notices from all campuses concurrently.
     pub async fn fetch_all(&self, campuses: &[Campus]) -> Result<CrawlOutcome> {
+        // Relative list dates ("3일 전") resolve against one instant per run
+        let started_at = Utc::now();
         let concurrency = self.config.crawler.max_concurrent.max(1);
         let board_lookup = Arc::new(Self::build_board_lookup(campuses));
         let (selector_cache, selector_errors, invalid_boards) =
@@ -55,62 +127,148 @@ impl NoticeCrawler {
         let selector_cache = Arc::new(selector_cache);
 
         // Stage 1: Fetch all notice lists from boards concurrently, but bounded by concurrency.
-        let board_jobs_all: Vec<_> = campuses
-            .iter()
-            .flat_map(|c| c.all_departments())
+        let board_jobs_all: Vec<_> = Campus::departments_of(campuses)
+            .into_iter()
             .flat_map(|dept_ref| {
                 dept_ref
                     .dept
                     .boards
                     .iter()
+                    .filter(|board| board.enabled)
                     .map(move |board| (dept_ref, board))
             })
             .collect();
         let board_jobs: Vec<_> = board_jobs_all
             .into_iter()
-            .filter(|(_, board)| !invalid_boards.contains(board.id.as_str()))
+            .filter(|(dept_ref, board)| !invalid_boards.contains(&board_key(*dept_ref, board)))
             .collect();
 
+        let robots = if self.config.crawler.respect_robots {
+            self.fetch_robots_rules(board_jobs.iter().map(|(_, board)| *board))
+                .await
+        } else {
+            HashMap::new()
+        };
+        let (board_jobs, disallowed): (Vec<_>, Vec<_>) = board_jobs
+            .into_iter()
+            .partition(|(_, board)| Self::robots_allow(&robots, &board.url));
+
         let mut outcome = CrawlOutcome {
-            board_total: board_jobs.len() + invalid_boards.len(),
-            board_failures: invalid_boards.len(),
+            board_total: board_jobs.len() + disallowed.len() + invalid_boards.len(),
+            board_failures: disallowed.len() + invalid_boards.len(),
             errors: selector_errors,
             ..CrawlOutcome::default()
         };
+        for (dept_ref, board) in disallowed {
+            log::warn!("robots.txt disallows {} ({})", board.name, board.url);
+            outcome.errors.push(
+                Self::build_error(
+                    CrawlStage::RobotsDisallowed,
+                    Some(board),
+                    Some(&board.url),
+                    None,
+                    &AppError::crawl("robots", "Disallowed by robots.txt"),
+                )
+                .with_department(dept_ref),
+            );
+        }
+
+        let slow_start = self
+            .config
+            .crawler
+            .slow_start
+            .then(|| SlowStart::new(concurrency, DEFAULT_RAMP_REQUESTS));
 
         let mut notice_buffer = Vec::new();
+        let mut fetch_time = Duration::ZERO;
+        let mut parse_time = Duration::ZERO;
+        let mut wait_time = Duration::ZERO;
         let mut board_stream = stream::iter(board_jobs)
             .map(|(dept_ref, board)| {
                 let selector_cache = Arc::clone(&selector_cache);
+                let slow_start = slow_start.as_ref();
+                let robots = &robots;
                 async move {
-                    let selectors = selector_cache.get(&board.id).cloned().ok_or_else(|| {
-                        AppError::crawl("selector_cache", "Missing selector cache entry")
-                    });
+                    let permit = match slow_start {
+                        Some(ramp) => {
+                            let domain = get_domain(&board.url).unwrap_or_default();
+                            Some(ramp.acquire(&domain).await)
+                        }
+                        None => None,
+                    };
+                    let selectors = selector_cache
+                        .get(&board_key(dept_ref, board))
+                        .cloned()
+                        .ok_or_else(|| {
+                            AppError::crawl("selector_cache", "Missing selector cache entry")
+                        });
+                    let waited = self.apply_request_delay(&board.url).await;
+                    let started = Instant::now();
                     let result = match selectors {
-                        Ok(selectors) => self.fetch_board_list(dept_ref, board, &selectors).await,
+                        Ok(selectors) => {
+                            self.fetch_board_list(dept_ref, board, &selectors, robots)
+                                .await
+                        }
                         Err(err) => Err(err),
                     };
-                    (board, result)
+                    let elapsed = started.elapsed();
+                    if let Some(permit) = permit {
+                        permit.finish(!result.as_ref().is_err_and(AppError::is_retryable));
+                    }
+                    (dept_ref, board, result, elapsed, waited)
                 }
             })
             .buffer_unordered(concurrency);
 
-        while let Some((board, result)) = board_stream.next().await {
+        while let Some((dept_ref, board, result, elapsed, waited)) = board_stream.next().await {
+            wait_time += waited;
             match result {
-                Ok(list_result) => {
+                Ok(mut list_result) => {
+                    for notice in &mut list_result.notices {
+                        notice.resolve_relative_date(started_at);
+                    }
+                    fetch_time += list_result.fetch_time;
+                    parse_time += list_result.parse_time;
+                    wait_time += list_result.wait_time;
                     outcome.notice_total += list_result.row_total;
                     outcome.notice_failures += list_result.row_failures;
-                    notice_buffer.extend(list_result.notices);
+                    outcome.bytes_downloaded += list_result.wire_bytes;
+                    outcome.bytes_parsed += list_result.decoded_bytes;
+                    // A board with a failed page is not cached, or a later
+                    // `304` would keep reusing its partial rows
+                    if self.config.crawler.conditional_requests
+                        && !list_result.validators.is_empty()
+                        && list_result.page_errors.is_empty()
+                    {
+                        outcome.http_cache.entries.insert(
+                            board.url.clone(),
+                            HttpCacheEntry {
+                                validators: list_result.validators,
+                                notices: list_result.notices.clone(),
+                            },
+                        );
+                    }
+                    outcome.errors.extend(list_result.page_errors);
+                    notice_buffer.extend(
+                        list_result
+                            .notices
+                            .into_iter()
+                            .map(|notice| (board.url.as_str(), notice)),
+                    );
                 }
                 Err(error) => {
                     outcome.board_failures += 1;
-                    outcome.errors.push(Self::build_error(
-                        CrawlStage::BoardList,
-                        Some(board),
-                        Some(&board.url),
-                        None,
-                        &error,
-                    ));
+                    outcome.errors.push(CrawlError {
+                        duration_ms: Some(elapsed.as_millis() as u64),
+                        ..Self::build_error(
+                            CrawlStage::BoardList,
+                            Some(board),
+                            Some(&board.url),
+                            None,
+                            &error,
+                        )
+                        .with_department(dept_ref)
+                    });
                     log::warn!(
                         "Failed to fetch board list {} ({}): {}",
                         board.name,
@@ -121,14 +279,32 @@ impl NoticeCrawler {
             }
         }
 
-        let mut seen = HashSet::new();
-        let mut deduped = Vec::new();
-        for notice in notice_buffer {
-            let id = notice.canonical_id();
-            if seen.insert(id) {
-                deduped.push(notice);
+        outcome.total_fetch_ms = fetch_time.as_millis() as u64;
+        outcome.total_parse_ms = parse_time.as_millis() as u64;
+        outcome.total_wait_ms = wait_time.as_millis() as u64;
+
+        let mut deduped = Self::dedup(
+            notice_buffer,
+            self.config.crawler.dedup_scope,
+            self.config.crawler.id_options(),
+        );
+        if self.config.crawler.dedup_cross_board {
+            deduped = Self::dedup_cross_board(deduped);
+        }
+        if let Some(max_age_days) = self.config.crawler.max_age_days {
+            let before = deduped.len();
+            deduped = Self::filter_by_age(deduped, max_age_days, started_at);
+            outcome.notices_filtered = before - deduped.len();
+            if outcome.notices_filtered > 0 {
+                log::info!(
+                    "Skipped {} notices older than {} days",
+                    outcome.notices_filtered,
+                    max_age_days
+                );
             }
         }
+        // Capped last, so duplicates and old notices do not use up the cap
+        let deduped = Self::cap_total(deduped, self.config.crawler.max_total_notices);
 
         // Stage 2: Fetch details for each notice concurrently.
         outcome.detail_total = deduped.len();
@@ -136,22 +312,31 @@ impl NoticeCrawler {
             .map(|notice| {
                 let board_lookup = Arc::clone(&board_lookup);
                 let selector_cache = Arc::clone(&selector_cache);
-                let notice_id = notice.canonical_id();
+                let notice_id = notice.canonical_id_with(self.config.crawler.id_options());
                 let board_id = notice.board_id.clone();
                 let board_name = notice.board_name.clone();
                 let url = notice.link.clone();
                 async move {
+                    let started = Instant::now();
                     let result = self
                         .fetch_notice_detail(notice, &board_lookup, &selector_cache)
                         .await;
-                    (notice_id, board_id, board_name, url, result)
+                    (
+                        notice_id,
+                        board_id,
+                        board_name,
+                        url,
+                        result,
+                        started.elapsed(),
+                    )
                 }
             })
             .buffer_unordered(concurrency);
 
         let mut detailed = Vec::new();
         let mut detail_stream = detailed_notices;
-        while let Some((notice_id, board_id, board_name, url, result)) = detail_stream.next().await
+        while let Some((notice_id, board_id, board_name, url, result, elapsed)) =
+            detail_stream.next().await
         {
             match result {
                 Ok(notice) => detailed.push(notice),
@@ -167,12 +352,17 @@ impl NoticeCrawler {
                     };
                     outcome.errors.push(CrawlError {
                         stage,
+                        code: CrawlErrorCode::from_error(&error),
+                        campus: None,
+                        department_id: None,
                         board_id: Some(board_id),
                         board_name: Some(board_name),
                         url: Some(url),
                         notice_id: Some(notice_id),
                         message: error.to_string(),
                         retryable: error.is_retryable(),
+                        http_status: error.http_status(),
+                        duration_ms: Some(elapsed.as_millis() as u64),
                     });
                     log::warn!("Failed to fetch notice detail: {}", error);
                 }
@@ -183,40 +373,403 @@ impl NoticeCrawler {
         Ok(outcome)
     }
 
+    /// Whether the robots.txt rules fetched for `url`'s domain allow it.
+    fn robots_allow(robots: &HashMap<String, RobotsRules>, url: &str) -> bool {
+        get_domain(url)
+            .and_then(|domain| robots.get(&domain))
+            .is_none_or(|rules| rules.is_url_allowed(url))
+    }
+
+    /// Fetch robots.txt once per domain among `boards`, keyed by domain.
+    async fn fetch_robots_rules<'b>(
+        &self,
+        boards: impl Iterator<Item = &'b Board>,
+    ) -> HashMap<String, RobotsRules> {
+        let mut sites: HashMap<String, &str> = HashMap::new();
+        for board in boards {
+            if let Some(domain) = get_domain(&board.url) {
+                sites.entry(domain).or_insert(&board.url);
+            }
+        }
+
+        let crawler = &self.config.crawler;
+        stream::iter(sites)
+            .map(|(domain, url)| async move {
+                let rules = fetch_robots(
+                    &self.client,
+                    url,
+                    &crawler.user_agent,
+                    crawler.max_body_bytes,
+                )
+                .await;
+                (domain, rules)
+            })
+            .buffer_unordered(crawler.max_concurrent.max(1))
+            .collect()
+            .await
+    }
+
+    /// Fetch the notices of a feed-backed board from its RSS/Atom items.
+    ///
+    /// Feeds carry no pinned state and are read in a single request, with
+    /// the board's headers and, under `conditional_requests`, the cached
+    /// validators.
+    async fn fetch_feed_list(
+        &self,
+        dept_ref: DepartmentRef<'_>,
+        board: &Board,
+    ) -> Result<BoardListResult> {
+        let limits = http::PageLimits::from_config(&self.config.crawler);
+        let options = http::RequestOptions::for_board(&board.fetch, &self.config.crawler)?;
+        let cached = self
+            .config
+            .crawler
+            .conditional_requests
+            .then(|| self.http_cache.entries.get(&board.url))
+            .flatten();
+        let started = Instant::now();
+        let mut wait_time = Duration::ZERO;
+        let fetched = self
+            .fetch_with_retry(&board.url, &mut wait_time, || {
+                http::fetch_feed(
+                    &self.client,
+                    &board.url,
+                    &limits,
+                    cached.map(|entry| &entry.validators),
+                    &options,
+                )
+            })
+            .await?;
+        let fetch_time = started.elapsed().saturating_sub(wait_time);
+        let feed = match (fetched, cached) {
+            (http::ConditionalFeed::Modified(feed), _) => feed,
+            (http::ConditionalFeed::NotModified, Some(entry)) => {
+                log::debug!("{} not modified, reusing cached notices", board.url);
+                return Ok(BoardListResult {
+                    notices: entry.notices.clone(),
+                    row_total: entry.notices.len(),
+                    row_failures: 0,
+                    wire_bytes: 0,
+                    decoded_bytes: 0,
+                    validators: entry.validators.clone(),
+                    fetch_time,
+                    parse_time: Duration::ZERO,
+                    wait_time,
+                    page_errors: Vec::new(),
+                });
+            }
+            (http::ConditionalFeed::NotModified, None) => {
+                return Err(AppError::UpstreamNotModified {
+                    url: board.url.clone(),
+                });
+            }
+        };
+        let parse_started = Instant::now();
+        let items = rss::parse_feed(&feed.text);
+        let base_url = url::Url::parse(board.link_base_url())?;
+
+        let notices = items
+            .iter()
+            .map(|item| {
+                let link = resolve_url(&base_url, &item.link);
+                Notice {
+                    campus: dept_ref.campus.to_string(),
+                    college: dept_ref.college.unwrap_or("").to_string(),
+                    department_id: dept_ref.dept.id.clone(),
+                    department_name: dept_ref.dept.name.clone(),
+                    board_id: board.id.clone(),
+                    board_name: board.name.clone(),
+                    title: item.title.clone(),
+                    author: item.author.clone(),
+                    date: item.date.clone(),
+                    views: None,
+                    source_id: extract_notice_id_with(&link, &self.config.crawler.id_extraction),
+                    link,
+                    is_pinned: false,
+                    date_inferred: false,
+                    lang: self
+                        .config
+                        .crawler
+                        .detect_language
+                        .then(|| detect_lang(&item.title).map(str::to_string))
+                        .flatten(),
+                    attachments: Vec::new(),
+                }
+            })
+            .collect();
+
+        Ok(BoardListResult {
+            notices,
+            row_total: items.len(),
+            row_failures: 0,
+            wire_bytes: feed.wire_bytes,
+            decoded_bytes: feed.decoded_bytes,
+            validators: feed.validators,
+            fetch_time,
+            parse_time: parse_started.elapsed(),
+            wait_time,
+            page_errors: Vec::new(),
+        })
+    }
+
     /// Fetch a list of notices from a single board.
+    ///
+    /// Follows up to `max_pages` list pages, via `pagination_selector` or
+    /// `next_url_pattern`. Paging stops early at a page with no new rows or
+    /// one repeating a regular (non-pinned) notice from an earlier page.
+    /// A later page that fails or is disallowed by `robots` also ends paging;
+    /// the rows gathered so far are kept and the page is recorded in
+    /// [`BoardListResult::page_errors`].
+    ///
+    /// With `conditional_requests`, the first page is revalidated against the
+    /// cached validators; a `304` returns the cached notices without paging.
     async fn fetch_board_list(
         &self,
         dept_ref: DepartmentRef<'_>,
         board: &Board,
         selectors: &BoardSelectors,
+        robots: &HashMap<String, RobotsRules>,
     ) -> Result<BoardListResult> {
-        self.apply_request_delay().await;
-        let document = http::fetch_page_async(&self.client, &board.url).await?;
-        let base_url = url::Url::parse(&board.url)?;
-        let mut notices = Vec::new();
-        let mut row_total = 0;
-        let mut row_failures = 0;
-
-        for row in document.select(&selectors.row) {
-            row_total += 1;
-            if let Some(notice) = self.parse_notice_row(
-                &row,
-                selectors,
-                &board.selectors.attr_name,
-                dept_ref,
-                board,
-                &base_url,
-            ) {
-                notices.push(notice);
-            } else {
-                row_failures += 1;
+        if board.source == BoardSource::Rss {
+            return self.fetch_feed_list(dept_ref, board).await;
+        }
+        let limits = http::PageLimits::from_config(&self.config.crawler);
+        let options = http::RequestOptions::for_board(&board.fetch, &self.config.crawler)?
+            .with_content_root(selectors.content_root.clone());
+        let base_url = url::Url::parse(board.link_base_url())?;
+        let max_pages = board.selectors.max_pages.max(1);
+        let mut result = BoardListResult {
+            notices: Vec::new(),
+            row_total: 0,
+            row_failures: 0,
+            wire_bytes: 0,
+            decoded_bytes: 0,
+            validators: HttpValidators::default(),
+            fetch_time: Duration::ZERO,
+            parse_time: Duration::ZERO,
+            wait_time: Duration::ZERO,
+            page_errors: Vec::new(),
+        };
+        let cached = self
+            .config
+            .crawler
+            .conditional_requests
+            .then(|| self.http_cache.entries.get(&board.url))
+            .flatten();
+        let mut seen = HashSet::new();
+        let mut page_url = board.url.clone();
+
+        for page_number in 1..=max_pages {
+            if page_number > 1 {
+                if !Self::robots_allow(robots, &page_url) {
+                    log::warn!(
+                        "robots.txt disallows page {} of {} ({})",
+                        page_number,
+                        board.name,
+                        page_url
+                    );
+                    result.page_errors.push(
+                        Self::build_error(
+                            CrawlStage::BoardPage,
+                            Some(board),
+                            Some(&page_url),
+                            None,
+                            &AppError::crawl("robots", "Disallowed by robots.txt"),
+                        )
+                        .with_department(dept_ref),
+                    );
+                    break;
+                }
+                result.wait_time += self.apply_request_delay(&page_url).await;
+            }
+            let fetch_started = Instant::now();
+            let mut waited = Duration::ZERO;
+            let fetched = match cached.filter(|_| page_number == 1) {
+                Some(entry) => {
+                    match self
+                        .fetch_with_retry(&page_url, &mut waited, || {
+                            http::fetch_page_conditional(
+                                &self.client,
+                                &page_url,
+                                &limits,
+                                &entry.validators,
+                                &options,
+                            )
+                        })
+                        .await?
+                    {
+                        http::ConditionalPage::NotModified => {
+                            log::debug!("{} not modified, reusing cached notices", board.url);
+                            result.row_total = entry.notices.len();
+                            result.notices = entry.notices.clone();
+                            result.validators = entry.validators.clone();
+                            result.fetch_time += fetch_started.elapsed().saturating_sub(waited);
+                            result.wait_time += waited;
+                            return Ok(result);
+                        }
+                        http::ConditionalPage::Modified(page) => Ok(page),
+                    }
+                }
+                None => {
+                    self.fetch_with_retry(&page_url, &mut waited, || {
+                        http::fetch_page_with_options(&self.client, &page_url, &limits, &options)
+                    })
+                    .await
+                }
+            };
+            let request_time = fetch_started.elapsed().saturating_sub(waited);
+            result.wait_time += waited;
+            let page = match fetched {
+                Ok(page) => page,
+                Err(error) if page_number > 1 => {
+                    log::warn!(
+                        "Failed to fetch page {} of {} ({}): {}",
+                        page_number,
+                        board.name,
+                        page_url,
+                        error
+                    );
+                    result.fetch_time += request_time;
+                    result.page_errors.push(
+                        Self::build_error(
+                            CrawlStage::BoardPage,
+                            Some(board),
+                            Some(&page_url),
+                            None,
+                            &error,
+                        )
+                        .with_department(dept_ref),
+                    );
+                    break;
+                }
+                Err(error) => return Err(error),
+            };
+            result.fetch_time += request_time.saturating_sub(page.parse_time);
+            result.parse_time += page.parse_time;
+            if page_number == 1 {
+                result.validators = page.validators.clone();
+            }
+            result.wire_bytes += page.wire_bytes;
+            result.decoded_bytes += page.decoded_bytes;
+
+            let parse_started = Instant::now();
+            let document = page.document;
+            let mut page_ids = Vec::new();
+            let mut repeated = false;
+            for row in document.select(&selectors.row) {
+                let row_index = result.row_total;
+                result.row_total += 1;
+                let Some(notice) = self.parse_notice_row(
+                    &row,
+                    row_index,
+                    selectors,
+                    &board.selectors.attr_name,
+                    dept_ref,
+                    board,
+                    &base_url,
+                ) else {
+                    result.row_failures += 1;
+                    continue;
+                };
+                // Only rows from earlier pages count as repeats; duplicates
+                // within a page are left to the dedup stage
+                let id = notice.canonical_id_with(self.config.crawler.id_options());
+                if seen.contains(&id) {
+                    repeated |= !notice.is_pinned;
+                    continue;
+                }
+                page_ids.push(id);
+                result.notices.push(notice);
+            }
+
+            let new_rows = page_ids.len();
+            seen.extend(page_ids);
+            result.parse_time += parse_started.elapsed();
+            if page_number == max_pages || new_rows == 0 || repeated {
+                break;
+            }
+            match Self::next_page_url(&document, selectors, board, &page_url, page_number + 1) {
+                Some(next) if next != page_url => page_url = next,
+                _ => break,
             }
         }
-        Ok(BoardListResult {
-            notices,
-            row_total,
-            row_failures,
-        })
+
+        Self::infer_missing_dates(&mut result.notices);
+        Self::limit_pinned(
+            &mut result.notices,
+            self.config.crawler.max_pinned_per_board,
+            self.config.crawler.drop_excess_pinned,
+        );
+        Ok(result)
+    }
+
+    /// Run a list request, retried with exponential backoff while the error
+    /// is retryable and `board_max_retries` allows.
+    ///
+    /// A `Retry-After` on a 429 or 503 stretches the backoff to the requested
+    /// delay; one longer than [`MAX_RETRY_AFTER`] ends the retries instead.
+    /// Backoff sleeps and request delays between attempts are added to
+    /// `waited`.
+    async fn fetch_with_retry<T, F, Fut>(
+        &self,
+        url: &str,
+        waited: &mut Duration,
+        fetch: F,
+    ) -> Result<T>
+    where
+        F: Fn() -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        let max_retries = self.config.crawler.board_max_retries;
+        let mut attempt = 0;
+        loop {
+            match fetch().await {
+                Err(error) if error.is_retryable() && attempt < max_retries => {
+                    let backoff = Duration::from_millis(
+                        self.config
+                            .crawler
+                            .board_retry_base_ms
+                            .saturating_mul(1 << attempt.min(16)),
+                    );
+                    let wait = match error.retry_after() {
+                        Some(requested) if requested > MAX_RETRY_AFTER => return Err(error),
+                        Some(requested) => backoff.max(requested),
+                        None => backoff,
+                    };
+                    attempt += 1;
+                    log::debug!(
+                        "Retrying {} in {}ms (attempt {}/{}): {}",
+                        url,
+                        wait.as_millis(),
+                        attempt,
+                        max_retries,
+                        error
+                    );
+                    tokio::time::sleep(wait).await;
+                    *waited += wait + self.apply_request_delay(url).await;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    /// URL of list page `next_page`, from the "next" link or the URL template.
+    fn next_page_url(
+        document: &Html,
+        selectors: &BoardSelectors,
+        board: &Board,
+        page_url: &str,
+        next_page: usize,
+    ) -> Option<String> {
+        if let Some(pagination) = &selectors.pagination {
+            let href = document.select(pagination).next()?.value().attr("href")?;
+            return resolve(page_url, href);
+        }
+        let pattern = board.selectors.next_url_pattern.as_ref()?;
+        resolve(
+            &board.url,
+            &pattern.replace("{page}", &next_page.to_string()),
+        )
     }
 
     /// Process a single notice (placeholder for future detail fetching).
@@ -224,51 +777,124 @@ impl NoticeCrawler {
         &self,
         notice: Notice,
         _board_lookup: &HashMap<&str, &Board>,
-        _selector_cache: &HashMap<String, Arc<BoardSelectors>>,
+        _selector_cache: &HashMap<BoardKey, Arc<BoardSelectors>>,
     ) -> Result<Notice> {
         // Note: Body content is no longer stored in the notice.
         // This method is kept for future pinned detection or other metadata
         Ok(notice)
     }
 
+    /// Parse a single board row into a notice.
+    ///
+    /// `row_index` is the row's position on the board page. It becomes the
+    /// last-resort `source_id` (`{board_id}:row{N}`) when the row has no usable
+    /// link, keeping otherwise identical rows distinct.
     #[allow(clippy::too_many_arguments)]
     fn parse_notice_row(
         &self,
         row: &scraper::ElementRef,
+        row_index: usize,
         selectors: &BoardSelectors,
         attr_name: &str,
         dept_ref: DepartmentRef<'_>,
         board: &Board,
         base_url: &url::Url,
     ) -> Option<Notice> {
+        let cleaning = &self.config.cleaning;
         let title_elem = row.select(&selectors.title).next()?;
-        let date_elem = row.select(&selectors.date).next()?;
-        let author_elem = selectors
-            .author
-            .as_ref()
-            .and_then(|sel| row.select(sel).next());
+        // A date column can move (e.g. when a view-count column is added):
+        // the configured fallbacks are tried in order, then a missing, empty
+        // or numeric date cell falls back to the first other cell holding a
+        // date
+        let date_texts: Vec<String> = std::iter::once(&selectors.date)
+            .chain(&selectors.date_fallbacks)
+            .filter_map(|sel| row.select(sel).next())
+            .map(|el| cleaning.strip_labels(&cleaning.clean_date(&el.text().collect::<String>())))
+            .collect();
+        let (date, date_cell_rest) = match date_texts.iter().find_map(|text| Self::split_date(text))
+        {
+            Some(found) => found,
+            None => match date_texts.into_iter().next() {
+                Some(text) if !text.is_empty() && !Self::is_count(&text) => (text, String::new()),
+                text => Self::find_date_cell(row, &title_elem, cleaning)
+                    .or_else(|| text.map(|text| (text, String::new())))?,
+            },
+        };
 
         let raw_title: String = title_elem.text().collect();
-        let raw_date: String = date_elem.text().collect();
-        let raw_author: String = author_elem.map_or(String::new(), |el| el.text().collect());
-
-        let title = self.config.cleaning.clean_title(&raw_title);
-        let date = self.config.cleaning.clean_date(&raw_date);
-
+        let title = cleaning.clean_title(&raw_title, &board.name);
         if title.is_empty() {
             return None;
         }
 
+        let author = selectors
+            .author
+            .iter()
+            .chain(&selectors.author_fallbacks)
+            .filter_map(|sel| row.select(sel).next())
+            .map(|el| cleaning.strip_labels(&el.text().collect::<String>()))
+            .find(|author| !author.is_empty())
+            // Author sharing the date cell ("작성자: 학사팀 | 등록일: 2024-01-05"),
+            // unless the rest is only a time of day
+            .or_else(|| {
+                date_cell_rest
+                    .chars()
+                    .any(char::is_alphabetic)
+                    .then_some(date_cell_rest)
+            })
+            .unwrap_or_default();
+        let views = selectors
+            .views
+            .as_ref()
+            .and_then(|sel| row.select(sel).next())
+            .and_then(|el| {
+                Self::parse_count(&cleaning.strip_labels(&el.text().collect::<String>()))
+            });
+
         let link_elem = selectors
             .link
             .as_ref()
             .and_then(|sel| row.select(sel).next())
-            .or(Some(title_elem));
-        let raw_link = link_elem
-            .and_then(|e| e.value().attr(attr_name))
-            .unwrap_or("");
-        let link = resolve_url(base_url, raw_link);
-        let source_id = extract_notice_id(&link);
+            .unwrap_or(title_elem);
+        let raw_link =
+            Self::extract_link(&link_elem, attr_name, &board.selectors.link_attr_fallbacks)
+                .or_else(|| {
+                    Self::extract_link(row, attr_name, &board.selectors.link_attr_fallbacks)
+                })
+                .unwrap_or_default();
+        let link = resolve_url(base_url, &raw_link);
+        let source_id =
+            extract_notice_id_with(&link, &self.config.crawler.id_extraction).or_else(|| {
+                raw_link
+                    .is_empty()
+                    .then(|| format!("{}:row{}", board.id, row_index))
+            });
+
+        let is_pinned = Self::is_pinned_row(
+            row,
+            &title_elem,
+            selectors.pinned.as_ref(),
+            board.selectors.pinned_class.as_deref(),
+        );
+
+        let mut attachments: Vec<String> = Vec::new();
+        if let Some(sel) = &selectors.attachment {
+            // Attachments are plain download links even on boards whose notice
+            // links use another attribute
+            for href in row.select(sel).filter_map(|a| a.value().attr("href")) {
+                let url = resolve_url(base_url, href);
+                if !attachments.contains(&url) {
+                    attachments.push(url);
+                }
+            }
+        }
+
+        let lang = self
+            .config
+            .crawler
+            .detect_language
+            .then(|| detect_lang(&title).map(str::to_string))
+            .flatten();
 
         Some(Notice {
             campus: dept_ref.campus.to_string(),
@@ -278,27 +904,391 @@ impl NoticeCrawler {
             board_id: board.id.clone(),
             board_name: board.name.clone(),
             title,
-            author: raw_author.trim().to_string(),
+            author,
             date,
+            views,
             link,
             source_id,
-            is_pinned: false, // TODO: Detect pinned notices from row styling
+            is_pinned,
+            date_inferred: false,
+            lang,
+            attachments,
         })
     }
 
-    async fn apply_request_delay(&self) {
-        let delay_ms = self.config.crawler.request_delay_ms;
-        if delay_ms > 0 {
-            tokio::time::sleep(Duration::from_millis(delay_ms)).await;
+    /// Find a date in a cell's text, returning it with the remaining text
+    /// (e.g. an author sharing the cell) trimmed of separators.
+    fn split_date(text: &str) -> Option<(String, String)> {
+        static DATE: LazyLock<Regex> = LazyLock::new(|| {
+            Regex::new(
+                r"\d{2,4}\s*[.\-/]\s*\d{1,2}\s*[.\-/]\s*\d{1,2}\.?|\d{2,4}\s*년\s*\d{1,2}\s*월\s*\d{1,2}\s*일",
+            )
+            .unwrap()
+        });
+        let found = DATE.find(text)?;
+        let rest = format!("{} {}", &text[..found.start()], &text[found.end()..]);
+        let rest = rest
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ")
+            .trim_matches(|c: char| c.is_whitespace() || "|/·,-()[]".contains(c))
+            .to_string();
+        Some((found.as_str().trim_end_matches('.').to_string(), rest))
+    }
+
+    /// Date and remaining text of the first cell of `row`, other than the
+    /// title's, that holds a date.
+    fn find_date_cell(
+        row: &scraper::ElementRef,
+        title_elem: &scraper::ElementRef,
+        cleaning: &CleaningConfig,
+    ) -> Option<(String, String)> {
+        static CELL: LazyLock<Selector> = LazyLock::new(|| Selector::parse("td, th").unwrap());
+        row.select(&CELL)
+            .filter(|cell| !cell.descendants().any(|node| node.id() == title_elem.id()))
+            .find_map(|cell| {
+                let text = cleaning.clean_date(&cell.text().collect::<String>());
+                Self::split_date(&cleaning.strip_labels(&text))
+            })
+    }
+
+    /// Whether `text` is a bare count such as a view count (`1,024`).
+    fn is_count(text: &str) -> bool {
+        !text.is_empty() && text.chars().all(|c| c.is_ascii_digit() || c == ',')
+    }
+
+    /// Parse a count such as `1,024` or `조회 57`.
+    fn parse_count(text: &str) -> Option<u32> {
+        let digits: String = text.chars().filter(char::is_ascii_digit).collect();
+        digits.parse().ok()
+    }
+
+    /// Whether a list row is a pinned (공지/중요) notice.
+    ///
+    /// With `pinned_selector` or `pinned_class` configured, the row is pinned
+    /// when either matches. Otherwise the row's first cell decides: pinned when
+    /// its text, or an icon's `alt` or file name, holds one of
+    /// [`PINNED_MARKERS`] where a post number would be, unless that cell is
+    /// the title itself. Other non-numeric cells (dates, categories) are not
+    /// markers.
+    fn is_pinned_row(
+        row: &scraper::ElementRef,
+        title_elem: &scraper::ElementRef,
+        pinned: Option<&Selector>,
+        pinned_class: Option<&str>,
+    ) -> bool {
+        if pinned.is_some() || pinned_class.is_some() {
+            let by_class = pinned_class
+                .is_some_and(|class| row.value().classes().any(|c| c.eq_ignore_ascii_case(class)));
+            let by_selector =
+                pinned.is_some_and(|sel| sel.matches(row) || row.select(sel).next().is_some());
+            return by_class || by_selector;
+        }
+
+        let Some(first_cell) = row.children().find_map(scraper::ElementRef::wrap) else {
+            return false;
+        };
+        if first_cell.id() == title_elem.id()
+            || first_cell.descendants().any(|n| n.id() == title_elem.id())
+        {
+            return false;
+        }
+
+        static IMG: LazyLock<Selector> = LazyLock::new(|| Selector::parse("img").unwrap());
+        let is_marker = |text: &str| {
+            let text = text.to_lowercase();
+            PINNED_MARKERS.iter().any(|marker| text.contains(marker))
+        };
+        is_marker(&first_cell.text().collect::<String>())
+            || first_cell.select(&IMG).any(|img| {
+                let icon = img.value();
+                icon.attr("alt").is_some_and(is_marker)
+                    || icon
+                        .attr("src")
+                        .and_then(|src| src.rsplit('/').next())
+                        .is_some_and(is_marker)
+            })
+    }
+
+    /// Fill empty dates from the nearest preceding dated row on the same board.
+    ///
+    /// Rows are in page order, so an undated row most likely belongs to the
+    /// same period as the row above it. Rows before the first dated row are
+    /// left empty rather than guessed.
+    fn infer_missing_dates(notices: &mut [Notice]) {
+        let mut last_date: Option<String> = None;
+        for notice in notices {
+            if notice.date.trim().is_empty() {
+                if let Some(date) = &last_date {
+                    notice.date = date.clone();
+                    notice.date_inferred = true;
+                }
+            } else {
+                last_date = Some(notice.date.clone());
+            }
+        }
+    }
+
+    /// Collapse notices sharing a canonical ID within `scope`, keeping the first.
+    fn dedup(
+        notices: Vec<(&str, Notice)>,
+        scope: DedupScope,
+        id_options: IdOptions<'_>,
+    ) -> Vec<Notice> {
+        let mut seen = HashSet::new();
+        notices
+            .into_iter()
+            .filter(|(board_url, notice)| match scope {
+                DedupScope::Global => seen.insert((None, notice.canonical_id_with(id_options))),
+                DedupScope::PerBoard => {
+                    seen.insert((Some(*board_url), notice.canonical_id_with(id_options)))
+                }
+                DedupScope::None => true,
+            })
+            .map(|(_, notice)| notice)
+            .collect()
+    }
+
+    /// Drop notices dated before `now - max_age_days`. Notices whose date
+    /// does not parse are kept; pinned notices are judged by date like any
+    /// other, so a long-pinned announcement is dropped too.
+    fn filter_by_age(notices: Vec<Notice>, max_age_days: u32, now: DateTime<Utc>) -> Vec<Notice> {
+        let Some(cutoff) = now
+            .date_naive()
+            .checked_sub_days(chrono::Days::new(max_age_days.into()))
+        else {
+            return notices;
+        };
+        notices
+            .into_iter()
+            .filter(|notice| {
+                NaiveDate::parse_from_str(&notice.normalized_date_at(now), "%Y-%m-%d")
+                    .ok()
+                    .is_none_or(|date| date >= cutoff)
+            })
+            .collect()
+    }
+
+    /// Collapse notices listed on several boards of one department.
+    ///
+    /// Two notices of a department match when their titles (compared by
+    /// alphanumerics only) and dates are equal, or when they share a
+    /// `source_id` and also agree on the title or the date; boards that
+    /// number articles per board reuse IDs for unrelated notices. Matches
+    /// chain, so A~B and B~C put all three in one group. Each group keeps
+    /// its pinned notice, else the earliest dated one, at the position of
+    /// the group's first notice.
+    fn dedup_cross_board(notices: Vec<Notice>) -> Vec<Notice> {
+        let keys: Vec<(String, String)> = notices
+            .iter()
+            .map(|n| (normalize_text(&n.title), n.normalized_date()))
+            .collect();
+        let mut parent: Vec<usize> = (0..notices.len()).collect();
+
+        let mut by_title: HashMap<(&str, &str, &str), usize> = HashMap::new();
+        let mut by_source: HashMap<(&str, &str), Vec<usize>> = HashMap::new();
+        for (i, notice) in notices.iter().enumerate() {
+            let dept = notice.department_id.as_str();
+            let (title, date) = (keys[i].0.as_str(), keys[i].1.as_str());
+            if !title.is_empty() {
+                let first = *by_title.entry((dept, title, date)).or_insert(i);
+                Self::union(&mut parent, first, i);
+            }
+            if let Some(source_id) = notice.source_id.as_deref().filter(|s| !s.is_empty()) {
+                let earlier = by_source.entry((dept, source_id)).or_default();
+                for &j in earlier.iter() {
+                    let (other_title, other_date) = (&keys[j].0, &keys[j].1);
+                    if (!title.is_empty() && title == other_title)
+                        || (!date.is_empty() && date == other_date)
+                    {
+                        Self::union(&mut parent, j, i);
+                    }
+                }
+                earlier.push(i);
+            }
+        }
+
+        // Roots are the lowest index of their group
+        let mut best: HashMap<usize, usize> = HashMap::new();
+        for i in 0..notices.len() {
+            let root = Self::find(&mut parent, i);
+            let kept = best.entry(root).or_insert(i);
+            let rank = |k: usize| {
+                let date = &keys[k].1;
+                (
+                    notices[k].is_pinned,
+                    !date.is_empty(),
+                    std::cmp::Reverse(date),
+                )
+            };
+            if rank(i) > rank(*kept) {
+                *kept = i;
+            }
+        }
+        let mut slots: Vec<Option<Notice>> = notices.into_iter().map(Some).collect();
+        (0..slots.len())
+            .filter_map(|i| best.get(&i).copied())
+            .filter_map(|k| slots[k].take())
+            .collect()
+    }
+
+    /// Union-find root of `i`, halving the path on the way.
+    fn find(parent: &mut [usize], mut i: usize) -> usize {
+        while parent[i] != i {
+            parent[i] = parent[parent[i]];
+            i = parent[i];
+        }
+        i
+    }
+
+    /// Merge the groups of `a` and `b`, rooting them at the lower index.
+    fn union(parent: &mut [usize], a: usize, b: usize) {
+        let (ra, rb) = (Self::find(parent, a), Self::find(parent, b));
+        parent[ra.max(rb)] = ra.min(rb);
+    }
+
+    /// Keep at most `max` notices, taking them round-robin across boards.
+    ///
+    /// Each board contributes its pinned notices first, then rows in page
+    /// order, so shares differ by at most one unless a board runs out.
+    /// Kept notices stay grouped by board. `max == 0` disables the cap.
+    fn cap_total(notices: Vec<Notice>, max: usize) -> Vec<Notice> {
+        if max == 0 || notices.len() <= max {
+            return notices;
+        }
+        let total = notices.len();
+
+        let board_key = |n: &Notice| {
+            (
+                n.campus.clone(),
+                n.department_id.clone(),
+                n.board_id.clone(),
+            )
+        };
+        let mut boards: Vec<(_, VecDeque<Notice>)> = Vec::new();
+        for notice in notices {
+            let key = board_key(&notice);
+            match boards.iter_mut().find(|(board, _)| *board == key) {
+                Some((_, queue)) => queue.push_back(notice),
+                None => boards.push((key, VecDeque::from([notice]))),
+            }
+        }
+        for (_, queue) in &mut boards {
+            queue.make_contiguous().sort_by_key(|n| !n.is_pinned);
+        }
+
+        let mut kept: Vec<Vec<Notice>> = vec![Vec::new(); boards.len()];
+        let mut remaining = max;
+        while remaining > 0 {
+            let mut progressed = false;
+            for (i, (_, queue)) in boards.iter_mut().enumerate() {
+                if remaining == 0 {
+                    break;
+                }
+                if let Some(notice) = queue.pop_front() {
+                    kept[i].push(notice);
+                    remaining -= 1;
+                    progressed = true;
+                }
+            }
+            if !progressed {
+                break;
+            }
+        }
+
+        log::info!(
+            "Capped notices at {} of {} across {} boards",
+            max,
+            total,
+            boards.len()
+        );
+        kept.into_iter().flatten().collect()
+    }
+
+    /// Keep only the newest `max` pinned notices on a board.
+    ///
+    /// Excess pinned notices are demoted to regular rows, or removed when
+    /// `drop_excess` is set. Ties on date keep page order. `max == 0` disables
+    /// the limit.
+    fn limit_pinned(notices: &mut Vec<Notice>, max: usize, drop_excess: bool) {
+        if max == 0 {
+            return;
+        }
+
+        let mut pinned: Vec<usize> = (0..notices.len())
+            .filter(|&i| notices[i].is_pinned)
+            .collect();
+        if pinned.len() <= max {
+            return;
+        }
+
+        // Raw dates mix formats ("2024.03.01", "2024/03/01"), so compare
+        // them normalized
+        pinned.sort_by_cached_key(|&i| std::cmp::Reverse(notices[i].normalized_date()));
+        let excess: HashSet<usize> = pinned[max..].iter().copied().collect();
+
+        if drop_excess {
+            let mut index = 0;
+            notices.retain(|_| {
+                let keep = !excess.contains(&index);
+                index += 1;
+                keep
+            });
+        } else {
+            for &i in &excess {
+                notices[i].is_pinned = false;
+            }
+        }
+    }
+
+    /// Extract a usable link from an element, trying `attr_name` then each fallback.
+    ///
+    /// Placeholder values (`#`, empty) are skipped; `onclick` handlers and
+    /// `javascript:` links are scanned for an embedded URL.
+    fn extract_link(
+        elem: &scraper::ElementRef,
+        attr_name: &str,
+        fallbacks: &[String],
+    ) -> Option<String> {
+        std::iter::once(attr_name)
+            .chain(fallbacks.iter().map(String::as_str))
+            .filter_map(|attr| {
+                let value = elem.value().attr(attr)?.trim();
+                if attr.eq_ignore_ascii_case("onclick")
+                    || value.to_lowercase().starts_with("javascript:")
+                {
+                    extract_script_url(value)
+                } else if value.is_empty() || value.starts_with('#') {
+                    None
+                } else {
+                    Some(value.to_string())
+                }
+            })
+            .next()
+    }
+
+    /// Wait before a request to `url`: the per-domain limiter when
+    /// `per_domain_rps` is set, otherwise the fixed `request_delay_ms`.
+    /// Returns how long it waited.
+    async fn apply_request_delay(&self, url: &str) -> Duration {
+        let started = Instant::now();
+        if let Some(limiter) = &self.rate_limiter {
+            limiter.acquire(&get_domain(url).unwrap_or_default()).await;
+        } else {
+            let delay_ms = self.config.crawler.request_delay_ms;
+            if delay_ms > 0 {
+                tokio::time::sleep(Duration::from_millis(delay_ms)).await;
+            }
         }
+        started.elapsed()
     }
 
     fn build_selector_cache(
         campuses: &[Campus],
     ) -> (
-        HashMap<String, Arc<BoardSelectors>>,
+        HashMap<BoardKey, Arc<BoardSelectors>>,
         Vec<CrawlError>,
-        HashSet<String>,
+        HashSet<BoardKey>,
     ) {
         let mut cache = HashMap::new();
         let mut errors = Vec::new();
@@ -306,90 +1296,130 @@ impl NoticeCrawler {
 
         for campus in campuses {
             for dept_ref in campus.all_departments() {
-                for board in &dept_ref.dept.boards {
+                for board in dept_ref.dept.boards.iter().filter(|b| b.enabled) {
                     let row = match Self::parse_selector(&board.selectors.row_selector) {
                         Ok(sel) => sel,
                         Err(err) => {
-                            errors.push(Self::build_error(
-                                CrawlStage::Selector,
-                                Some(board),
-                                Some(&board.url),
-                                None,
-                                &err,
-                            ));
-                            invalid_boards.insert(board.id.clone());
+                            errors.push(
+                                Self::build_error(
+                                    CrawlStage::Selector,
+                                    Some(board),
+                                    Some(&board.url),
+                                    None,
+                                    &err,
+                                )
+                                .with_department(dept_ref),
+                            );
+                            invalid_boards.insert(board_key(dept_ref, board));
                             continue;
                         }
                     };
                     let title = match Self::parse_selector(&board.selectors.title_selector) {
                         Ok(sel) => sel,
                         Err(err) => {
-                            errors.push(Self::build_error(
-                                CrawlStage::Selector,
-                                Some(board),
-                                Some(&board.url),
-                                None,
-                                &err,
-                            ));
-                            invalid_boards.insert(board.id.clone());
-                            continue;
-                        }
-                    };
-                    let date = match Self::parse_selector(&board.selectors.date_selector) {
-                        Ok(sel) => sel,
-                        Err(err) => {
-                            errors.push(Self::build_error(
-                                CrawlStage::Selector,
-                                Some(board),
-                                Some(&board.url),
-                                None,
-                                &err,
-                            ));
-                            invalid_boards.insert(board.id.clone());
-                            continue;
-                        }
-                    };
-                    let author = match board.selectors.author_selector.as_ref() {
-                        Some(sel) => match Self::parse_selector(sel) {
-                            Ok(parsed) => Some(parsed),
-                            Err(err) => {
-                                errors.push(Self::build_error(
+                            errors.push(
+                                Self::build_error(
                                     CrawlStage::Selector,
                                     Some(board),
                                     Some(&board.url),
                                     None,
                                     &err,
-                                ));
-                                None
-                            }
-                        },
-                        None => None,
+                                )
+                                .with_department(dept_ref),
+                            );
+                            invalid_boards.insert(board_key(dept_ref, board));
+                            continue;
+                        }
                     };
-                    let link = match board.selectors.link_selector.as_ref() {
-                        Some(sel) => match Self::parse_selector(sel) {
-                            Ok(parsed) => Some(parsed),
-                            Err(err) => {
-                                errors.push(Self::build_error(
+                    let date = match Self::parse_selector(&board.selectors.date_selector) {
+                        Ok(sel) => sel,
+                        Err(err) => {
+                            errors.push(
+                                Self::build_error(
                                     CrawlStage::Selector,
                                     Some(board),
                                     Some(&board.url),
                                     None,
                                     &err,
-                                ));
-                                None
-                            }
-                        },
-                        None => None,
+                                )
+                                .with_department(dept_ref),
+                            );
+                            invalid_boards.insert(board_key(dept_ref, board));
+                            continue;
+                        }
                     };
+                    let author = Self::parse_optional_selector(
+                        board.selectors.author_selector.as_deref(),
+                        dept_ref,
+                        board,
+                        &mut errors,
+                    );
+                    let views = Self::parse_optional_selector(
+                        board.selectors.views_selector.as_deref(),
+                        dept_ref,
+                        board,
+                        &mut errors,
+                    );
+                    let date_fallbacks = Self::parse_fallback_selectors(
+                        &board.selectors.date_fallbacks,
+                        dept_ref,
+                        board,
+                        &mut errors,
+                    );
+                    let author_fallbacks = Self::parse_fallback_selectors(
+                        &board.selectors.author_fallbacks,
+                        dept_ref,
+                        board,
+                        &mut errors,
+                    );
+                    let link = Self::parse_optional_selector(
+                        board.selectors.link_selector.as_deref(),
+                        dept_ref,
+                        board,
+                        &mut errors,
+                    );
+
+                    let content_root = Self::parse_content_root(
+                        board.content_root_selector.as_deref(),
+                        dept_ref,
+                        board,
+                        &mut errors,
+                    );
+
+                    let pinned = Self::parse_optional_selector(
+                        board.selectors.pinned_selector.as_deref(),
+                        dept_ref,
+                        board,
+                        &mut errors,
+                    );
+                    let pagination = Self::parse_optional_selector(
+                        board.selectors.pagination_selector.as_deref(),
+                        dept_ref,
+                        board,
+                        &mut errors,
+                    );
+                    let attachment = Self::parse_optional_selector(
+                        board.selectors.attachment_selector.as_deref(),
+                        dept_ref,
+                        board,
+                        &mut errors,
+                    );
 
                     cache.insert(
-                        board.id.clone(),
+                        board_key(dept_ref, board),
                         Arc::new(BoardSelectors {
                             row,
                             title,
                             date,
+                            date_fallbacks,
                             author,
+                            author_fallbacks,
+                            views,
                             link,
+                            content_root,
+                            pinned,
+                            pagination,
+                            attachment,
                         }),
                     );
                 }
@@ -399,25 +1429,100 @@ impl NoticeCrawler {
         (cache, errors, invalid_boards)
     }
 
-    fn build_error(
-        stage: CrawlStage,
-        board: Option<&Board>,
-        url: Option<&str>,
-        notice_id: Option<&str>,
-        error: &AppError,
-    ) -> CrawlError {
-        CrawlError {
+    /// Parse an optional board selector. An invalid one is reported as a
+    /// [`CrawlStage::Selector`] error and left unset, so the board is still
+    /// crawled without it.
+    fn parse_optional_selector(
+        selector: Option<&str>,
+        dept_ref: DepartmentRef<'_>,
+        board: &Board,
+        errors: &mut Vec<CrawlError>,
+    ) -> Option<Selector> {
+        match Self::parse_selector(selector?) {
+            Ok(parsed) => Some(parsed),
+            Err(err) => {
+                errors.push(
+                    Self::build_error(
+                        CrawlStage::Selector,
+                        Some(board),
+                        Some(&board.url),
+                        None,
+                        &err,
+                    )
+                    .with_department(dept_ref),
+                );
+                None
+            }
+        }
+    }
+
+    /// Parse a board's content root. Only simple selectors can be matched
+    /// before the page is parsed; any other is reported as a
+    /// [`CrawlStage::Selector`] error and the whole page is parsed instead.
+    fn parse_content_root(
+        selector: Option<&str>,
+        dept_ref: DepartmentRef<'_>,
+        board: &Board,
+        errors: &mut Vec<CrawlError>,
+    ) -> Option<RegionSelector> {
+        let selector = selector?;
+        let parsed = RegionSelector::parse(selector);
+        if parsed.is_none() {
+            let err = AppError::selector(
+                selector,
+                "a content root must be a tag, #id or .class selector",
+            );
+            errors.push(
+                Self::build_error(
+                    CrawlStage::Selector,
+                    Some(board),
+                    Some(&board.url),
+                    None,
+                    &err,
+                )
+                .with_department(dept_ref),
+            );
+        }
+        parsed
+    }
+
+    /// Parse a list of fallback selectors, reporting and skipping invalid ones.
+    fn parse_fallback_selectors(
+        selectors: &[String],
+        dept_ref: DepartmentRef<'_>,
+        board: &Board,
+        errors: &mut Vec<CrawlError>,
+    ) -> Vec<Selector> {
+        selectors
+            .iter()
+            .filter_map(|sel| Self::parse_optional_selector(Some(sel), dept_ref, board, errors))
+            .collect()
+    }
+
+    fn build_error(
+        stage: CrawlStage,
+        board: Option<&Board>,
+        url: Option<&str>,
+        notice_id: Option<&str>,
+        error: &AppError,
+    ) -> CrawlError {
+        CrawlError {
             stage,
+            code: CrawlErrorCode::from_error(error),
+            campus: None,
+            department_id: None,
             board_id: board.map(|b| b.id.clone()),
             board_name: board.map(|b| b.name.clone()),
             url: url.map(str::to_string),
             notice_id: notice_id.map(str::to_string),
             message: error.to_string(),
             retryable: error.is_retryable(),
+            http_status: error.http_status(),
+            duration_ms: None,
         }
     }
 
-    fn build_board_lookup<'a>(campuses: &'a [Campus]) -> HashMap<&'a str, &'a Board> {
+    fn build_board_lookup(campuses: &[Campus]) -> HashMap<&str, &Board> {
         campuses
             .iter()
             .flat_map(|campus| campus.all_departments())
@@ -448,7 +1553,1315 @@ impl NoticeCrawler {
 
 #[cfg(test)]
 mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
     use super::*;
+    use crate::models::{CmsSelectors, Department, FetchConfig, NoticeOutput};
+    use crate::utils::test_server::{TestResponse, TestServer};
+
+    fn test_crawler() -> NoticeCrawler {
+        NoticeCrawler::new(Arc::new(Config::default()), Client::new()).unwrap()
+    }
+
+    fn test_department(selectors: CmsSelectors) -> Department {
+        Department {
+            id: "dept1".to_string(),
+            name: "Department".to_string(),
+            url: "https://example.com".to_string(),
+            boards: vec![Board {
+                id: "notice".to_string(),
+                name: "공지사항".to_string(),
+                url: "https://example.com/board/list".to_string(),
+                selectors,
+                content_root_selector: None,
+                link_base: None,
+                enabled: true,
+                fetch: FetchConfig::default(),
+                source: BoardSource::Html,
+            }],
+        }
+    }
+
+    fn board_selectors(selectors: &CmsSelectors) -> BoardSelectors {
+        BoardSelectors {
+            row: NoticeCrawler::parse_selector(&selectors.row_selector).unwrap(),
+            title: NoticeCrawler::parse_selector(&selectors.title_selector).unwrap(),
+            date: NoticeCrawler::parse_selector(&selectors.date_selector).unwrap(),
+            date_fallbacks: fallback_selectors(&selectors.date_fallbacks),
+            author: selectors
+                .author_selector
+                .as_ref()
+                .map(|sel| NoticeCrawler::parse_selector(sel).unwrap()),
+            author_fallbacks: fallback_selectors(&selectors.author_fallbacks),
+            views: selectors
+                .views_selector
+                .as_ref()
+                .map(|sel| NoticeCrawler::parse_selector(sel).unwrap()),
+            link: None,
+            content_root: None,
+            pinned: board_pinned_selector(selectors),
+            pagination: None,
+            attachment: selectors
+                .attachment_selector
+                .as_ref()
+                .map(|sel| NoticeCrawler::parse_selector(sel).unwrap()),
+        }
+    }
+
+    fn fallback_selectors(selectors: &[String]) -> Vec<Selector> {
+        selectors
+            .iter()
+            .map(|sel| NoticeCrawler::parse_selector(sel).unwrap())
+            .collect()
+    }
+
+    fn board_pinned_selector(selectors: &CmsSelectors) -> Option<Selector> {
+        selectors
+            .pinned_selector
+            .as_ref()
+            .map(|sel| NoticeCrawler::parse_selector(sel).unwrap())
+    }
+
+    /// Parse every row of `html` for the single test board of `dept`.
+    /// Rows of `html` parsed with the default config, all expected to parse.
+    fn notices_of(dept: &Department, html: &str) -> Vec<Notice> {
+        parse_rows(&test_crawler(), dept, html)
+            .into_iter()
+            .map(Option::unwrap)
+            .collect()
+    }
+
+    fn parse_rows(crawler: &NoticeCrawler, dept: &Department, html: &str) -> Vec<Option<Notice>> {
+        let board = &dept.boards[0];
+        let selectors = board_selectors(&board.selectors);
+        let dept_ref = DepartmentRef {
+            campus: "TestCampus",
+            college: None,
+            dept,
+            campus_idx: 0,
+            college_idx: None,
+            dept_idx: 0,
+        };
+        let base_url = url::Url::parse(board.link_base_url()).unwrap();
+        let document = Html::parse_document(html);
+        document
+            .select(&selectors.row)
+            .enumerate()
+            .map(|(row_index, row)| {
+                crawler.parse_notice_row(
+                    &row,
+                    row_index,
+                    &selectors,
+                    &board.selectors.attr_name,
+                    dept_ref,
+                    board,
+                    &base_url,
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_link_from_data_url_attribute() {
+        let dept = test_department(CmsSelectors::default());
+        let html = r##"<table>
+            <tr><td><a href="#" data-url="/board/view?id=11">Data URL notice</a></td><td>2024-01-15</td></tr>
+        </table>"##;
+
+        let notices = notices_of(&dept, html);
+        let notice = &notices[0];
+        assert_eq!(notice.link, "https://example.com/board/view?id=11");
+        assert_eq!(notice.source_id.as_deref(), Some("11"));
+    }
+
+    #[test]
+    fn test_link_from_onclick_handler() {
+        let dept = test_department(CmsSelectors::default());
+        let html = r##"<table>
+            <tr><td><a href="javascript:void(0)" onclick="location.href='/board/view?id=12'">Onclick notice</a></td><td>2024-01-15</td></tr>
+        </table>"##;
+
+        let notices = notices_of(&dept, html);
+        assert_eq!(notices[0].link, "https://example.com/board/view?id=12");
+    }
+
+    #[test]
+    fn test_link_fallback_order_is_configurable() {
+        let selectors = CmsSelectors {
+            link_attr_fallbacks: vec!["data-href".to_string(), "data-url".to_string()],
+            ..CmsSelectors::default()
+        };
+        let dept = test_department(selectors);
+        let html = r##"<table>
+            <tr><td><a href="#" data-url="/a?id=1" data-href="/b?id=2">Both</a></td><td>2024-01-15</td></tr>
+        </table>"##;
+
+        let notices = notices_of(&dept, html);
+        assert_eq!(notices[0].link, "https://example.com/b?id=2");
+    }
+
+    #[test]
+    fn test_identical_linkless_rows_get_distinct_ids() {
+        let dept = test_department(CmsSelectors::default());
+        let html = r##"<table>
+            <tr><td><a href="#">Same notice</a></td><td>2024-01-15</td></tr>
+            <tr><td><a href="#">Same notice</a></td><td>2024-01-15</td></tr>
+        </table>"##;
+
+        let notices: Vec<Notice> = notices_of(&dept, html);
+
+        assert_eq!(notices[0].source_id.as_deref(), Some("notice:row0"));
+        assert_eq!(notices[1].source_id.as_deref(), Some("notice:row1"));
+        assert_ne!(notices[0].canonical_id(), notices[1].canonical_id());
+    }
+
+    #[test]
+    fn test_relative_link_resolves_against_link_base() {
+        let html = r##"<table>
+            <tr><td><a href="view.do?id=5">Relative notice</a></td><td>2024-01-15</td></tr>
+        </table>"##;
+
+        let mut dept = test_department(CmsSelectors::default());
+        let notices = notices_of(&dept, html);
+        assert_eq!(notices[0].link, "https://example.com/board/view.do?id=5");
+
+        dept.boards[0].link_base = Some("https://example.com/bbs/article/".to_string());
+        let notices = notices_of(&dept, html);
+        assert_eq!(
+            notices[0].link,
+            "https://example.com/bbs/article/view.do?id=5"
+        );
+    }
+
+    #[test]
+    fn test_combined_author_date_cell() {
+        let dept = test_department(CmsSelectors::default());
+        let html = r##"<table>
+            <tr><td><a href="/view?id=1">Combined</a></td><td>작성자: 학사팀 | 등록일: 2024.01.05</td></tr>
+            <tr><td><a href="/view?id=2">Timestamp</a></td><td>2024-01-06 10:30</td></tr>
+        </table>"##;
+        let notices: Vec<Notice> = notices_of(&dept, html);
+
+        assert_eq!(notices[0].date, "2024.01.05");
+        assert_eq!(notices[0].normalized_date(), "2024-01-05");
+        assert_eq!(notices[0].author, "학사팀");
+        assert_eq!(notices[1].date, "2024-01-06");
+        assert_eq!(notices[1].author, "");
+    }
+
+    #[test]
+    fn test_views_column_and_shifted_date() {
+        let dept = test_department(CmsSelectors {
+            author_selector: Some("td.writer".to_string()),
+            views_selector: Some("td.hit".to_string()),
+            ..CmsSelectors::default()
+        });
+        // The last cell is the view count, not the date
+        let html = r##"<table>
+            <tr><td><a href="/view?id=1">Shifted</a></td><td class="writer">작성자: 장학팀</td>
+                <td>2024-02-01</td><td class="hit">조회수 1,024</td></tr>
+        </table>"##;
+        let notice = notices_of(&dept, html).remove(0);
+
+        assert_eq!(notice.date, "2024-02-01");
+        assert_eq!(notice.author, "장학팀");
+        assert_eq!(notice.views, Some(1024));
+    }
+
+    #[test]
+    fn test_date_and_author_fallback_selectors() {
+        let dept = test_department(CmsSelectors {
+            date_selector: "td.date".to_string(),
+            date_fallbacks: vec!["td:nth-child(3)".to_string()],
+            author_fallbacks: vec!["td:nth-child(4)".to_string()],
+            ..CmsSelectors::default()
+        });
+        // The second cell holds a date too, but the fallback names the column
+        let html = r##"<table>
+            <tr><td><a href="/view?id=1">규정 개정</a></td><td>2023-12-01 개정</td>
+                <td>2024-02-01</td><td>작성자: 교무팀</td></tr>
+        </table>"##;
+        let notice = notices_of(&dept, html).remove(0);
+
+        assert_eq!(notice.date, "2024-02-01");
+        assert_eq!(notice.author, "교무팀");
+    }
+
+    #[test]
+    fn test_undated_row_infers_date_from_preceding_row() {
+        let dept = test_department(CmsSelectors::default());
+        let html = r##"<table>
+            <tr><td><a href="/view?id=1">First</a></td><td></td></tr>
+            <tr><td><a href="/view?id=2">Second</a></td><td>2024-01-15</td></tr>
+            <tr><td><a href="/view?id=3">Third</a></td><td> </td></tr>
+            <tr><td><a href="/view?id=4">Fourth</a></td><td>2024-01-10</td></tr>
+        </table>"##;
+
+        let mut notices: Vec<Notice> = notices_of(&dept, html);
+        NoticeCrawler::infer_missing_dates(&mut notices);
+
+        assert_eq!(notices[0].date, "");
+        assert!(!notices[0].date_inferred);
+        assert_eq!(notices[2].date, "2024-01-15");
+        assert!(notices[2].date_inferred);
+        assert!(!notices[1].date_inferred);
+        assert!(!notices[3].date_inferred);
+    }
+
+    #[test]
+    fn test_dedup_scopes() {
+        let dept = test_department(CmsSelectors::default());
+        let html = r##"<table>
+            <tr><td><a href="/view?id=1">Shared</a></td><td>2024-01-01</td></tr>
+            <tr><td><a href="/view?id=2">Only A</a></td><td>2024-01-02</td></tr>
+        </table>"##;
+        let rows: Vec<Notice> = notices_of(&dept, html);
+
+        // Two boards mapped to the same ID list the shared notice; board A
+        // also repeats it (e.g. pinned and in the regular list)
+        let dataset = || {
+            vec![
+                ("https://example.com/a", rows[0].clone()),
+                ("https://example.com/a", rows[1].clone()),
+                ("https://example.com/a", rows[0].clone()),
+                ("https://example.com/b", rows[0].clone()),
+            ]
+        };
+        let titles = |notices: Vec<Notice>| -> Vec<String> {
+            notices.into_iter().map(|n| n.title).collect()
+        };
+
+        assert_eq!(
+            titles(NoticeCrawler::dedup(
+                dataset(),
+                DedupScope::Global,
+                IdOptions::default()
+            )),
+            ["Shared", "Only A"]
+        );
+        assert_eq!(
+            titles(NoticeCrawler::dedup(
+                dataset(),
+                DedupScope::PerBoard,
+                IdOptions::default()
+            )),
+            ["Shared", "Only A", "Shared"]
+        );
+        assert_eq!(
+            NoticeCrawler::dedup(dataset(), DedupScope::None, IdOptions::default()).len(),
+            4
+        );
+    }
+
+    #[test]
+    fn test_dedup_cross_board() {
+        let dept = test_department(CmsSelectors::default());
+        let html = r##"<table>
+            <tr><td><a href="/view?id=7">[학사] 수강신청 안내</a></td><td>2024-01-05</td></tr>
+            <tr><td><a href="/view?id=8">Only here</a></td><td>2024-01-06</td></tr>
+        </table>"##;
+        let rows: Vec<Notice> = notices_of(&dept, html);
+
+        // The same notice on a second board: same source ID, pinned there
+        let mut academic = rows[0].clone();
+        academic.board_id = "academic".to_string();
+        academic.link = "https://example.com/academic/view?id=7".to_string();
+        academic.is_pinned = true;
+        // And on a third board without a source ID, title spaced differently
+        let mut news = rows[0].clone();
+        news.board_id = "news".to_string();
+        news.source_id = None;
+        news.title = "[학사]수강신청 안내".to_string();
+        // Same title in another department is a different notice
+        let mut other_dept = rows[0].clone();
+        other_dept.department_id = "dept2".to_string();
+
+        let deduped = NoticeCrawler::dedup_cross_board(vec![
+            rows[0].clone(),
+            rows[1].clone(),
+            academic,
+            news,
+            other_dept,
+        ]);
+        let kept: Vec<_> = deduped
+            .iter()
+            .map(|n| (n.department_id.as_str(), n.board_id.as_str()))
+            .collect();
+        assert_eq!(
+            kept,
+            [
+                ("dept1", "academic"),
+                ("dept1", "notice"),
+                ("dept2", "notice")
+            ]
+        );
+        assert_eq!(deduped[1].title, "Only here");
+    }
+
+    #[test]
+    fn test_dedup_cross_board_source_id_needs_title_or_date() {
+        let dept = test_department(CmsSelectors::default());
+        let html = r##"<table>
+            <tr><td><a href="/view?id=1">Exam schedule</a></td><td>2024-01-05</td></tr>
+        </table>"##;
+        let exam = notices_of(&dept, html).pop().unwrap();
+        let on_board = |board: &str, source_id: Option<&str>, title: &str, date: &str| Notice {
+            board_id: board.to_string(),
+            source_id: source_id.map(str::to_string),
+            title: title.to_string(),
+            date: date.to_string(),
+            ..exam.clone()
+        };
+
+        let deduped = NoticeCrawler::dedup_cross_board(vec![
+            exam.clone(),
+            // Per-board article sequence: same ID, unrelated notice
+            on_board("seminar", Some("1"), "Guest talk", "2024-03-02"),
+            on_board("news", None, "Exam schedule (updated)", "2024-01-05"),
+            // Same ID and date as the first, same title as the previous one:
+            // joins both groups
+            on_board(
+                "academic",
+                Some("1"),
+                "Exam schedule (updated)",
+                "2024-01-05",
+            ),
+        ]);
+        let boards: Vec<_> = deduped.iter().map(|n| n.board_id.as_str()).collect();
+        assert_eq!(boards, ["notice", "seminar"]);
+    }
+
+    #[test]
+    fn test_filter_by_age() {
+        let dept = test_department(CmsSelectors::default());
+        let html = r##"<table>
+            <tr><td><a href="/view?id=1">Recent</a></td><td>2024-03-01</td></tr>
+            <tr><td><a href="/view?id=2">Cutoff day</a></td><td>2024-02-01</td></tr>
+            <tr><td><a href="/view?id=3">Old</a></td><td>2024-01-31</td></tr>
+            <tr><td><a href="/view?id=4">Relative</a></td><td>3일 전</td></tr>
+            <tr><td><a href="/view?id=5">Undated</a></td><td>상시</td></tr>
+            <tr><td><a href="/view?id=6">Old pinned</a></td><td>2023-09-01</td></tr>
+        </table>"##;
+        let mut notices: Vec<Notice> = notices_of(&dept, html);
+        notices[5].is_pinned = true;
+        let now = DateTime::parse_from_rfc3339("2024-03-02T09:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+
+        let kept: Vec<String> = NoticeCrawler::filter_by_age(notices, 30, now)
+            .into_iter()
+            .map(|n| n.title)
+            .collect();
+        assert_eq!(kept, ["Recent", "Cutoff day", "Relative", "Undated"]);
+    }
+
+    #[test]
+    fn test_dedup_scope_config_values() {
+        let config: crate::models::CrawlerConfig =
+            toml::from_str(r#"dedup_scope = "per_board""#).unwrap();
+        assert_eq!(config.dedup_scope, DedupScope::PerBoard);
+        let config: crate::models::CrawlerConfig = toml::from_str("").unwrap();
+        assert_eq!(config.dedup_scope, DedupScope::Global);
+    }
+
+    #[test]
+    fn test_language_tag_detected_when_enabled() {
+        let dept = test_department(CmsSelectors::default());
+        let html = r##"<table>
+            <tr><td><a href="/view?id=1">수강신청 안내</a></td><td>2024-01-01</td></tr>
+            <tr><td><a href="/view?id=2">Exchange Program Info</a></td><td>2024-01-02</td></tr>
+            <tr><td><a href="/view?id=3">Global Week 국제교류 행사</a></td><td>2024-01-03</td></tr>
+        </table>"##;
+
+        let mut config = Config::default();
+        config.crawler.detect_language = true;
+        let crawler = NoticeCrawler::new(Arc::new(config), Client::new()).unwrap();
+        let notices: Vec<Notice> = parse_rows(&crawler, &dept, html)
+            .into_iter()
+            .map(Option::unwrap)
+            .collect();
+
+        let langs: Vec<_> = notices.iter().map(|n| n.lang.as_deref()).collect();
+        assert_eq!(langs, [Some("ko"), Some("en"), Some("mixed")]);
+
+        let output = serde_json::to_value(NoticeOutput::from(&notices[0])).unwrap();
+        assert_eq!(output["metadata"]["lang"], "ko");
+
+        // Disabled by default and omitted from the output
+        let untagged = notices_of(&dept, html);
+        assert!(untagged.iter().all(|n| n.lang.is_none()));
+        let output = serde_json::to_value(NoticeOutput::from(&untagged[0])).unwrap();
+        assert!(output["metadata"].get("lang").is_none());
+    }
+
+    #[test]
+    fn test_attachment_links_collected() {
+        let dept = test_department(CmsSelectors {
+            title_selector: "td.title a".to_string(),
+            attachment_selector: Some("a.file".to_string()),
+            attr_name: "data-href".to_string(),
+            ..CmsSelectors::default()
+        });
+        let html = r##"<table>
+            <tr><td class="title"><a data-href="/view?id=1">Files</a></td>
+                <td><a class="file" href="/download?f=guide.hwp">hwp</a><a class="file" href="https://cdn.example.com/form.pdf">pdf</a></td>
+                <td>2024-01-02</td></tr>
+            <tr><td class="title"><a data-href="/view?id=2">Plain</a></td><td></td><td>2024-01-01</td></tr>
+        </table>"##;
+
+        let notices: Vec<Notice> = notices_of(&dept, html);
+        assert_eq!(
+            notices[0].attachments,
+            [
+                "https://example.com/download?f=guide.hwp",
+                "https://cdn.example.com/form.pdf"
+            ]
+        );
+        assert!(notices[1].attachments.is_empty());
+        assert!(NoticeOutput::from(&notices[0]).metadata.has_attachment);
+        assert!(!NoticeOutput::from(&notices[1]).metadata.has_attachment);
+    }
+
+    #[test]
+    fn test_pinned_detected_from_number_column() {
+        let dept = test_department(CmsSelectors {
+            title_selector: "td.title a".to_string(),
+            ..CmsSelectors::default()
+        });
+        let html = r##"<table>
+            <tr><td>공지</td><td class="title"><a href="/view?id=1">Pinned</a></td><td>2024-01-03</td></tr>
+            <tr><td><img src="/i.gif" alt="중요"></td><td class="title"><a href="/view?id=2">Icon</a></td><td>2024-01-02</td></tr>
+            <tr><td>1,024</td><td class="title"><a href="/view?id=3">Regular</a></td><td>2024-01-01</td></tr>
+            <tr><td><img src="/img/ico_notice.gif"></td><td class="title"><a href="/view?id=4">File icon</a></td><td>2024-01-01</td></tr>
+            <tr><td></td><td class="title"><a href="/view?id=5">Empty</a></td><td>2024-01-01</td></tr>
+            <tr><td>2024.01.01</td><td class="title"><a href="/view?id=6">Dated</a></td><td>2024-01-01</td></tr>
+            <tr><td>학사</td><td class="title"><a href="/view?id=7">Category</a></td><td>2024-01-01</td></tr>
+        </table>"##;
+
+        let notices: Vec<Notice> = notices_of(&dept, html);
+        let pinned: Vec<bool> = notices.iter().map(|n| n.is_pinned).collect();
+        assert_eq!(pinned, [true, true, false, true, false, false, false]);
+        assert!(NoticeOutput::from(&notices[0]).metadata.pinned);
+    }
+
+    #[test]
+    fn test_pinned_detected_from_configured_class_or_selector() {
+        let html = r##"<table>
+            <tr class="Notice"><td><a href="/view?id=1">Class</a></td><td>2024-01-03</td></tr>
+            <tr><td><a href="/view?id=2">Badge</a><span class="fixed"></span></td><td>2024-01-02</td></tr>
+            <tr><td><a href="/view?id=3">Regular</a></td><td>2024-01-01</td></tr>
+        </table>"##;
+        let pinned_flags = |selectors: CmsSelectors| -> Vec<bool> {
+            parse_rows(&test_crawler(), &test_department(selectors), html)
+                .into_iter()
+                .map(|n| n.unwrap().is_pinned)
+                .collect()
+        };
+
+        let by_class = CmsSelectors {
+            pinned_class: Some("notice".to_string()),
+            ..CmsSelectors::default()
+        };
+        assert_eq!(pinned_flags(by_class), [true, false, false]);
+
+        let by_selector = CmsSelectors {
+            pinned_selector: Some(".fixed".to_string()),
+            ..CmsSelectors::default()
+        };
+        assert_eq!(pinned_flags(by_selector), [false, true, false]);
+    }
+
+    fn pinned_rows() -> Vec<Notice> {
+        let dept = test_department(CmsSelectors::default());
+        let html = r##"<table>
+            <tr><td><a href="/view?id=1">Old</a></td><td>2024/01/01</td></tr>
+            <tr><td><a href="/view?id=2">Newest</a></td><td>2024-03-01</td></tr>
+            <tr><td><a href="/view?id=3">Older</a></td><td>2023-12-01</td></tr>
+            <tr><td><a href="/view?id=4">Newer</a></td><td>2024.02.01</td></tr>
+            <tr><td><a href="/view?id=5">Oldest</a></td><td>2023-11-01</td></tr>
+            <tr><td><a href="/view?id=6">Regular</a></td><td>2024-03-02</td></tr>
+        </table>"##;
+
+        let mut notices: Vec<Notice> = notices_of(&dept, html);
+        for notice in &mut notices[..5] {
+            notice.is_pinned = true;
+        }
+        notices
+    }
+
+    #[test]
+    fn test_total_cap_is_shared_across_boards() {
+        let rows = pinned_rows();
+        let on_board = |board: &str, n: &Notice| Notice {
+            board_id: board.to_string(),
+            ..n.clone()
+        };
+        let mut buffer: Vec<_> = rows.iter().map(|n| on_board("a", n)).collect();
+        buffer.extend(rows.iter().map(|n| on_board("b", n)));
+        buffer.push(on_board("c", &rows[5]));
+
+        let capped = NoticeCrawler::cap_total(buffer.clone(), 7);
+        assert_eq!(capped.len(), 7);
+        let share = |board: &str| capped.iter().filter(|n| n.board_id == board).count();
+        assert_eq!(share("c"), 1);
+        assert_eq!(share("a"), 3);
+        assert_eq!(share("b"), 3);
+        // Pinned rows are taken before regular ones
+        assert!(
+            capped
+                .iter()
+                .filter(|n| n.board_id != "c")
+                .all(|n| n.is_pinned)
+        );
+
+        assert_eq!(NoticeCrawler::cap_total(buffer.clone(), 0).len(), 13);
+        assert_eq!(NoticeCrawler::cap_total(buffer, 100).len(), 13);
+    }
+
+    #[tokio::test]
+    async fn test_duplicates_do_not_use_up_total_cap() {
+        let server = TestServer::start(|_| list_page(&[1, 1, 2], None));
+        let campus = server_campus(&server);
+        let settings = crate::models::CrawlerConfig {
+            max_total_notices: 4,
+            ..local_settings()
+        };
+        let outcome = crawl(&campus, settings).await;
+
+        assert_eq!(outcome.notice_total, 6);
+        assert_eq!(outcome.notices.len(), 4);
+    }
+
+    #[test]
+    fn test_excess_pinned_notices_are_demoted() {
+        let mut notices = pinned_rows();
+        NoticeCrawler::limit_pinned(&mut notices, 2, false);
+
+        assert_eq!(notices.len(), 6);
+        let pinned: Vec<_> = notices
+            .iter()
+            .filter(|n| n.is_pinned)
+            .map(|n| n.title.as_str())
+            .collect();
+        assert_eq!(pinned, ["Newest", "Newer"]);
+    }
+
+    #[test]
+    fn test_excess_pinned_notices_are_dropped() {
+        let mut notices = pinned_rows();
+        NoticeCrawler::limit_pinned(&mut notices, 2, true);
+
+        let titles: Vec<_> = notices.iter().map(|n| n.title.as_str()).collect();
+        assert_eq!(titles, ["Newest", "Newer", "Regular"]);
+        assert!(!notices[2].is_pinned);
+    }
+
+    fn server_campus(server: &TestServer) -> Campus {
+        let board = |id: &str| Board {
+            id: id.to_string(),
+            name: id.to_string(),
+            url: server.url(&format!("/{}", id)),
+            selectors: CmsSelectors::default(),
+            content_root_selector: None,
+            link_base: None,
+            enabled: true,
+            fetch: FetchConfig::default(),
+            source: BoardSource::Html,
+        };
+        Campus {
+            campus: "TestCampus".to_string(),
+            colleges: vec![],
+            departments: vec![Department {
+                id: "dept1".to_string(),
+                name: "Department".to_string(),
+                url: server.url("/"),
+                boards: vec![board("notice"), board("scholarship")],
+            }],
+            source_url: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_disabled_board_is_skipped() {
+        let server = TestServer::start(|req| {
+            TestResponse::html(format!(
+                r#"<table><tr><td><a href="/view?id=1">{}</a></td><td>2024-01-15</td></tr></table>"#,
+                req.path
+            ))
+        });
+        let mut campus = server_campus(&server);
+        let crawler = fast_crawler();
+
+        campus.set_board_enabled(None, "scholarship", false);
+        let outcome = crawler
+            .fetch_all(std::slice::from_ref(&campus))
+            .await
+            .unwrap();
+        assert_eq!(outcome.board_total, 1);
+        assert_eq!(outcome.board_failures, 0);
+        assert_eq!(outcome.notices.len(), 1);
+        assert_eq!(outcome.notices[0].board_id, "notice");
+        assert!(
+            server
+                .requests()
+                .iter()
+                .all(|r| r.path == "/notice" || r.path == "/robots.txt")
+        );
+
+        campus.set_board_enabled(None, "scholarship", true);
+        let outcome = crawler
+            .fetch_all(std::slice::from_ref(&campus))
+            .await
+            .unwrap();
+        assert_eq!(outcome.board_total, 2);
+        assert_eq!(outcome.notices.len(), 2);
+        assert!(server.requests().iter().any(|r| r.path == "/scholarship"));
+    }
+
+    #[tokio::test]
+    async fn test_robots_disallowed_board_is_skipped() {
+        let server = TestServer::start(|req| match req.path.as_str() {
+            "/robots.txt" => TestResponse {
+                headers: vec![("Content-Type".into(), "text/plain".into())],
+                ..TestResponse::html("User-agent: *\nDisallow: /scholarship\n")
+            },
+            _ => TestResponse::html(
+                r#"<table><tr><td><a href="/view?id=1">Notice</a></td><td>2024-01-15</td></tr></table>"#,
+            ),
+        });
+        let campus = server_campus(&server);
+
+        let outcome = fast_crawler()
+            .fetch_all(std::slice::from_ref(&campus))
+            .await
+            .unwrap();
+        assert_eq!(outcome.board_total, 2);
+        assert_eq!(outcome.board_failures, 1);
+        assert_eq!(outcome.notices.len(), 1);
+        assert_eq!(outcome.errors[0].stage, CrawlStage::RobotsDisallowed);
+        assert_eq!(outcome.errors[0].board_id.as_deref(), Some("scholarship"));
+
+        let paths: Vec<_> = server.requests().into_iter().map(|r| r.path).collect();
+        assert_eq!(paths.iter().filter(|p| *p == "/robots.txt").count(), 1);
+        assert!(!paths.contains(&"/scholarship".to_string()));
+
+        let outcome = crawl(&campus, local_settings()).await;
+        assert_eq!(outcome.board_failures, 0);
+    }
+
+    fn paged_campus(server: &TestServer, selectors: CmsSelectors) -> Campus {
+        let mut campus = server_campus(server);
+        let dept = &mut campus.departments[0];
+        dept.boards.truncate(1);
+        dept.boards[0].url = server.url("/list");
+        dept.boards[0].selectors = selectors;
+        campus
+    }
+
+    fn list_page(ids: &[u32], next: Option<&str>) -> TestResponse {
+        let rows: String = ids
+            .iter()
+            .map(|id| {
+                format!(
+                    r#"<tr><td><a href="/view?id={id}">Notice {id}</a></td><td>2024-01-{id:02}</td></tr>"#
+                )
+            })
+            .collect();
+        let next = next.map_or_else(String::new, |href| {
+            format!(r#"<div class="paging"><a class="next" href="{href}">다음</a></div>"#)
+        });
+        TestResponse::html(format!("<table>{rows}</table>{next}"))
+    }
+
+    #[tokio::test]
+    async fn test_pagination_follows_next_links_up_to_max_pages() {
+        let server = TestServer::start(|req| match req.path.as_str() {
+            "/list" => list_page(&[1, 2], Some("/list?page=2")),
+            "/list?page=2" => list_page(&[3, 4], Some("/list?page=3")),
+            "/list?page=3" => list_page(&[5], Some("/list?page=4")),
+            _ => list_page(&[6], None),
+        });
+        let selectors = CmsSelectors {
+            row_selector: "table tr".to_string(),
+            pagination_selector: Some("a.next".to_string()),
+            max_pages: 3,
+            ..CmsSelectors::default()
+        };
+        let campus = paged_campus(&server, selectors);
+
+        let outcome = fast_crawler()
+            .fetch_all(std::slice::from_ref(&campus))
+            .await
+            .unwrap();
+
+        let mut titles: Vec<_> = outcome.notices.iter().map(|n| n.title.as_str()).collect();
+        titles.sort();
+        assert_eq!(
+            titles,
+            ["Notice 1", "Notice 2", "Notice 3", "Notice 4", "Notice 5"]
+        );
+        assert_eq!(outcome.notice_total, 5);
+        assert!(!server.requests().iter().any(|r| r.path == "/list?page=4"));
+    }
+
+    #[tokio::test]
+    async fn test_pagination_stops_at_repeated_notice() {
+        // The page parameter is ignored past the last page, so page 3 repeats page 2
+        let server = TestServer::start(|req| match req.path.as_str() {
+            "/list" => list_page(&[1, 2], None),
+            "/list?page=2" => list_page(&[3], None),
+            _ => list_page(&[3], None),
+        });
+        let selectors = CmsSelectors {
+            row_selector: "table tr".to_string(),
+            next_url_pattern: Some("?page={page}".to_string()),
+            max_pages: 10,
+            ..CmsSelectors::default()
+        };
+        let campus = paged_campus(&server, selectors);
+
+        let outcome = fast_crawler()
+            .fetch_all(std::slice::from_ref(&campus))
+            .await
+            .unwrap();
+
+        assert_eq!(outcome.notices.len(), 3);
+        assert_eq!(outcome.notice_total, 4);
+        let list_fetches = server
+            .requests()
+            .iter()
+            .filter(|r| r.path.starts_with("/list"))
+            .count();
+        assert_eq!(list_fetches, 3);
+    }
+
+    #[tokio::test]
+    async fn test_failed_later_page_keeps_earlier_rows() {
+        let server = TestServer::start(|req| match req.path.as_str() {
+            "/list" => list_page(&[1, 2], Some("/list?page=2")),
+            _ => TestResponse {
+                status: 500,
+                ..TestResponse::html("error")
+            },
+        });
+        let selectors = CmsSelectors {
+            row_selector: "table tr".to_string(),
+            pagination_selector: Some("a.next".to_string()),
+            max_pages: 3,
+            ..CmsSelectors::default()
+        };
+        let campus = paged_campus(&server, selectors);
+
+        let outcome = fast_crawler()
+            .fetch_all(std::slice::from_ref(&campus))
+            .await
+            .unwrap();
+
+        assert_eq!(outcome.board_failures, 0);
+        assert_eq!(outcome.notices.len(), 2);
+        assert_eq!(outcome.errors.len(), 1);
+        assert_eq!(outcome.errors[0].stage, CrawlStage::BoardPage);
+        assert_eq!(outcome.errors[0].url, Some(server.url("/list?page=2")));
+        assert_eq!(outcome.errors[0].http_status, Some(500));
+    }
+
+    #[tokio::test]
+    async fn test_robots_checked_for_every_page() {
+        let server = TestServer::start(|req| match req.path.as_str() {
+            "/robots.txt" => TestResponse {
+                headers: vec![("Content-Type".into(), "text/plain".into())],
+                ..TestResponse::html("User-agent: *\nDisallow: /list?page=\n")
+            },
+            "/list" => list_page(&[1, 2], Some("/list?page=2")),
+            _ => list_page(&[3], None),
+        });
+        let selectors = CmsSelectors {
+            row_selector: "table tr".to_string(),
+            pagination_selector: Some("a.next".to_string()),
+            max_pages: 3,
+            ..CmsSelectors::default()
+        };
+        let campus = paged_campus(&server, selectors);
+
+        let outcome = fast_crawler()
+            .fetch_all(std::slice::from_ref(&campus))
+            .await
+            .unwrap();
+
+        assert_eq!(outcome.notices.len(), 2);
+        assert_eq!(outcome.errors[0].stage, CrawlStage::BoardPage);
+        assert!(!server.requests().iter().any(|r| r.path == "/list?page=2"));
+    }
+
+    /// Crawler settings for a local test server: no request delay,
+    /// robots.txt ignored.
+    fn local_settings() -> crate::models::CrawlerConfig {
+        crate::models::CrawlerConfig {
+            request_delay_ms: 0,
+            respect_robots: false,
+            ..Default::default()
+        }
+    }
+
+    /// Crawl `campus` with `crawler` settings through `client`.
+    async fn crawl_with(
+        campus: &Campus,
+        crawler: crate::models::CrawlerConfig,
+        client: Client,
+    ) -> CrawlOutcome {
+        let config = Config {
+            crawler,
+            ..Config::default()
+        };
+        NoticeCrawler::new(Arc::new(config), client)
+            .unwrap()
+            .fetch_all(std::slice::from_ref(campus))
+            .await
+            .unwrap()
+    }
+
+    /// Crawl `campus` with `crawler` settings and a plain client.
+    async fn crawl(campus: &Campus, crawler: crate::models::CrawlerConfig) -> CrawlOutcome {
+        crawl_with(campus, crawler, Client::new()).await
+    }
+
+    fn fast_crawler() -> NoticeCrawler {
+        let config = Config {
+            crawler: crate::models::CrawlerConfig {
+                request_delay_ms: 0,
+                board_retry_base_ms: 0,
+                ..Default::default()
+            },
+            ..Config::default()
+        };
+        NoticeCrawler::new(Arc::new(config), Client::new()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_rows_parsed_only_within_content_root() {
+        let server = TestServer::start(|_| {
+            TestResponse::html(
+                r#"<html><body>
+                <table class="sidebar"><tr><td><a href="/view?id=99">Popular post</a></td><td>2024-01-01</td></tr></table>
+                <div id="board"><table>
+                    <tr><td><a href="/view?id=1">Board notice</a></td><td>2024-01-15</td></tr>
+                </table></div>
+                </body></html>"#,
+            )
+        });
+        let mut campus = server_campus(&server);
+        campus.set_board_enabled(None, "scholarship", false);
+        let crawler = fast_crawler();
+
+        let outcome = crawler
+            .fetch_all(std::slice::from_ref(&campus))
+            .await
+            .unwrap();
+        assert_eq!(outcome.notices.len(), 2);
+
+        campus.departments[0].boards[0].content_root_selector = Some("#board".to_string());
+        let outcome = crawler
+            .fetch_all(std::slice::from_ref(&campus))
+            .await
+            .unwrap();
+        assert_eq!(outcome.notice_total, 1);
+        assert_eq!(outcome.notices.len(), 1);
+        assert_eq!(outcome.notices[0].title, "Board notice");
+    }
+
+    #[tokio::test]
+    async fn test_content_root_is_per_department() {
+        let server = TestServer::start(|_| {
+            TestResponse::html(
+                r#"<html><body>
+                <div id="a"><table><tr><td><a href="/view?id=1">A notice</a></td><td>2024-01-15</td></tr></table></div>
+                <div id="b"><table><tr><td><a href="/view?id=2">B notice</a></td><td>2024-01-16</td></tr></table></div>
+                </body></html>"#,
+            )
+        });
+        let mut campus = server_campus(&server);
+        campus.set_board_enabled(None, "scholarship", false);
+        let mut other = campus.departments[0].clone();
+        other.id = "dept2".to_string();
+        other.boards[0].url = server.url("/other/notice");
+        campus.departments[0].boards[0].content_root_selector = Some("#a".to_string());
+        other.boards[0].content_root_selector = Some("#b".to_string());
+        campus.departments.push(other);
+
+        let outcome = fast_crawler()
+            .fetch_all(std::slice::from_ref(&campus))
+            .await
+            .unwrap();
+        let mut titles: Vec<_> = outcome.notices.iter().map(|n| n.title.as_str()).collect();
+        titles.sort();
+        assert_eq!(titles, ["A notice", "B notice"]);
+    }
+
+    #[tokio::test]
+    async fn test_error_code_for_timeout_and_404() {
+        let server = TestServer::start(|req| {
+            if req.path == "/slow" {
+                std::thread::sleep(Duration::from_millis(300));
+            }
+            TestResponse {
+                status: 404,
+                headers: Vec::new(),
+                body: Vec::new(),
+            }
+        });
+        let client = Client::builder()
+            .timeout(Duration::from_millis(50))
+            .build()
+            .unwrap();
+
+        let err = http::fetch_page_async(&client, &server.url("/slow"))
+            .await
+            .err()
+            .unwrap();
+        let crawl_error = NoticeCrawler::build_error(CrawlStage::BoardList, None, None, None, &err);
+        assert_eq!(crawl_error.code, CrawlErrorCode::Timeout);
+        assert!(crawl_error.retryable);
+
+        let err = http::fetch_page_async(&client, &server.url("/missing"))
+            .await
+            .err()
+            .unwrap();
+        let crawl_error = NoticeCrawler::build_error(CrawlStage::BoardList, None, None, None, &err);
+        assert_eq!(crawl_error.code, CrawlErrorCode::ClientError);
+        assert!(!crawl_error.retryable);
+        assert_eq!(crawl_error.http_status, Some(404));
+    }
+
+    #[tokio::test]
+    async fn test_board_failure_records_status_and_duration() {
+        let server = TestServer::start(|req| match req.path.as_str() {
+            "/notice" => TestResponse {
+                status: 503,
+                ..TestResponse::html("busy")
+            },
+            _ => TestResponse::html("<table></table>"),
+        });
+        let campus = server_campus(&server);
+
+        let outcome = fast_crawler()
+            .fetch_all(std::slice::from_ref(&campus))
+            .await
+            .unwrap();
+
+        assert_eq!(outcome.errors.len(), 1);
+        let error = &outcome.errors[0];
+        assert_eq!(error.http_status, Some(503));
+        assert!(error.duration_ms.is_some());
+
+        let json = serde_json::to_value(error).unwrap();
+        assert_eq!(json["http_status"], 503);
+        let selector_error = NoticeCrawler::build_error(
+            CrawlStage::Selector,
+            None,
+            None,
+            None,
+            &AppError::validation("x"),
+        );
+        let json = serde_json::to_value(&selector_error).unwrap();
+        assert!(json.get("http_status").is_none());
+        assert!(json.get("duration_ms").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_board_retried_after_transient_failures() {
+        let attempts = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&attempts);
+        let server = TestServer::start(move |req| match req.path.as_str() {
+            "/notice" if counter.fetch_add(1, Ordering::SeqCst) < 2 => TestResponse {
+                status: 503,
+                ..TestResponse::html("busy")
+            },
+            _ => TestResponse::html(
+                r#"<table><tr><td><a href="/view?id=1">Notice</a></td><td>2024-01-15</td></tr></table>"#,
+            ),
+        });
+        let campus = server_campus(&server);
+        let config = Config {
+            crawler: crate::models::CrawlerConfig {
+                request_delay_ms: 0,
+                board_retry_base_ms: 1,
+                ..Default::default()
+            },
+            ..Config::default()
+        };
+        let crawler = NoticeCrawler::new(Arc::new(config), Client::new()).unwrap();
+
+        let outcome = crawler
+            .fetch_all(std::slice::from_ref(&campus))
+            .await
+            .unwrap();
+
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+        assert_eq!(outcome.board_failures, 0);
+        assert!(outcome.errors.is_empty(), "{:?}", outcome.errors);
+    }
+
+    #[tokio::test]
+    async fn test_failed_page_retried_without_refetching_board() {
+        let attempts = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&attempts);
+        let server = TestServer::start(move |req| match req.path.as_str() {
+            "/list" => list_page(&[1, 2], Some("/list?page=2")),
+            "/list?page=2" if counter.fetch_add(1, Ordering::SeqCst) == 0 => TestResponse {
+                status: 429,
+                ..TestResponse::html("slow down").header("Retry-After", "1")
+            },
+            _ => list_page(&[3], None),
+        });
+        let selectors = CmsSelectors {
+            row_selector: "table tr".to_string(),
+            pagination_selector: Some("a.next".to_string()),
+            max_pages: 2,
+            ..CmsSelectors::default()
+        };
+        let campus = paged_campus(&server, selectors);
+
+        let started = Instant::now();
+        let outcome = fast_crawler()
+            .fetch_all(std::slice::from_ref(&campus))
+            .await
+            .unwrap();
+
+        assert!(started.elapsed() >= Duration::from_secs(1));
+        assert_eq!(outcome.notices.len(), 3);
+        assert!(outcome.errors.is_empty(), "{:?}", outcome.errors);
+        let list_fetches = server
+            .requests()
+            .iter()
+            .filter(|r| r.path == "/list")
+            .count();
+        assert_eq!(list_fetches, 1);
+        assert_eq!(attempts.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_board_fetch_headers_are_sent() {
+        let server = TestServer::start(|req| {
+            if !req.headers.contains_key("referer") {
+                return TestResponse::html("<p>direct access denied</p>");
+            }
+            TestResponse::html(
+                r#"<table><tr><td><a href="/view?id=1">Notice</a></td><td>2024-01-15</td></tr></table>"#,
+            )
+        });
+        let mut campus = server_campus(&server);
+        campus.set_board_enabled(None, "scholarship", false);
+        campus.departments[0].boards[0].fetch = FetchConfig {
+            headers: [
+                ("Referer".to_string(), server.url("/")),
+                ("User-Agent".to_string(), "BoardAgent/1.0".to_string()),
+            ]
+            .into(),
+            cookies: Some("JSESSIONID=abc".to_string()),
+        };
+        let client =
+            http::create_crawl_client(&local_settings(), std::slice::from_ref(&campus)).unwrap();
+
+        let outcome = crawl_with(&campus, local_settings(), client).await;
+        assert_eq!(outcome.notices.len(), 1);
+
+        let requests = server.requests();
+        assert_eq!(requests[0].headers["referer"], server.url("/"));
+        assert_eq!(requests[0].headers["user-agent"], "BoardAgent/1.0");
+        assert_eq!(requests[0].headers["cookie"], "JSESSIONID=abc");
+    }
+
+    #[tokio::test]
+    async fn test_session_cookie_set_by_board_is_sent_back() {
+        let server = TestServer::start(|req| match req.path.as_str() {
+            "/list" => {
+                list_page(&[1], Some("/list?page=2")).header("Set-Cookie", "SESSION=fresh; Path=/")
+            }
+            _ if req.headers.get("cookie").map(String::as_str) == Some("SESSION=fresh") => {
+                list_page(&[2], None)
+            }
+            _ => TestResponse {
+                status: 403,
+                ..TestResponse::html("")
+            },
+        });
+        let mut campus = paged_campus(
+            &server,
+            CmsSelectors {
+                row_selector: "table tr".to_string(),
+                pagination_selector: Some("a.next".to_string()),
+                max_pages: 2,
+                ..CmsSelectors::default()
+            },
+        );
+        campus.departments[0].boards[0].fetch.cookies = Some("SESSION=stale".to_string());
+        let client =
+            http::create_crawl_client(&local_settings(), std::slice::from_ref(&campus)).unwrap();
+
+        let outcome = crawl_with(&campus, local_settings(), client).await;
+        assert_eq!(outcome.notices.len(), 2);
+        assert_eq!(server.requests()[0].headers["cookie"], "SESSION=stale");
+    }
+
+    #[tokio::test]
+    async fn test_board_fetch_time_recorded() {
+        let server = TestServer::start(|_| {
+            std::thread::sleep(Duration::from_millis(50));
+            TestResponse::html(
+                r#"<table><tr><td><a href="/view?id=1">Notice</a></td><td>2024-01-15</td></tr></table>"#,
+            )
+        });
+        let campus = server_campus(&server);
+        let outcome = crawl(&campus, local_settings()).await;
+        assert_eq!(outcome.board_failures, 0);
+        assert!(outcome.total_fetch_ms >= 100, "{}", outcome.total_fetch_ms);
+        assert!(outcome.total_parse_ms < outcome.total_fetch_ms);
+    }
+
+    #[tokio::test]
+    async fn test_request_delay_reported_as_wait_time() {
+        let server = TestServer::start(|_| {
+            TestResponse::html(
+                r#"<table><tr><td><a href="/view?id=1">Notice</a></td><td>2024-01-15</td></tr></table>"#,
+            )
+        });
+        let campus = server_campus(&server);
+        let settings = crate::models::CrawlerConfig {
+            request_delay_ms: 150,
+            ..local_settings()
+        };
+
+        let outcome = crawl(&campus, settings).await;
+        // One delay per board, not counted as fetching
+        assert!(outcome.total_wait_ms >= 300, "{}", outcome.total_wait_ms);
+        assert!(outcome.total_fetch_ms < 150, "{}", outcome.total_fetch_ms);
+    }
+
+    #[tokio::test]
+    async fn test_rss_board_reads_feed_items() {
+        let (title, _, _) = encoding_rs::EUC_KR.encode("장학 공지");
+        let body = [
+            br#"<?xml version="1.0" encoding="EUC-KR"?><rss version="2.0"><channel>
+                <item><title>"#
+                .as_slice(),
+            &title,
+            br#"</title><link>/view?id=7</link>
+                <pubDate>Mon, 15 Jan 2024 09:00:00 +0900</pubDate></item>
+                </channel></rss>"#
+                .as_slice(),
+        ]
+        .concat();
+        let server = TestServer::start(move |req| {
+            if req.headers.get("x-board").map(String::as_str) != Some("notice") {
+                return TestResponse {
+                    status: 403,
+                    ..TestResponse::html("")
+                };
+            }
+            TestResponse {
+                status: 200,
+                headers: vec![("Content-Type".into(), "application/rss+xml".into())],
+                body: body.clone(),
+            }
+        });
+        let mut campus = server_campus(&server);
+        campus.set_board_enabled(None, "scholarship", false);
+        let board = &mut campus.departments[0].boards[0];
+        board.source = BoardSource::Rss;
+        board.selectors = CmsSelectors::rss();
+        board.fetch.headers = [("X-Board".to_string(), "notice".to_string())].into();
+        let outcome = crawl(&campus, local_settings()).await;
+        assert_eq!(outcome.notices.len(), 1);
+        let notice = &outcome.notices[0];
+        assert_eq!(notice.title, "장학 공지");
+        assert_eq!(notice.link, server.url("/view?id=7"));
+        assert_eq!(notice.date, "2024-01-15");
+    }
+
+    #[test]
+    fn test_invalid_board_header_is_config_error() {
+        let fetch = FetchConfig {
+            headers: [("Bad Header".to_string(), "x".to_string())].into(),
+            cookies: None,
+        };
+        let err = http::board_headers(&fetch).unwrap_err();
+        assert!(err.to_string().contains("Bad Header"));
+    }
+
+    #[tokio::test]
+    async fn test_not_modified_board_reuses_cached_notices() {
+        let server = TestServer::start(|req| {
+            if req.headers.get("if-none-match").map(String::as_str) == Some("\"v1\"") {
+                return TestResponse {
+                    status: 304,
+                    ..TestResponse::html("")
+                };
+            }
+            TestResponse::html(
+                r#"<table><tr><td><a href="/view?id=1">Notice</a></td><td>2024-01-15</td></tr></table>"#,
+            )
+            .header("ETag", "\"v1\"")
+        });
+        let mut campus = server_campus(&server);
+        campus.set_board_enabled(None, "scholarship", false);
+        let config = Arc::new(Config {
+            crawler: crate::models::CrawlerConfig {
+                conditional_requests: true,
+                ..local_settings()
+            },
+            ..Config::default()
+        });
+
+        let first = NoticeCrawler::new(Arc::clone(&config), Client::new())
+            .unwrap()
+            .fetch_all(std::slice::from_ref(&campus))
+            .await
+            .unwrap();
+        assert_eq!(first.notices.len(), 1);
+        let entry = &first.http_cache.entries[&server.url("/notice")];
+        assert_eq!(entry.validators.etag.as_deref(), Some("\"v1\""));
+
+        let second = NoticeCrawler::new(config, Client::new())
+            .unwrap()
+            .with_http_cache(first.http_cache.clone())
+            .fetch_all(std::slice::from_ref(&campus))
+            .await
+            .unwrap();
+        assert_eq!(second.notices[0].link, first.notices[0].link);
+        assert_eq!(second.notice_total, 1);
+        assert_eq!(second.bytes_downloaded, 0);
+        assert_eq!(second.http_cache.entries.len(), 1);
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 2);
+        assert!(!requests[0].headers.contains_key("if-none-match"));
+        assert_eq!(requests[1].headers["if-none-match"], "\"v1\"");
+    }
+
+    #[test]
+    fn test_error_code_for_selector_failure() {
+        let selectors = CmsSelectors {
+            row_selector: "[[invalid".to_string(),
+            ..CmsSelectors::default()
+        };
+        let campus = Campus {
+            campus: "TestCampus".to_string(),
+            colleges: vec![],
+            departments: vec![test_department(selectors)],
+            source_url: None,
+        };
+
+        let (_, errors, invalid) = NoticeCrawler::build_selector_cache(&[campus]);
+        let key = (
+            "TestCampus".to_string(),
+            "dept1".to_string(),
+            "notice".to_string(),
+        );
+        assert!(invalid.contains(&key));
+        assert_eq!(errors[0].code, CrawlErrorCode::Selector);
+        let json = serde_json::to_value(&errors[0]).unwrap();
+        assert_eq!(json["code"], "selector");
+    }
 
     #[test]
     fn test_parse_selector_valid() {
@@ -2,7 +2,11 @@
 //!
 //! Detects the CMS type used by a website and returns appropriate CSS selectors.
 
-use scraper::Html;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use scraper::{Html, Selector};
+use tokio::sync::OnceCell;
 
 use crate::models::{CmsPattern, CmsSelectors, Config};
 
@@ -18,45 +22,168 @@ impl SelectorDetector {
         Self { patterns }
     }
 
+    /// Name of the first pattern whose `detect_url_contains` matches `url`.
+    pub fn url_pattern(&self, url: &str) -> Option<&str> {
+        self.patterns
+            .iter()
+            .find(|p| {
+                p.detect_url_contains
+                    .as_ref()
+                    .is_some_and(|marker| url.contains(marker.as_str()))
+            })
+            .map(|p| p.name.as_str())
+    }
+
     /// Detect CMS type and return appropriate selectors.
-    /// Returns the selectors and the matched pattern name.
     pub fn detect(&self, document: &Html, url: &str) -> Option<CmsSelectors> {
+        self.detect_named(document, url)
+            .map(|detected| detected.selectors)
+    }
+
+    /// Detect CMS type, returning the matched pattern name with its selectors.
+    pub fn detect_named(&self, document: &Html, url: &str) -> Option<DetectedCms> {
+        let (pattern, rows) = self.best_match(document, url)?;
+        log::debug!(
+            "Detected CMS pattern: '{}' ({} rows) for URL: {}",
+            pattern.name,
+            rows,
+            url
+        );
+        Some(DetectedCms {
+            pattern_name: pattern.name.clone(),
+            selectors: Self::selectors_for(pattern),
+        })
+    }
+
+    /// Detect CMS type, also returning how many rows its `row_selector`
+    /// matches in `document`.
+    pub fn detect_scored(&self, document: &Html, url: &str) -> Option<(CmsSelectors, usize)> {
+        self.best_match(document, url)
+            .map(|(pattern, rows)| (Self::selectors_for(pattern), rows))
+    }
+
+    /// The matching pattern whose `row_selector` finds the most rows.
+    ///
+    /// Ties go to the longer `detect_html_contains` marker (a more specific
+    /// pattern), then to config order. When no candidate matches any row
+    /// (e.g. on a homepage rather than a board page), the first matching
+    /// pattern is returned with a score of 0.
+    fn best_match(&self, document: &Html, url: &str) -> Option<(&CmsPattern, usize)> {
         let html_lower = document.html().to_lowercase();
+        let specificity = |p: &CmsPattern| {
+            p.detect_html_contains
+                .as_ref()
+                .map_or(0, |marker| marker.len())
+        };
 
-        self.patterns.iter().find_map(|pattern| {
-            if self.matches_pattern(pattern, url, &html_lower) {
-                log::debug!("Detected CMS pattern: '{}' for URL: {}", pattern.name, url);
-                Some(CmsSelectors::from_pattern(
-                    &pattern.row_selector,
-                    &pattern.title_selector,
-                    &pattern.date_selector,
-                    &pattern.link_attr,
-                ))
-            } else {
-                None
+        let mut best: Option<(&CmsPattern, usize)> = None;
+        for pattern in self
+            .patterns
+            .iter()
+            .filter(|p| self.matches_pattern(p, url, &html_lower))
+        {
+            let rows = Self::row_count(pattern, document);
+            let better = match best {
+                None => true,
+                Some((current, current_rows)) => {
+                    rows > 0 && (rows, specificity(pattern)) > (current_rows, specificity(current))
+                }
+            };
+            if better {
+                best = Some((pattern, rows));
             }
-        })
+        }
+        best
+    }
+
+    fn row_count(pattern: &CmsPattern, document: &Html) -> usize {
+        Selector::parse(&pattern.row_selector)
+            .map(|row| document.select(&row).count())
+            .unwrap_or(0)
+    }
+
+    fn selectors_for(pattern: &CmsPattern) -> CmsSelectors {
+        CmsSelectors::from_pattern(
+            &pattern.row_selector,
+            &pattern.title_selector,
+            &pattern.date_selector,
+            &pattern.link_attr,
+        )
     }
 
     fn matches_pattern(&self, pattern: &CmsPattern, url: &str, html_lower: &str) -> bool {
         // Check URL pattern
-        if let Some(url_pattern) = &pattern.detect_url_contains {
-            if url.contains(url_pattern) {
-                return true;
-            }
+        if let Some(url_pattern) = &pattern.detect_url_contains
+            && url.contains(url_pattern)
+        {
+            return true;
         }
 
         // Check HTML pattern
-        if let Some(html_pattern) = &pattern.detect_html_contains {
-            if html_lower.contains(&html_pattern.to_lowercase()) {
-                return true;
-            }
+        if let Some(html_pattern) = &pattern.detect_html_contains
+            && html_lower.contains(&html_pattern.to_lowercase())
+        {
+            return true;
         }
 
         false
     }
 }
 
+/// A CMS pattern matched on a page.
+#[derive(Debug, Clone)]
+pub struct DetectedCms {
+    pub pattern_name: String,
+    pub selectors: CmsSelectors,
+}
+
+/// Detected CMS per domain and URL-identified pattern, shared by all
+/// departments one discovery service scans.
+///
+/// Boards whose URLs match different patterns' `detect_url_contains` are
+/// detected separately even on one host. Concurrent misses on a key wait for
+/// a single detection.
+#[derive(Debug, Default)]
+pub struct SelectorCache {
+    entries: Mutex<HashMap<SelectorCacheKey, Arc<OnceCell<DetectedCms>>>>,
+}
+
+/// A board's domain and the pattern its URL identifies, if any.
+type SelectorCacheKey = (String, Option<String>);
+
+impl SelectorCache {
+    /// Create an empty cache.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// CMS cached for `domain` and `pattern`, running `detect` on a miss.
+    ///
+    /// A failed detection (`None`) is not cached, so the next board retries.
+    pub async fn get_or_detect<F, Fut>(
+        &self,
+        domain: &str,
+        pattern: Option<&str>,
+        detect: F,
+    ) -> Option<DetectedCms>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Option<DetectedCms>>,
+    {
+        let cell = Arc::clone(
+            self.entries
+                .lock()
+                .unwrap()
+                .entry((domain.to_string(), pattern.map(str::to_string)))
+                .or_default(),
+        );
+        cell.get_or_try_init(|| async { detect().await.ok_or(()) })
+            .await
+            .ok()
+            .cloned()
+    }
+}
+
 /// Default implementation using built-in CMS patterns.
 impl Default for SelectorDetector {
     fn default() -> Self {
@@ -67,6 +194,8 @@ impl Default for SelectorDetector {
 /// Unit tests for SelectorDetector
 #[cfg(test)]
 mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
     use super::*;
 
     #[test]
@@ -74,4 +203,117 @@ mod tests {
         let detector = SelectorDetector::default();
         assert!(!detector.patterns.is_empty());
     }
+
+    fn pattern(name: &str, marker: &str, row: &str) -> CmsPattern {
+        CmsPattern {
+            name: name.to_string(),
+            detect_url_contains: None,
+            detect_html_contains: Some(marker.to_string()),
+            row_selector: row.to_string(),
+            title_selector: "a".to_string(),
+            date_selector: "td:last-child".to_string(),
+            link_attr: "href".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_detect_prefers_pattern_matching_most_rows() {
+        let detector = SelectorDetector::new(vec![
+            pattern("generic", "board", "div.board-item"),
+            pattern("specific", "board-list", "table.board-list tr"),
+        ]);
+        let document = Html::parse_document(
+            r#"<table class="board-list">
+                <tr><td><a href="/1">One</a></td><td>2024-01-01</td></tr>
+                <tr><td><a href="/2">Two</a></td><td>2024-01-02</td></tr>
+            </table>"#,
+        );
+
+        let (selectors, rows) = detector
+            .detect_scored(&document, "https://example.com")
+            .unwrap();
+        assert_eq!(selectors.row_selector, "table.board-list tr");
+        assert_eq!(rows, 2);
+        assert_eq!(
+            detector
+                .detect_named(&document, "https://example.com")
+                .unwrap()
+                .pattern_name,
+            "specific"
+        );
+    }
+
+    #[test]
+    fn test_detect_ties_go_to_specific_marker() {
+        let detector = SelectorDetector::new(vec![
+            pattern("generic", "board", "tr"),
+            pattern("specific", "board-list", "tr"),
+        ]);
+        let document =
+            Html::parse_document(r#"<table class="board-list"><tr><td>1</td></tr></table>"#);
+        let detected = detector
+            .detect_named(&document, "https://example.com")
+            .unwrap();
+        assert_eq!(detected.pattern_name, "specific");
+
+        // No rows anywhere: the first matching pattern, as before scoring
+        let homepage = Html::parse_document(r#"<div class="board-list">menu</div>"#);
+        let (selectors, rows) = detector
+            .detect_scored(&homepage, "https://example.com")
+            .unwrap();
+        assert_eq!((selectors.row_selector.as_str(), rows), ("tr", 0));
+        assert_eq!(
+            detector
+                .detect_named(&homepage, "https://example.com")
+                .unwrap()
+                .pattern_name,
+            "generic"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_selector_cache_keys_on_domain_and_pattern() {
+        let cache = SelectorCache::new();
+        let detected = |name: &str| DetectedCms {
+            pattern_name: name.to_string(),
+            selectors: CmsSelectors::fallback(),
+        };
+        let runs = AtomicUsize::new(0);
+        let detect = |name: &'static str| {
+            let runs = &runs;
+            move || async move {
+                runs.fetch_add(1, Ordering::SeqCst);
+                Some(detected(name))
+            }
+        };
+
+        let (first, second) = tokio::join!(
+            cache.get_or_detect("a.com", None, detect("xe")),
+            cache.get_or_detect("a.com", None, detect("xe")),
+        );
+        assert_eq!(runs.load(Ordering::SeqCst), 1);
+        assert_eq!(first.unwrap().pattern_name, "xe");
+        assert_eq!(second.unwrap().pattern_name, "xe");
+
+        // Another URL-identified pattern on the same host is detected anew
+        let standard = cache
+            .get_or_detect("a.com", Some("yonsei_standard"), detect("yonsei_standard"))
+            .await;
+        assert_eq!(standard.unwrap().pattern_name, "yonsei_standard");
+        assert_eq!(runs.load(Ordering::SeqCst), 2);
+
+        // Failures are retried
+        assert!(
+            cache
+                .get_or_detect("b.com", None, || async { None })
+                .await
+                .is_none()
+        );
+        assert!(
+            cache
+                .get_or_detect("b.com", None, detect("xe"))
+                .await
+                .is_some()
+        );
+    }
 }
@@ -8,38 +8,92 @@
 //! ```text
 //! {root}/
 //! ├── config.toml           # Crawler Configuration
-//! ├── index.json            # Inverted Index for Search
+//! ├── index.json            # Inverted Index for Search (unsharded)
+//! ├── index/                # Sharded index for lazy loading ([index] shards)
+//! │   ├── manifest.json     # Shard count and index totals
+//! │   ├── <shard>.json      # Tokens hashed to this shard
+//! │   └── search-<campus>-<hash>.json # Index of one campus's notices
 //! ├── current.json          # Hot: Active Window (Write-Buffer)
+//! ├── errors.json           # Crawl error report (sorted for stable diffs)
+//! ├── health.json           # Board success rate per campus
+//! ├── notify_diff.json      # Latest diff with payloads for the notifier
+//! ├── diff.json             # Latest diff, IDs only
 //! ├── siteMap.json          # Site Map for Crawling
+//! ├── breaker_state.json    # Circuit Breaker state across runs
+//! ├── http-cache.json       # Conditional-request validators per board list
+//! ├── snapshots/            # Notices written by each recent run, for diffs
+//! │   └── YYYYMMDDTHHMMSSZ.json
 //! └── stacks/               # Cold: Immutable Archives
 //!     └── YYYY/
 //!         └── MM.json
 //! ```
 //!
+//! Campus-scoped storage (see [`LocalStorage::with_campus`]) uses the same
+//! layout under `{root}/{campus}-{hash}/`, with its own `current.json` pointer.
+//!
 //! ## Features
 //!
 //! - **Circuit Breaker**: Aborts write if notice count drops >20%
 //! - **Inverted Index**: Generates `index.json` for client-side search
 //! - **Diff Calculation**: Returns changes for notification dispatch
 
-use std::collections::HashMap;
-use std::path::PathBuf;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::{Path, PathBuf};
 
 use async_trait::async_trait;
-use chrono::{Datelike, Utc};
+use chrono::{DateTime, Datelike, Utc};
+use futures::stream::{self, StreamExt};
 use serde::{Serialize, de::DeserializeOwned};
+use sha2::{Digest, Sha256};
 use tokio::io::AsyncWriteExt;
 
 use crate::error::{AppError, Result};
-use crate::models::{Campus, CrawlOutcome, CrawlStats, NoticeOutput};
-use crate::pipeline::{CircuitBreaker, InvertedIndex, build_index, calculate_diff};
-use crate::storage::{CurrentData, NoticeStorage, WriteMetadata, WriteOptions};
+use crate::models::{
+    Campus, CrawlOutcome, CrawlOutcomeReport, CrawlStats, HealthReport, HttpCache, IdOptions,
+    NoticeOutput,
+};
+use crate::pipeline::{
+    CircuitBreaker, CircuitBreakerResult, CircuitBreakerState, DiffResult, IndexBuilder,
+    IndexConfig, IndexManifest, InvertedIndex, build_index_with_config, calculate_diff,
+};
+use crate::storage::{
+    ByteReader, CurrentData, NoticeStorage, PruneResult, SnapshotInfo, WriteMetadata, WriteOptions,
+};
+
+/// Notices per campus, with every name in `campuses` present (at zero if
+/// it has none).
+fn campus_counts<'a>(
+    notices: &[NoticeOutput],
+    campuses: impl IntoIterator<Item = &'a str>,
+) -> BTreeMap<String, usize> {
+    let mut counts: BTreeMap<String, usize> = campuses
+        .into_iter()
+        .map(|campus| (campus.to_string(), 0))
+        .collect();
+    for notice in notices {
+        *counts.entry(notice.metadata.campus.clone()).or_default() += 1;
+    }
+    counts
+}
 
 /// Local filesystem storage backend.
 #[derive(Clone)]
 pub struct LocalStorage {
     root_dir: PathBuf,
     circuit_breaker: CircuitBreaker,
+    write_concurrency: usize,
+    snapshot_retention: usize,
+    index_config: IndexConfig,
+    link_id_params: Option<Vec<String>>,
+    id_includes_college: bool,
+}
+
+/// [`CurrentData`] over borrowed notices, serialized identically.
+#[derive(Serialize)]
+struct CurrentDataRef<'a> {
+    updated_at: DateTime<Utc>,
+    count: usize,
+    notices: &'a [&'a NoticeOutput],
 }
 
 impl LocalStorage {
@@ -48,6 +102,11 @@ impl LocalStorage {
         Self {
             root_dir: root_dir.into(),
             circuit_breaker: CircuitBreaker::new(),
+            write_concurrency: Self::DEFAULT_WRITE_CONCURRENCY,
+            snapshot_retention: Self::DEFAULT_SNAPSHOT_RETENTION,
+            index_config: IndexConfig::default(),
+            link_id_params: None,
+            id_includes_college: false,
         }
     }
 
@@ -59,16 +118,164 @@ impl LocalStorage {
         Self {
             root_dir: root_dir.into(),
             circuit_breaker,
+            write_concurrency: Self::DEFAULT_WRITE_CONCURRENCY,
+            snapshot_retention: Self::DEFAULT_SNAPSHOT_RETENTION,
+            index_config: IndexConfig::default(),
+            link_id_params: None,
+            id_includes_college: false,
         }
     }
 
+    /// Scope this storage to a single campus.
+    ///
+    /// All keys resolve under `{root}/{campus}-{hash}/`, so each campus keeps an
+    /// independent `current.json`, archive and index and can be published on
+    /// its own schedule.
+    pub fn with_campus(&self, campus: &str) -> Self {
+        Self {
+            root_dir: self.root_dir.join(Self::campus_segment(campus)),
+            circuit_breaker: self.circuit_breaker.clone(),
+            write_concurrency: self.write_concurrency,
+            snapshot_retention: self.snapshot_retention,
+            index_config: self.index_config.clone(),
+            link_id_params: self.link_id_params.clone(),
+            id_includes_college: self.id_includes_college,
+        }
+    }
+
+    /// Keep the snapshots of the last `runs` writes for diffs; 0 records none.
+    /// They count towards [`prune_to_size`](NoticeStorage::prune_to_size)
+    /// usage but are bounded by this retention, not pruned.
+    pub fn with_snapshot_retention(mut self, runs: usize) -> Self {
+        self.snapshot_retention = runs;
+        self
+    }
+
+    /// Directory this storage reads and writes, campus-scoped or not.
+    pub fn root_dir(&self) -> &Path {
+        &self.root_dir
+    }
+
+    /// Set how many archive files are written concurrently.
+    pub fn with_write_concurrency(mut self, concurrency: usize) -> Self {
+        self.write_concurrency = concurrency.max(1);
+        self
+    }
+
+    /// Set how the index written with each snapshot is built; with
+    /// `enabled = false` no index is written and any earlier one is removed.
+    pub fn with_index_config(mut self, config: IndexConfig) -> Self {
+        self.index_config = config;
+        self
+    }
+
+    /// Build the notice IDs of snapshots as `options` say (see
+    /// `CrawlerConfig::id_options`).
+    pub fn with_id_options(mut self, options: IdOptions<'_>) -> Self {
+        self.link_id_params = options.link_params.map(<[String]>::to_vec);
+        self.id_includes_college = options.include_college;
+        self
+    }
+
+    /// A campus name made safe to use as a single path segment: the name
+    /// with anything but letters, digits and `-` replaced by `_`, then a
+    /// short hash of the exact name so names that sanitize alike (`A/B`,
+    /// `A_B`) still get their own segment.
+    fn campus_segment(campus: &str) -> String {
+        let name: String = campus
+            .trim()
+            .chars()
+            .map(|c| {
+                if c.is_alphanumeric() || c == '-' {
+                    c
+                } else {
+                    '_'
+                }
+            })
+            .collect();
+        let digest = Sha256::digest(campus.as_bytes());
+        format!("{}-{}", name, &hex::encode(digest)[..8])
+    }
+
+    /// File stem of the search index for one campus. The `search-` prefix
+    /// keeps it apart from the hex-named shards alongside it.
+    fn campus_index_stem(campus: &str) -> String {
+        format!("search-{}", Self::campus_segment(campus))
+    }
+
+    fn campus_index_key(campus: &str) -> String {
+        format!("index/{}.json", Self::campus_index_stem(campus))
+    }
+
+    /// Delete the file at `key` if it exists.
+    async fn remove_key(&self, key: &str) -> Result<()> {
+        match tokio::fs::remove_file(self.path(key)).await {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(e.into()),
+            _ => Ok(()),
+        }
+    }
+
+    /// Delete `index.json` and everything under `index/` (manifest, shards
+    /// and campus indices).
+    async fn remove_index(&self) -> Result<()> {
+        self.remove_key("index.json").await?;
+        match tokio::fs::remove_dir_all(self.path("index")).await {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(e.into()),
+            _ => Ok(()),
+        }
+    }
+
+    /// Delete shard files under `index/` whose name is not in `keep`.
+    async fn remove_stale_shards(&self, keep: &HashSet<String>) -> Result<()> {
+        self.remove_index_files(|stem| IndexManifest::is_shard_name(stem) && !keep.contains(stem))
+            .await
+    }
+
+    /// Delete campus search indices under `index/` whose file stem is not in
+    /// `keep`, such as those of campuses no longer in the snapshot.
+    async fn remove_stale_campus_indices(&self, keep: &HashSet<String>) -> Result<()> {
+        self.remove_index_files(|stem| stem.starts_with("search-") && !keep.contains(stem))
+            .await
+    }
+
+    /// Delete the `.json` files under `index/` whose stem matches `stale`.
+    async fn remove_index_files(&self, stale: impl Fn(&str) -> bool) -> Result<()> {
+        let mut entries = match tokio::fs::read_dir(self.path("index")).await {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(()),
+            Err(e) => return Err(e.into()),
+        };
+        while let Some(entry) = entries.next_entry().await? {
+            let file_name = entry.file_name();
+            let Some(stem) = file_name.to_str().and_then(|n| n.strip_suffix(".json")) else {
+                continue;
+            };
+            if stale(stem) {
+                tokio::fs::remove_file(entry.path()).await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Default number of archive files written concurrently.
+    const DEFAULT_WRITE_CONCURRENCY: usize = 8;
+
+    /// Default number of runs whose snapshots are kept.
+    const DEFAULT_SNAPSHOT_RETENTION: usize = 30;
+
+    /// Key for the persisted circuit breaker state.
+    const BREAKER_STATE_KEY: &'static str = "breaker_state.json";
+
+    /// Key for the conditional-request cache.
+    const HTTP_CACHE_KEY: &'static str = "http-cache.json";
+
     /// Get the full path for a relative key.
     fn path(&self, key: &str) -> PathBuf {
         self.root_dir.join(key)
     }
 
     /// Ensure parent directory exists.
-    async fn ensure_dir(&self, path: &PathBuf) -> Result<()> {
+    async fn ensure_dir(&self, path: &Path) -> Result<()> {
         if let Some(parent) = path.parent() {
             tokio::fs::create_dir_all(parent).await?;
         }
@@ -96,16 +303,6 @@ impl LocalStorage {
         self.write_bytes(key, &bytes).await
     }
 
-    /// Read bytes, returning None if file doesn't exist.
-    async fn read_bytes(&self, key: &str) -> Result<Option<Vec<u8>>> {
-        let path = self.path(key);
-        match tokio::fs::read(&path).await {
-            Ok(bytes) => Ok(Some(bytes)),
-            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
-            Err(e) => Err(AppError::Io(e)),
-        }
-    }
-
     /// Read JSON data.
     async fn read_json<T: DeserializeOwned>(&self, key: &str) -> Result<Option<T>> {
         match self.read_bytes(key).await? {
@@ -119,6 +316,108 @@ impl LocalStorage {
         format!("stacks/{}/{:02}.json", year, month)
     }
 
+    /// Key of the snapshot of the run written as `version`.
+    fn snapshot_key(version: &str) -> String {
+        format!("snapshots/{}.json", version)
+    }
+
+    /// Versions of the run snapshots under `snapshots/`, oldest first.
+    async fn list_snapshot_versions(&self) -> Result<Vec<String>> {
+        let mut versions = Vec::new();
+        let mut dir = match tokio::fs::read_dir(self.path("snapshots")).await {
+            Ok(dir) => dir,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(versions),
+            Err(e) => return Err(AppError::Io(e)),
+        };
+        while let Some(entry) = dir.next_entry().await? {
+            if let Some(version) = entry
+                .file_name()
+                .to_str()
+                .and_then(|name| name.strip_suffix(".json"))
+            {
+                versions.push(version.to_string());
+            }
+        }
+        versions.sort_unstable();
+        Ok(versions)
+    }
+
+    /// Record the notices a run wrote as its snapshot, then drop the oldest
+    /// snapshots past the retention.
+    async fn write_run_snapshot(&self, at: DateTime<Utc>, notices: &[NoticeOutput]) -> Result<()> {
+        if self.snapshot_retention == 0 {
+            return Ok(());
+        }
+        let version = at.format(SnapshotInfo::VERSION_FORMAT).to_string();
+        let notices: Vec<&NoticeOutput> = notices.iter().collect();
+        let data = CurrentDataRef {
+            updated_at: at,
+            count: notices.len(),
+            notices: &notices,
+        };
+        self.write_json(&Self::snapshot_key(&version), &data)
+            .await?;
+
+        let versions = self.list_snapshot_versions().await?;
+        let excess = versions.len().saturating_sub(self.snapshot_retention);
+        for version in &versions[..excess] {
+            self.remove_key(&Self::snapshot_key(version)).await?;
+        }
+        Ok(())
+    }
+
+    /// List archive periods present under `stacks/`, oldest first.
+    async fn list_archives(&self) -> Result<Vec<(i32, u32)>> {
+        let mut periods = Vec::new();
+        let mut years = match tokio::fs::read_dir(self.path("stacks")).await {
+            Ok(dir) => dir,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(periods),
+            Err(e) => return Err(AppError::Io(e)),
+        };
+
+        while let Some(year_entry) = years.next_entry().await? {
+            let Some(year) = year_entry.file_name().to_str().and_then(|y| y.parse().ok()) else {
+                continue;
+            };
+            let mut months = tokio::fs::read_dir(year_entry.path()).await?;
+            while let Some(month_entry) = months.next_entry().await? {
+                let name = month_entry.file_name();
+                if let Some(month) = name
+                    .to_str()
+                    .and_then(|n| n.strip_suffix(".json"))
+                    .and_then(|m| m.parse().ok())
+                {
+                    periods.push((year, month));
+                }
+            }
+        }
+
+        periods.sort_unstable();
+        Ok(periods)
+    }
+
+    /// Total size in bytes of all files under the storage root.
+    async fn usage_bytes(&self) -> Result<u64> {
+        let mut total = 0;
+        let mut pending = vec![self.root_dir.clone()];
+        while let Some(dir) = pending.pop() {
+            let mut entries = match tokio::fs::read_dir(&dir).await {
+                Ok(entries) => entries,
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => continue,
+                Err(e) => return Err(AppError::Io(e)),
+            };
+            while let Some(entry) = entries.next_entry().await? {
+                let metadata = entry.metadata().await?;
+                if metadata.is_dir() {
+                    pending.push(entry.path());
+                } else {
+                    total += metadata.len();
+                }
+            }
+        }
+        Ok(total)
+    }
+
     /// Write hot/cold data and generate index.
     async fn write_hot_cold_data(
         &self,
@@ -126,7 +425,7 @@ impl LocalStorage {
         stats: &CrawlStats,
         all_notices: &[NoticeOutput],
         options: &WriteOptions,
-    ) -> Result<(usize, usize)> {
+    ) -> Result<(usize, Vec<String>)> {
         let now = Utc::now();
         let current_year = now.year();
         let current_month = now.month();
@@ -136,74 +435,129 @@ impl LocalStorage {
             outcome.notices.len()
         );
 
-        // Partition notices by month
-        let mut by_month: HashMap<(i32, u32), Vec<NoticeOutput>> = HashMap::new();
-        for notice in &outcome.notices {
-            let (year, month) = notice.archive_period();
+        // Partition the already-converted notices by month, borrowing them
+        let mut by_month: HashMap<(i32, u32), Vec<&NoticeOutput>> = HashMap::new();
+        for notice in all_notices {
             by_month
-                .entry((year, month))
+                .entry(notice.archive_period())
                 .or_default()
-                .push(NoticeOutput::from(notice));
+                .push(notice);
         }
 
         // Separate hot (current month) and cold (archived) notices
-        let hot_notices: Vec<NoticeOutput> = by_month
+        let hot_notices = by_month
             .remove(&(current_year, current_month))
             .unwrap_or_default();
 
         // Write hot data: current.json
-        let current_data = CurrentData::new(hot_notices.clone());
+        let hot_count = hot_notices.len();
+        let current_data = CurrentDataRef {
+            updated_at: now,
+            count: hot_count,
+            notices: &hot_notices,
+        };
         self.write_json("current.json", &current_data).await?;
-        log::info!(
-            "Hot data: {} notices written to current.json",
-            current_data.count
-        );
-
-        // Write cold data: stacks/YYYY/MM.json
-        let mut cold_files_updated = 0;
-        for ((year, month), notices) in by_month {
-            let key = Self::archive_key(year, month);
-
-            // Merge with existing archive if present
-            let mut existing: Vec<NoticeOutput> = self.read_json(&key).await?.unwrap_or_default();
+        log::info!("Hot data: {} notices written to current.json", hot_count);
 
-            // Deduplicate by ID
-            let existing_ids: std::collections::HashSet<_> =
-                existing.iter().map(|n| n.id.clone()).collect();
-
-            for notice in notices {
-                if !existing_ids.contains(&notice.id) {
-                    existing.push(notice);
-                }
-            }
-
-            // Sort by date descending
-            existing.sort_by(|a, b| b.metadata.date.cmp(&a.metadata.date));
-
-            self.write_json(&key, &existing).await?;
-            log::info!("Cold data: {} notices written to {}", existing.len(), key);
-            cold_files_updated += 1;
-        }
+        // Write cold data: stacks/YYYY/MM.json (one file per period, in parallel)
+        // Futures are built up front: a stream closure over borrowed notices
+        // trips the `Send` check of the async-trait future
+        let merges: Vec<_> = by_month
+            .into_iter()
+            .map(|((year, month), notices)| self.merge_archive(year, month, notices))
+            .collect();
+        let mut archive_keys: Vec<String> = stream::iter(merges)
+            .buffer_unordered(self.write_concurrency)
+            .collect::<Vec<Result<String>>>()
+            .await
+            .into_iter()
+            .collect::<Result<_>>()?;
+        archive_keys.sort();
 
         // Generate and write inverted index
-        if options.generate_index {
+        if options.generate_index && self.index_config.enabled {
             log::info!(
                 "Generating inverted index for {} notices",
                 all_notices.len()
             );
-            let index = build_index(all_notices);
+            let index = build_index_with_config(all_notices, &self.index_config);
             self.save_index(&index).await?;
             log::info!(
                 "Inverted index: {} tokens indexing {} notices",
                 index.token_count,
                 index.notice_count
             );
+
+            // Per-campus indices, so a campus-scoped search need not fetch the global one
+            let mut by_campus: BTreeMap<&str, IndexBuilder> = BTreeMap::new();
+            for notice in all_notices {
+                by_campus
+                    .entry(notice.metadata.campus.as_str())
+                    .or_insert_with(|| IndexBuilder::with_config(self.index_config.clone()))
+                    .add_notice(notice);
+            }
+            let mut written = HashSet::new();
+            for (campus, builder) in by_campus {
+                self.save_campus_index(campus, &builder.build()).await?;
+                written.insert(Self::campus_index_stem(campus));
+            }
+            self.remove_stale_campus_indices(&written).await?;
+        } else if options.generate_index {
+            // Disabled: drop the index of earlier runs rather than leave it stale
+            self.remove_index().await?;
         }
 
-        // Write stats for debugging
+        // Write stats and the (deterministically ordered) error report for debugging
         self.write_json("stats.json", stats).await?;
+        self.write_json("errors.json", &CrawlOutcomeReport::from(outcome))
+            .await?;
+
+        Ok((hot_count, archive_keys))
+    }
+
+    /// Merge notices into an archive file, returning its key.
+    ///
+    /// Only notices missing from the archive are cloned into it.
+    async fn merge_archive(
+        &self,
+        year: i32,
+        month: u32,
+        notices: Vec<&NoticeOutput>,
+    ) -> Result<String> {
+        let key = Self::archive_key(year, month);
+
+        // Merge with existing archive if present
+        let mut existing: Vec<NoticeOutput> = self.read_json(&key).await?.unwrap_or_default();
+
+        // Deduplicate by ID
+        let existing_ids: std::collections::HashSet<_> =
+            existing.iter().map(|n| n.id.clone()).collect();
+
+        for notice in notices {
+            if !existing_ids.contains(&notice.id) {
+                existing.push(notice.clone());
+            }
+        }
+
+        // Sort by date descending
+        existing.sort_by(|a, b| b.metadata.date.cmp(&a.metadata.date));
+
+        self.write_json(&key, &existing).await?;
+        log::info!("Cold data: {} notices written to {}", existing.len(), key);
+        Ok(key)
+    }
+}
 
-        Ok((current_data.count, cold_files_updated))
+#[async_trait]
+impl ByteReader for LocalStorage {
+    /// Read bytes, returning None if file doesn't exist.
+    async fn read_bytes(&self, key: &str) -> Result<Option<Vec<u8>>> {
+        let path = self.path(key);
+        match tokio::fs::read(&path).await {
+            Ok(bytes) => Ok(Some(bytes)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(AppError::Io(e)),
+        }
     }
 }
 
@@ -223,34 +577,109 @@ impl NoticeStorage for LocalStorage {
     async fn write_notices_with_options(
         &self,
         outcome: &CrawlOutcome,
-        _campuses: &[Campus],
+        campuses: &[Campus],
         stats: &CrawlStats,
         options: &WriteOptions,
     ) -> Result<WriteMetadata> {
         let now = Utc::now();
 
         // Convert notices to output format
-        let current_notices: Vec<NoticeOutput> =
-            outcome.notices.iter().map(NoticeOutput::from).collect();
+        let id_options = IdOptions {
+            link_params: self.link_id_params.as_deref(),
+            include_college: self.id_includes_college,
+        };
+        let mut current_notices: Vec<NoticeOutput> = outcome
+            .notices
+            .iter()
+            .map(|notice| NoticeOutput::new(notice, id_options))
+            .collect();
 
         // Load previous snapshot for circuit breaker and diff
         let previous_notices = self.load_current().await.unwrap_or_default();
+        let mut held_campuses = Vec::new();
+
+        let state: CircuitBreakerState = self
+            .read_json(Self::BREAKER_STATE_KEY)
+            .await
+            .unwrap_or_default()
+            .unwrap_or_default();
 
         // Circuit Breaker Check
         if options.circuit_breaker && !options.force_write {
-            if let Err(_) = self
-                .circuit_breaker
-                .validate(&current_notices, &previous_notices)
-            {
+            // Compare with the last written crawl rather than current.json,
+            // which only holds the current month
+            let previous_counts = state
+                .written_counts
+                .clone()
+                .unwrap_or_else(|| campus_counts(&previous_notices, []));
+            // Only configured campuses are checked, so a removed one does not
+            // read as empty; without a campus list, every known campus is
+            let current_counts = if campuses.is_empty() {
+                campus_counts(&current_notices, previous_counts.keys().map(String::as_str))
+            } else {
+                campus_counts(&current_notices, campuses.iter().map(|c| c.campus.as_str()))
+            };
+            let result = self.circuit_breaker.check_counts_with_state(
+                current_notices.len(),
+                previous_counts.values().sum(),
+                &state,
+            );
+            let mut next_state = state
+                .advance(current_notices.len())
+                .advance_campuses(&current_counts);
+
+            let write_skipped = matches!(result, CircuitBreakerResult::EmptyGrace { .. });
+            let mut triggered = self.circuit_breaker.evaluate(result).is_err();
+            if !triggered && !write_skipped {
+                let check =
+                    self.circuit_breaker
+                        .check_campuses(&current_counts, &previous_counts, &state);
+                triggered = check.abort.is_some();
+                held_campuses = check.held.into_iter().collect();
+            }
+            if triggered {
                 log::error!("Circuit breaker triggered - aborting write!");
+            }
+            if triggered || write_skipped {
+                self.write_json(Self::BREAKER_STATE_KEY, &next_state)
+                    .await?;
                 return Ok(WriteMetadata {
                     hot_count: 0,
                     cold_files_updated: 0,
+                    archive_keys: Vec::new(),
                     timestamp: now,
                     diff: None,
-                    circuit_breaker_triggered: true,
+                    circuit_breaker_triggered: triggered,
+                    write_skipped,
+                    held_campuses: Vec::new(),
                 });
             }
+
+            // Held campuses keep their stored notices and recorded counts
+            let mut written_counts = current_counts;
+            for campus in &held_campuses {
+                written_counts.insert(
+                    campus.clone(),
+                    previous_counts.get(campus).copied().unwrap_or(0),
+                );
+            }
+            next_state.written_counts = Some(written_counts);
+            self.write_json(Self::BREAKER_STATE_KEY, &next_state)
+                .await?;
+
+            if !held_campuses.is_empty() {
+                let held = |n: &NoticeOutput| held_campuses.contains(&n.metadata.campus);
+                current_notices.retain(|n| !held(n));
+                current_notices.extend(previous_notices.iter().filter(|n| held(n)).cloned());
+            }
+        } else {
+            // Keep the baseline current for the next checked run
+            let next_state = CircuitBreakerState {
+                written_counts: Some(campus_counts(&current_notices, [])),
+                ..state
+            };
+            self.write_json(Self::BREAKER_STATE_KEY, &next_state)
+                .await?;
         }
 
         // Calculate diff for notifications
@@ -270,16 +699,20 @@ impl NoticeStorage for LocalStorage {
         };
 
         // Write hot/cold data and generate index
-        let (hot_count, cold_files_updated) = self
+        let (hot_count, archive_keys) = self
             .write_hot_cold_data(outcome, stats, &current_notices, options)
             .await?;
+        self.write_run_snapshot(now, &current_notices).await?;
 
         Ok(WriteMetadata {
             hot_count,
-            cold_files_updated,
+            cold_files_updated: archive_keys.len(),
+            archive_keys,
             timestamp: now,
             diff,
             circuit_breaker_triggered: false,
+            write_skipped: false,
+            held_campuses,
         })
     }
 
@@ -304,20 +737,160 @@ impl NoticeStorage for LocalStorage {
         }
     }
 
+    async fn load_snapshot(&self, version: &str) -> Result<Option<Vec<NoticeOutput>>> {
+        let key = Self::snapshot_key(version);
+        match self.read_json::<CurrentData>(&key).await {
+            Ok(data) => Ok(data.map(|data| data.notices)),
+            Err(AppError::Json(e)) => {
+                log::warn!("{} unreadable: {}", key, e);
+                Ok(None)
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    async fn list_snapshots(&self) -> Result<Vec<SnapshotInfo>> {
+        let mut snapshots = Vec::new();
+        for version in self.list_snapshot_versions().await?.into_iter().rev() {
+            let key = Self::snapshot_key(&version);
+            let data = self.read_json::<CurrentData>(&key).await;
+            if let Err(e) = &data {
+                log::warn!("{} unreadable: {}", key, e);
+            }
+            let data = data.ok().flatten();
+            snapshots.push(SnapshotInfo {
+                version,
+                finished_at: data.as_ref().map(|d| d.updated_at),
+                notice_count: data.as_ref().map_or(0, |d| d.notices.len()),
+                complete: data.is_some(),
+            });
+        }
+        Ok(snapshots)
+    }
+
     async fn load_index(&self) -> Result<Option<InvertedIndex>> {
-        self.read_json("index.json").await
+        if let Some(index) = self.read_json("index.json").await? {
+            return Ok(Some(index));
+        }
+        let Some(manifest) = self.load_index_manifest().await? else {
+            return Ok(None);
+        };
+        let mut shards = Vec::new();
+        for name in manifest.shard_names() {
+            shards.extend(self.load_index_shard(&name).await?);
+        }
+        Ok(Some(InvertedIndex::from_shards(&manifest, shards)))
     }
 
+    /// Write `index.json`, or with `shards` set in the [`IndexConfig`] the
+    /// shards and manifest under `index/`. Files of the other layout and
+    /// shards left over from a larger shard count are removed.
     async fn save_index(&self, index: &InvertedIndex) -> Result<()> {
-        self.write_json("index.json", index).await
+        if self.index_config.shards == 0 {
+            self.write_json("index.json", index).await?;
+            self.remove_key("index/manifest.json").await?;
+            return self.remove_stale_shards(&HashSet::new()).await;
+        }
+
+        let (manifest, shards) = index.shard(self.index_config.shards);
+        for (name, shard) in &shards {
+            self.write_json(&format!("index/{}.json", name), shard)
+                .await?;
+        }
+        // Manifest last, so it never points at a shard not yet written
+        self.write_json("index/manifest.json", &manifest).await?;
+        self.remove_stale_shards(&shards.into_keys().collect())
+            .await?;
+        self.remove_key("index.json").await
+    }
+
+    async fn load_campus_index(&self, campus: &str) -> Result<Option<InvertedIndex>> {
+        self.read_json(&Self::campus_index_key(campus)).await
+    }
+
+    async fn save_campus_index(&self, campus: &str, index: &InvertedIndex) -> Result<()> {
+        self.write_json(&Self::campus_index_key(campus), index)
+            .await
+    }
+
+    async fn load_index_manifest(&self) -> Result<Option<IndexManifest>> {
+        self.read_json("index/manifest.json").await
+    }
+
+    async fn load_index_shard(&self, shard: &str) -> Result<Option<InvertedIndex>> {
+        self.read_json(&format!("index/{}.json", shard)).await
+    }
+
+    async fn save_diff(&self, diff: &DiffResult) -> Result<()> {
+        self.write_json("notify_diff.json", diff).await?;
+        self.write_json("diff.json", &diff.to_summary()).await
+    }
+
+    async fn load_diff(&self) -> Result<Option<DiffResult>> {
+        self.read_json("notify_diff.json").await
+    }
+
+    async fn load_http_cache(&self) -> Result<HttpCache> {
+        Ok(self
+            .read_json(Self::HTTP_CACHE_KEY)
+            .await?
+            .unwrap_or_default())
+    }
+
+    async fn save_http_cache(&self, cache: &HttpCache) -> Result<()> {
+        self.write_json(Self::HTTP_CACHE_KEY, cache).await
+    }
+
+    async fn save_health_report(&self, report: &HealthReport) -> Result<()> {
+        self.write_json("health.json", report).await
+    }
+
+    async fn prune_to_size(&self, max_bytes: u64) -> Result<PruneResult> {
+        let mut usage = self.usage_bytes().await?;
+        let mut result = PruneResult::default();
+        if usage <= max_bytes {
+            return Ok(result);
+        }
+
+        let mut archives = Vec::new();
+        for (year, month) in self.list_archives().await? {
+            let path = self.path(&Self::archive_key(year, month));
+            let bytes = tokio::fs::metadata(&path).await?.len();
+            archives.push((year, month, path, bytes));
+        }
+        // Only archives can be pruned; deleting them all would lose history
+        // without getting under budget
+        let fixed = usage - archives.iter().map(|a| a.3).sum::<u64>();
+        if fixed > max_bytes {
+            log::warn!(
+                "Storage over budget but not prunable: {} bytes outside archives > {} bytes",
+                fixed,
+                max_bytes
+            );
+            return Ok(result);
+        }
+
+        for (year, month, path, bytes) in archives {
+            if usage <= max_bytes {
+                break;
+            }
+            tokio::fs::remove_file(&path).await?;
+
+            usage -= bytes;
+            result.reclaimed_bytes += bytes;
+            result.deleted.push(format!("{}/{:02}", year, month));
+            log::info!("Pruned archive {}/{:02} ({} bytes)", year, month, bytes);
+        }
+        Ok(result)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::models::NoticeMetadata;
-    use crate::pipeline::CircuitBreakerConfig;
+    use crate::models::{Notice, NoticeMetadata};
+    use crate::pipeline::{CircuitBreakerConfig, build_index};
+    use chrono::TimeZone;
     use tempfile::TempDir;
 
     #[tokio::test]
@@ -353,8 +926,12 @@ mod tests {
                 college: "공과대학".to_string(),
                 department_name: "테스트학과".to_string(),
                 board_name: "공지사항".to_string(),
+                author: String::new(),
                 date: "2026-02-01".to_string(),
                 pinned: false,
+                lang: None,
+                has_attachment: false,
+                content_hash: None,
             },
         }];
 
@@ -381,8 +958,12 @@ mod tests {
                 college: "".to_string(),
                 department_name: "학생처".to_string(),
                 board_name: "공지".to_string(),
+                author: String::new(),
                 date: "2026-02-02".to_string(),
                 pinned: false,
+                lang: None,
+                has_attachment: false,
+                content_hash: None,
             },
         }];
 
@@ -392,6 +973,83 @@ mod tests {
         let loaded = storage.load_index().await.unwrap().unwrap();
         assert_eq!(loaded.notice_count, 1);
         assert!(loaded.index.contains_key("장학금"));
+        // Sharding is opt-in
+        assert!(storage.load_index_manifest().await.unwrap().is_none());
+
+        let sharded = storage.clone().with_index_config(IndexConfig {
+            shards: 4,
+            ..IndexConfig::default()
+        });
+        sharded.save_index(&index).await.unwrap();
+        assert!(!tmp.path().join("index.json").exists());
+
+        let manifest = sharded.load_index_manifest().await.unwrap().unwrap();
+        let shard = sharded
+            .load_index_shard(&manifest.shard_for("장학금"))
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(shard.index["장학금"], index.index["장학금"]);
+        assert_eq!(sharded.load_index().await.unwrap().unwrap(), index);
+
+        let config = IndexConfig::default();
+        let partial = sharded
+            .load_index_for_query("장학금", &config)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(partial.search("장학금", &config), vec!["001".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn test_stale_shards_removed() {
+        let tmp = TempDir::new().unwrap();
+        let notices: Vec<NoticeOutput> = (0..20)
+            .map(|i| NoticeOutput {
+                id: format!("{:03}", i),
+                title: format!("notice{} token{}", i, i * 7),
+                link: format!("https://example.com/{}", i),
+                metadata: NoticeMetadata {
+                    campus: "신촌캠퍼스".to_string(),
+                    college: "".to_string(),
+                    department_name: "학생처".to_string(),
+                    board_name: "공지".to_string(),
+                    author: String::new(),
+                    date: "2026-02-02".to_string(),
+                    pinned: false,
+                    lang: None,
+                    has_attachment: false,
+                    content_hash: None,
+                },
+            })
+            .collect();
+        let index = build_index(&notices);
+        let sharded = |shards| {
+            LocalStorage::new(tmp.path()).with_index_config(IndexConfig {
+                shards,
+                ..IndexConfig::default()
+            })
+        };
+        let shard_files = || {
+            std::fs::read_dir(tmp.path().join("index"))
+                .map(|entries| {
+                    entries
+                        .filter_map(|e| e.ok()?.file_name().into_string().ok())
+                        .filter(|name| name != "manifest.json")
+                        .count()
+                })
+                .unwrap_or(0)
+        };
+
+        sharded(16).save_index(&index).await.unwrap();
+        assert!(shard_files() > 2);
+        sharded(2).save_index(&index).await.unwrap();
+        assert_eq!(shard_files(), 2);
+
+        sharded(0).save_index(&index).await.unwrap();
+        assert_eq!(shard_files(), 0);
+        assert!(!tmp.path().join("index/manifest.json").exists());
+        assert!(tmp.path().join("index.json").exists());
     }
 
     #[tokio::test]
@@ -399,13 +1057,650 @@ mod tests {
         let tmp = TempDir::new().unwrap();
         let config = CircuitBreakerConfig {
             max_drop_percent: 10, // Stricter threshold
+            max_gain_percent: None,
             min_baseline: 5,
             allow_cold_start: true,
+            empty_grace_runs: 0,
+            per_campus: crate::pipeline::PerCampusMode::Off,
         };
         let cb = CircuitBreaker::with_config(config);
         let storage = LocalStorage::with_circuit_breaker(tmp.path(), cb);
 
         // Storage should be created successfully
-        assert!(storage.path("test.txt").exists() == false);
+        assert!(!storage.path("test.txt").exists());
+    }
+
+    fn empty_stats() -> CrawlStats {
+        CrawlStats {
+            schema_version: CrawlStats::SCHEMA_VERSION,
+            start_time: Utc::now(),
+            end_time: Utc::now(),
+            notice_count: 0,
+            department_count: 0,
+            board_count: 0,
+            board_total: 0,
+            board_failures: 0,
+            board_success_rate: 0.0,
+            notice_total: 0,
+            notice_failures: 0,
+            notice_success_rate: 0.0,
+            detail_total: 0,
+            detail_failures: 0,
+            detail_success_rate: 0.0,
+            bytes_downloaded: 0,
+            bytes_parsed: 0,
+            total_fetch_ms: 0,
+            total_parse_ms: 0,
+            total_wait_ms: 0,
+            avg_board_fetch_ms: 0,
+            notices_per_second: 0.0,
+            notices_filtered: 0,
+        }
+    }
+
+    fn campus_outcome(campus: &str, count: usize) -> CrawlOutcome {
+        let date = Utc::now().format("%Y-%m-%d").to_string();
+        let notices = (0..count)
+            .map(|i| Notice {
+                campus: campus.to_string(),
+                college: "".to_string(),
+                department_id: "dept".to_string(),
+                department_name: "학생처".to_string(),
+                board_id: "notice".to_string(),
+                board_name: "공지".to_string(),
+                title: format!("{} notice {}", campus, i),
+                author: "".to_string(),
+                date: date.clone(),
+                views: None,
+                link: format!("https://example.com/{}/{}", campus, i),
+                source_id: None,
+                is_pinned: false,
+                date_inferred: false,
+                lang: None,
+                attachments: Vec::new(),
+            })
+            .collect();
+        CrawlOutcome {
+            notices,
+            ..CrawlOutcome::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_campus_scoped_snapshots_are_independent() {
+        let tmp = TempDir::new().unwrap();
+        let storage = LocalStorage::new(tmp.path());
+        let sinchon = storage.with_campus("신촌캠퍼스");
+        let mirae = storage.with_campus("미래캠퍼스");
+        let options = WriteOptions::unsafe_for_testing();
+
+        sinchon
+            .write_notices_with_options(
+                &campus_outcome("신촌캠퍼스", 3),
+                &[],
+                &empty_stats(),
+                &options,
+            )
+            .await
+            .unwrap();
+        mirae
+            .write_notices_with_options(
+                &campus_outcome("미래캠퍼스", 1),
+                &[],
+                &empty_stats(),
+                &options,
+            )
+            .await
+            .unwrap();
+
+        let loaded = sinchon.load_current().await.unwrap();
+        assert_eq!(loaded.len(), 3);
+        assert!(loaded.iter().all(|n| n.metadata.campus == "신촌캠퍼스"));
+
+        let loaded = mirae.load_current().await.unwrap();
+        assert_eq!(loaded.len(), 1);
+        assert_eq!(loaded[0].metadata.campus, "미래캠퍼스");
+
+        let segment = LocalStorage::campus_segment("신촌캠퍼스");
+        assert!(tmp.path().join(segment).join("current.json").exists());
+        assert!(storage.load_current().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_load_month_after_write() {
+        let tmp = TempDir::new().unwrap();
+        let storage = LocalStorage::new(tmp.path());
+        storage
+            .write_notices_with_options(
+                &campus_outcome("신촌캠퍼스", 2),
+                &[],
+                &empty_stats(),
+                &WriteOptions::unsafe_for_testing(),
+            )
+            .await
+            .unwrap();
+
+        let today = Utc::now();
+        let loaded = storage
+            .load_month(today.year(), today.month())
+            .await
+            .unwrap();
+        assert_eq!(loaded.len(), 2);
+        assert!(
+            loaded
+                .iter()
+                .all(|n| n.title.starts_with("신촌캠퍼스 notice"))
+        );
+
+        assert!(storage.load_month(2001, 1).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_run_snapshots_listed_newest_first() {
+        let tmp = TempDir::new().unwrap();
+        let storage = LocalStorage::new(tmp.path()).with_snapshot_retention(2);
+        assert!(storage.list_snapshots().await.unwrap().is_empty());
+
+        let outputs = |count| -> Vec<NoticeOutput> {
+            campus_outcome("미래캠퍼스", count)
+                .notices
+                .iter()
+                .map(NoticeOutput::from)
+                .collect()
+        };
+        let day = |d| Utc.with_ymd_and_hms(2001, 1, d, 0, 0, 0).unwrap();
+        storage
+            .write_run_snapshot(day(1), &outputs(1))
+            .await
+            .unwrap();
+        storage
+            .write_run_snapshot(day(2), &outputs(3))
+            .await
+            .unwrap();
+        storage
+            .write_notices_with_options(
+                &campus_outcome("신촌캠퍼스", 2),
+                &[],
+                &empty_stats(),
+                &WriteOptions::unsafe_for_testing(),
+            )
+            .await
+            .unwrap();
+        storage
+            .write_bytes(
+                &LocalStorage::snapshot_key("20010103T000000Z"),
+                b"{\"notices\":",
+            )
+            .await
+            .unwrap();
+
+        let snapshots = storage.list_snapshots().await.unwrap();
+        let listed: Vec<(&str, usize, bool)> = snapshots
+            .iter()
+            .map(|s| (s.version.as_str(), s.notice_count, s.complete))
+            .collect();
+        // The oldest run fell out of the retention
+        assert_eq!(listed.len(), 3);
+        assert_eq!((listed[0].1, listed[0].2), (2, true));
+        assert_eq!(
+            listed[1..],
+            [
+                ("20010103T000000Z", 0, false),
+                ("20010102T000000Z", 3, true)
+            ]
+        );
+        assert!(snapshots[0].finished_at.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_load_version() {
+        let tmp = TempDir::new().unwrap();
+        let storage = LocalStorage::new(tmp.path());
+        assert!(storage.load_version("latest").await.is_err());
+
+        storage
+            .write_notices_with_options(
+                &campus_outcome("신촌캠퍼스", 2),
+                &[],
+                &empty_stats(),
+                &WriteOptions::unsafe_for_testing(),
+            )
+            .await
+            .unwrap();
+
+        let latest = storage.load_version("latest").await.unwrap();
+        assert_eq!(latest.len(), 2);
+        let version = &storage.list_snapshots().await.unwrap()[0].version;
+        assert_eq!(storage.load_version(version).await.unwrap(), latest);
+
+        for version in ["20010101T000000Z", "current", "2026-01", "../current"] {
+            let err = storage.load_version(version).await.unwrap_err();
+            assert!(
+                matches!(err, AppError::Validation(_)),
+                "{}: {:?}",
+                version,
+                err
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_snapshot_index_follows_index_config() {
+        let tmp = TempDir::new().unwrap();
+        let options = WriteOptions {
+            generate_index: true,
+            ..WriteOptions::unsafe_for_testing()
+        };
+
+        let storage = LocalStorage::new(tmp.path()).with_index_config(IndexConfig {
+            store_ranking_stats: true,
+            ..IndexConfig::default()
+        });
+        storage
+            .write_notices_with_options(
+                &campus_outcome("신촌캠퍼스", 2),
+                &[],
+                &empty_stats(),
+                &options,
+            )
+            .await
+            .unwrap();
+        let index = storage.load_index().await.unwrap().unwrap();
+        assert_eq!(index.notice_count, 2);
+        assert_eq!(index.doc_lengths.len(), 2);
+
+        // Disabling the index removes the one written before
+        let disabled = storage.with_index_config(IndexConfig {
+            enabled: false,
+            ..IndexConfig::default()
+        });
+        disabled
+            .write_notices_with_options(
+                &campus_outcome("신촌캠퍼스", 1),
+                &[],
+                &empty_stats(),
+                &options,
+            )
+            .await
+            .unwrap();
+        assert_eq!(disabled.load_current().await.unwrap().len(), 1);
+        assert!(disabled.load_index().await.unwrap().is_none());
+        assert!(!tmp.path().join("index").exists());
+    }
+
+    #[tokio::test]
+    async fn test_campus_index_contains_only_campus_notices() {
+        let tmp = TempDir::new().unwrap();
+        let storage = LocalStorage::new(tmp.path());
+        let mut outcome = campus_outcome("신촌캠퍼스", 2);
+        outcome
+            .notices
+            .extend(campus_outcome("미래캠퍼스", 1).notices);
+        let options = WriteOptions {
+            generate_index: true,
+            ..WriteOptions::unsafe_for_testing()
+        };
+        storage
+            .write_notices_with_options(&outcome, &[], &empty_stats(), &options)
+            .await
+            .unwrap();
+
+        let current = storage.load_current().await.unwrap();
+        let ids_of = |campus: &str| -> HashSet<String> {
+            current
+                .iter()
+                .filter(|n| n.metadata.campus == campus)
+                .map(|n| n.id.clone())
+                .collect()
+        };
+        for campus in ["신촌캠퍼스", "미래캠퍼스"] {
+            let index = storage.load_campus_index(campus).await.unwrap().unwrap();
+            let indexed: HashSet<String> = index.index.values().flatten().cloned().collect();
+            assert_eq!(indexed, ids_of(campus), "{}", campus);
+            assert_eq!(index.notice_count, ids_of(campus).len());
+        }
+        assert_eq!(storage.load_index().await.unwrap().unwrap().notice_count, 3);
+        assert!(
+            storage
+                .load_campus_index("국제캠퍼스")
+                .await
+                .unwrap()
+                .is_none()
+        );
+
+        // A campus that leaves the snapshot takes its index with it
+        let outcome = campus_outcome("신촌캠퍼스", 2);
+        storage
+            .write_notices_with_options(&outcome, &[], &empty_stats(), &options)
+            .await
+            .unwrap();
+        assert!(
+            storage
+                .load_campus_index("미래캠퍼스")
+                .await
+                .unwrap()
+                .is_none()
+        );
+        assert!(
+            storage
+                .load_campus_index("신촌캠퍼스")
+                .await
+                .unwrap()
+                .is_some()
+        );
+    }
+
+    #[test]
+    fn test_campus_segments_do_not_collide() {
+        let segments: HashSet<String> = ["A/B", "A_B", "A.B", "A B"]
+            .iter()
+            .map(|campus| LocalStorage::campus_segment(campus))
+            .collect();
+        assert_eq!(segments.len(), 4);
+        assert!(segments.iter().all(|s| s.starts_with("A_B-")));
+        assert!(!LocalStorage::campus_segment("../etc").contains('/'));
+    }
+
+    #[test]
+    fn test_with_campus_sanitizes_segment() {
+        let storage = LocalStorage::new("/data");
+        let scoped = storage.with_campus("../evil/campus");
+        let segment = LocalStorage::campus_segment("../evil/campus");
+        assert!(segment.starts_with("___evil_campus-"));
+        assert_eq!(
+            scoped.path("current.json"),
+            Path::new("/data").join(segment).join("current.json")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_empty_grace_keeps_snapshot_until_exhausted() {
+        let tmp = TempDir::new().unwrap();
+        let cb = CircuitBreaker::with_config(CircuitBreakerConfig {
+            empty_grace_runs: 2,
+            ..CircuitBreakerConfig::default()
+        });
+        let storage = LocalStorage::with_circuit_breaker(tmp.path(), cb);
+
+        let previous: Vec<NoticeOutput> = (0..20)
+            .map(|i| NoticeOutput {
+                id: format!("id_{}", i),
+                title: format!("Notice {}", i),
+                link: format!("https://example.com/{}", i),
+                metadata: NoticeMetadata {
+                    campus: "신촌캠퍼스".to_string(),
+                    college: "".to_string(),
+                    department_name: "학생처".to_string(),
+                    board_name: "공지".to_string(),
+                    author: String::new(),
+                    date: "2026-02-02".to_string(),
+                    pinned: false,
+                    lang: None,
+                    has_attachment: false,
+                    content_hash: None,
+                },
+            })
+            .collect();
+        storage
+            .write_json("current.json", &CurrentData::new(previous))
+            .await
+            .unwrap();
+
+        let outcome = CrawlOutcome::default();
+        let stats = empty_stats();
+
+        for _ in 0..2 {
+            let meta = storage.write_notices(&outcome, &[], &stats).await.unwrap();
+            assert!(meta.write_skipped);
+            assert!(!meta.circuit_breaker_triggered);
+            assert_eq!(storage.load_current().await.unwrap().len(), 20);
+        }
+
+        let meta = storage.write_notices(&outcome, &[], &stats).await.unwrap();
+        assert!(!meta.write_skipped);
+        assert!(meta.circuit_breaker_triggered);
+        assert_eq!(storage.load_current().await.unwrap().len(), 20);
+    }
+
+    #[tokio::test]
+    async fn test_large_drop_keeps_current_snapshot() {
+        let tmp = TempDir::new().unwrap();
+        let storage = LocalStorage::new(tmp.path());
+        let options = WriteOptions {
+            generate_index: false,
+            ..WriteOptions::safe()
+        };
+
+        let meta = storage
+            .write_notices_with_options(
+                &campus_outcome("신촌캠퍼스", 500),
+                &[],
+                &empty_stats(),
+                &options,
+            )
+            .await
+            .unwrap();
+        assert_eq!(meta.hot_count, 500);
+        let before = std::fs::read(storage.path("current.json")).unwrap();
+
+        let meta = storage
+            .write_notices_with_options(
+                &campus_outcome("신촌캠퍼스", 3),
+                &[],
+                &empty_stats(),
+                &options,
+            )
+            .await
+            .unwrap();
+        assert!(meta.circuit_breaker_triggered);
+        assert_eq!(meta.hot_count, 0);
+        assert_eq!(std::fs::read(storage.path("current.json")).unwrap(), before);
+        assert!(storage.load_diff().await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_tripped_campus_keeps_stored_notices() {
+        let tmp = TempDir::new().unwrap();
+        let cb = CircuitBreaker::with_config(CircuitBreakerConfig {
+            per_campus: crate::pipeline::PerCampusMode::Any,
+            ..CircuitBreakerConfig::default()
+        });
+        let storage = LocalStorage::with_circuit_breaker(tmp.path(), cb);
+        let options = WriteOptions {
+            generate_index: false,
+            ..WriteOptions::safe()
+        };
+        let crawl = |sinchon: usize, mirae: usize| {
+            let mut outcome = campus_outcome("신촌캠퍼스", sinchon);
+            outcome
+                .notices
+                .extend(campus_outcome("미래캠퍼스", mirae).notices);
+            outcome
+        };
+
+        storage
+            .write_notices_with_options(&crawl(50, 50), &[], &empty_stats(), &options)
+            .await
+            .unwrap();
+        let meta = storage
+            .write_notices_with_options(&crawl(90, 0), &[], &empty_stats(), &options)
+            .await
+            .unwrap();
+
+        assert!(!meta.circuit_breaker_triggered);
+        assert_eq!(meta.held_campuses, ["미래캠퍼스"]);
+        let current = storage.load_current().await.unwrap();
+        let count = |campus: &str| {
+            current
+                .iter()
+                .filter(|n| n.metadata.campus == campus)
+                .count()
+        };
+        assert_eq!((count("신촌캠퍼스"), count("미래캠퍼스")), (90, 50));
+    }
+
+    #[tokio::test]
+    async fn test_breaker_baseline_is_last_written_crawl() {
+        let tmp = TempDir::new().unwrap();
+        let cb = CircuitBreaker::with_config(CircuitBreakerConfig {
+            max_gain_percent: Some(50),
+            ..CircuitBreakerConfig::default()
+        });
+        let storage = LocalStorage::with_circuit_breaker(tmp.path(), cb);
+        let options = WriteOptions {
+            generate_index: false,
+            ..WriteOptions::safe()
+        };
+        // Mostly last month's notices: current.json only holds the 20 recent ones
+        let mut outcome = campus_outcome("신촌캠퍼스", 100);
+        let last_month = (Utc::now() - chrono::Duration::days(40))
+            .format("%Y-%m-%d")
+            .to_string();
+        for notice in outcome.notices.iter_mut().skip(20) {
+            notice.date = last_month.clone();
+        }
+
+        for _ in 0..2 {
+            let meta = storage
+                .write_notices_with_options(&outcome, &[], &empty_stats(), &options)
+                .await
+                .unwrap();
+            assert!(!meta.circuit_breaker_triggered);
+            assert_eq!(meta.hot_count, 20);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_http_cache_round_trip() {
+        let tmp = TempDir::new().unwrap();
+        let storage = LocalStorage::new(tmp.path());
+        assert!(storage.load_http_cache().await.unwrap().entries.is_empty());
+
+        let mut cache = HttpCache::default();
+        cache.entries.insert(
+            "https://example.com/notice".to_string(),
+            crate::models::HttpCacheEntry {
+                validators: crate::models::HttpValidators {
+                    etag: Some("\"v1\"".to_string()),
+                    last_modified: None,
+                },
+                notices: campus_outcome("신촌캠퍼스", 1).notices,
+            },
+        );
+        storage.save_http_cache(&cache).await.unwrap();
+
+        let loaded = storage.load_http_cache().await.unwrap();
+        let entry = &loaded.entries["https://example.com/notice"];
+        assert_eq!(entry.validators.etag.as_deref(), Some("\"v1\""));
+        assert_eq!(entry.notices.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_prune_to_size_deletes_oldest_archives() {
+        let tmp = TempDir::new().unwrap();
+        let storage = LocalStorage::new(tmp.path());
+        let options = WriteOptions::unsafe_for_testing();
+        storage
+            .write_notices_with_options(
+                &campus_outcome("신촌캠퍼스", 2),
+                &[],
+                &empty_stats(),
+                &options,
+            )
+            .await
+            .unwrap();
+
+        let filler = vec![b'x'; 1_000];
+        for key in [
+            "stacks/2024/11.json",
+            "stacks/2024/12.json",
+            "stacks/2025/01.json",
+        ] {
+            storage.write_bytes(key, &filler).await.unwrap();
+        }
+
+        let before = storage.usage_bytes().await.unwrap();
+        let budget = before - 1_500;
+        let result = storage.prune_to_size(budget).await.unwrap();
+
+        assert_eq!(result.deleted, ["2024/11", "2024/12"]);
+        assert_eq!(result.reclaimed_bytes, 2_000);
+        assert!(storage.usage_bytes().await.unwrap() <= budget);
+        assert!(storage.path("stacks/2025/01.json").exists());
+        assert_eq!(storage.load_current().await.unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_archives_written_in_parallel_are_listed_sorted() {
+        let tmp = TempDir::new().unwrap();
+        let storage = LocalStorage::new(tmp.path()).with_write_concurrency(4);
+
+        let mut outcome = campus_outcome("신촌캠퍼스", 6);
+        let dates = [
+            "2025-03-02",
+            "2024-11-20",
+            "2025-01-05",
+            "2024-11-03",
+            "2024-12-31",
+        ];
+        for (notice, date) in outcome.notices.iter_mut().zip(dates) {
+            notice.date = date.to_string();
+        }
+
+        let metadata = storage
+            .write_notices_with_options(
+                &outcome,
+                &[],
+                &empty_stats(),
+                &WriteOptions::unsafe_for_testing(),
+            )
+            .await
+            .unwrap();
+
+        let expected = [
+            "stacks/2024/11.json",
+            "stacks/2024/12.json",
+            "stacks/2025/01.json",
+            "stacks/2025/03.json",
+        ];
+        assert_eq!(metadata.archive_keys, expected);
+        assert_eq!(metadata.cold_files_updated, 4);
+        for key in expected {
+            assert!(storage.path(key).exists());
+        }
+        assert_eq!(storage.load_archive(2024, 11).await.unwrap().len(), 2);
+        assert_eq!(metadata.hot_count, 1);
+    }
+
+    #[tokio::test]
+    async fn test_prune_to_size_within_budget_is_noop() {
+        let tmp = TempDir::new().unwrap();
+        let storage = LocalStorage::new(tmp.path());
+        storage
+            .write_bytes("stacks/2024/11.json", b"[]")
+            .await
+            .unwrap();
+
+        let result = storage.prune_to_size(u64::MAX).await.unwrap();
+        assert!(result.deleted.is_empty());
+        assert_eq!(result.reclaimed_bytes, 0);
+    }
+
+    #[tokio::test]
+    async fn test_prune_to_size_keeps_archives_when_budget_unreachable() {
+        let tmp = TempDir::new().unwrap();
+        let storage = LocalStorage::new(tmp.path());
+        storage
+            .write_bytes("http-cache.json", &[b'x'; 1_000])
+            .await
+            .unwrap();
+        storage
+            .write_bytes("stacks/2024/11.json", b"[]")
+            .await
+            .unwrap();
+
+        let result = storage.prune_to_size(500).await.unwrap();
+        assert!(result.deleted.is_empty());
+        assert!(storage.path("stacks/2024/11.json").exists());
     }
 }
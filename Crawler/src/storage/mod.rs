@@ -18,8 +18,14 @@
 //! storage/
 //! ├── config.toml           # Crawler Configuration
 //! ├── index.json            # Inverted Index for Search
+//! ├── index/                # Sharded index: manifest.json + <shard>.json
 //! ├── current.json          # Hot: Latest notices (SWR cached)
+//! ├── notify_diff.json      # Latest diff with payloads for the notifier
+//! ├── diff.json             # Latest diff, IDs only
 //! ├── siteMap.json          # Site Map for Crawling
+//! ├── breaker_state.json    # Circuit Breaker state across runs
+//! ├── http-cache.json       # ETag/Last-Modified and notices per board list
+//! ├── snapshots/            # Notices written by each recent run, for diffs
 //! └── stacks/               # Cold: Monthly archives (immutable)
 //!     ├── 2025/
 //!     │   ├── 01.json
@@ -30,13 +36,18 @@
 
 pub mod local;
 
+use std::collections::{BTreeSet, HashSet};
+use std::path::Path;
+
 use async_trait::async_trait;
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDateTime, Utc};
 use serde::{Deserialize, Serialize};
 
-use crate::error::Result;
-use crate::models::{Campus, CrawlOutcome, CrawlStats, NoticeOutput};
-use crate::pipeline::{DiffResult, InvertedIndex};
+use crate::error::{AppError, Result};
+use crate::models::{
+    Campus, Config, CrawlOutcome, CrawlStats, HealthReport, HttpCache, NoticeOutput,
+};
+use crate::pipeline::{DiffResult, IndexConfig, IndexManifest, InvertedIndex, query_tokens};
 
 // Re-export for convenience
 pub use local::LocalStorage;
@@ -48,6 +59,9 @@ pub struct WriteMetadata {
     pub hot_count: usize,
     /// Number of archive files updated
     pub cold_files_updated: usize,
+    /// Keys of the archive files updated, sorted
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub archive_keys: Vec<String>,
     /// Timestamp of the write
     pub timestamp: DateTime<Utc>,
     /// Diff result (changes from previous snapshot)
@@ -56,6 +70,43 @@ pub struct WriteMetadata {
     /// Whether circuit breaker was triggered (write aborted)
     #[serde(default)]
     pub circuit_breaker_triggered: bool,
+    /// Whether the write was skipped within the empty-result grace window
+    #[serde(default)]
+    pub write_skipped: bool,
+    /// Campuses whose stored notices were kept instead of this crawl's
+    /// (per-campus circuit breaker)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub held_campuses: Vec<String>,
+}
+
+/// Result of a budget-based prune.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PruneResult {
+    /// Archive periods deleted, oldest first (`YYYY/MM`)
+    pub deleted: Vec<String>,
+    /// Total bytes reclaimed
+    pub reclaimed_bytes: u64,
+}
+
+/// The stored snapshot of one crawl run, as listed by
+/// [`NoticeStorage::list_snapshots`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SnapshotInfo {
+    /// Version for [`NoticeStorage::load_version`]: the UTC time the run
+    /// wrote it, in [`SnapshotInfo::VERSION_FORMAT`]
+    pub version: String,
+    /// When the run wrote the snapshot (`None` when it is not complete)
+    pub finished_at: Option<DateTime<Utc>>,
+    /// Notices in the snapshot (0 when it is not complete)
+    pub notice_count: usize,
+    /// Whether the snapshot file reads back in full; false for a truncated
+    /// or otherwise unreadable file
+    pub complete: bool,
+}
+
+impl SnapshotInfo {
+    /// Format of snapshot versions (`20261015T094500Z`); they sort by time.
+    pub const VERSION_FORMAT: &'static str = "%Y%m%dT%H%M%SZ";
 }
 
 /// Options for write operations.
@@ -114,6 +165,59 @@ impl CurrentData {
     }
 }
 
+/// Trait for reading raw objects from a storage backend.
+///
+/// Lets configuration and other inputs be sourced uniformly from
+/// whichever backend is in use (local filesystem, S3, in-memory).
+#[async_trait]
+pub trait ByteReader: Send + Sync {
+    /// Read the bytes stored under `key`, returning `None` if it does not exist.
+    async fn read_bytes(&self, key: &str) -> Result<Option<Vec<u8>>>;
+
+    /// Load configuration stored under `key`.
+    async fn load_config(&self, key: &str) -> Result<Config> {
+        match self.read_bytes(key).await? {
+            Some(bytes) => Config::from_bytes(&bytes),
+            None => Err(AppError::config(format!("Config not found at '{key}'"))),
+        }
+    }
+
+    /// Load configuration stored under `key`, or the default if loading fails.
+    async fn load_config_or_default(&self, key: &str) -> Config {
+        self.load_config(key).await.unwrap_or_else(|e| {
+            log::warn!("Config load failed from '{}': {}. Using defaults.", key, e);
+            Config::default()
+        })
+    }
+
+    /// Load the sitemap stored under `key`, or `None` when it does not exist.
+    async fn load_sitemap(&self, key: &str) -> Result<Option<Vec<Campus>>> {
+        match self.read_bytes(key).await? {
+            Some(bytes) => Ok(Some(serde_json::from_slice(&bytes)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Load the sitemap stored under `key`, falling back to a bundled local
+    /// file.
+    ///
+    /// Fails when neither exists, so a deployment without a sitemap errors
+    /// instead of crawling nothing.
+    async fn load_sitemap_or_file(&self, key: &str, fallback: &Path) -> Result<Vec<Campus>> {
+        if let Some(campuses) = self.load_sitemap(key).await? {
+            return Ok(campuses);
+        }
+        if fallback.exists() {
+            log::info!("Sitemap not in storage at '{}', using {:?}", key, fallback);
+            return Campus::load_all(fallback);
+        }
+        Err(AppError::config(format!(
+            "Sitemap not found in storage at '{}' or locally at {:?}; run `map` first",
+            key, fallback
+        )))
+    }
+}
+
 /// Trait for notice storage backends.
 #[async_trait]
 pub trait NoticeStorage: Send + Sync {
@@ -150,9 +254,194 @@ pub trait NoticeStorage: Send + Sync {
     /// Load archived notices for a specific month.
     async fn load_archive(&self, year: i32, month: u32) -> Result<Vec<NoticeOutput>>;
 
+    /// Load every stored notice dated in a month: its archive plus the hot
+    /// notices from that month not yet archived, archive order first.
+    async fn load_month(&self, year: i32, month: u32) -> Result<Vec<NoticeOutput>> {
+        let prefix = format!("{:04}-{:02}", year, month);
+        let mut notices = self.load_archive(year, month).await?;
+        let mut seen: HashSet<String> = notices.iter().map(|n| n.id.clone()).collect();
+        for notice in self.load_current().await? {
+            if notice.metadata.date.starts_with(&prefix) && seen.insert(notice.id.clone()) {
+                notices.push(notice);
+            }
+        }
+        Ok(notices)
+    }
+
+    /// Load the notices written by one crawl run: `version` as listed by
+    /// [`list_snapshots`](Self::list_snapshots), or `latest` for the newest
+    /// complete snapshot.
+    ///
+    /// Fails when the version is malformed or no snapshot is stored for it.
+    async fn load_version(&self, version: &str) -> Result<Vec<NoticeOutput>> {
+        let version = if version == "latest" {
+            self.list_snapshots()
+                .await?
+                .into_iter()
+                .find(|snapshot| snapshot.complete)
+                .map(|snapshot| snapshot.version)
+                .ok_or_else(|| AppError::validation("No snapshot stored yet"))?
+        } else {
+            NaiveDateTime::parse_from_str(version, SnapshotInfo::VERSION_FORMAT).map_err(|_| {
+                AppError::validation(format!(
+                    "Invalid snapshot version '{}', expected latest or a version like 20261015T094500Z",
+                    version
+                ))
+            })?;
+            version.to_string()
+        };
+        self.load_snapshot(&version).await?.ok_or_else(|| {
+            AppError::validation(format!("No snapshot stored for version '{}'", version))
+        })
+    }
+
+    /// Load the notices of the run snapshot `version`, or `None` when it is
+    /// not stored or unreadable.
+    async fn load_snapshot(&self, version: &str) -> Result<Option<Vec<NoticeOutput>>>;
+
+    /// List the snapshots of the recent crawl runs, newest first.
+    async fn list_snapshots(&self) -> Result<Vec<SnapshotInfo>>;
+
     /// Load the inverted index.
     async fn load_index(&self) -> Result<Option<InvertedIndex>>;
 
     /// Save the inverted index.
     async fn save_index(&self, index: &InvertedIndex) -> Result<()>;
+
+    /// Load the part of the index a search for `query` needs: with a sharded
+    /// index only the shards holding its tokens, otherwise the whole index.
+    async fn load_index_for_query(
+        &self,
+        query: &str,
+        config: &IndexConfig,
+    ) -> Result<Option<InvertedIndex>> {
+        let Some(manifest) = self.load_index_manifest().await? else {
+            return self.load_index().await;
+        };
+        let names: BTreeSet<String> = query_tokens(query, manifest.tokenize, config)
+            .iter()
+            .map(|token| manifest.shard_for(token))
+            .collect();
+        let mut shards = Vec::new();
+        for name in names {
+            shards.extend(self.load_index_shard(&name).await?);
+        }
+        Ok(Some(InvertedIndex::from_shards(&manifest, shards)))
+    }
+
+    /// Load the manifest of the sharded index.
+    async fn load_index_manifest(&self) -> Result<Option<IndexManifest>>;
+
+    /// Load a single index shard by name (see [`IndexManifest::shard_for`]).
+    async fn load_index_shard(&self, shard: &str) -> Result<Option<InvertedIndex>>;
+
+    /// Load the search index covering only the notices of `campus`.
+    async fn load_campus_index(&self, campus: &str) -> Result<Option<InvertedIndex>>;
+
+    /// Save the search index covering only the notices of `campus`.
+    async fn save_campus_index(&self, campus: &str, index: &InvertedIndex) -> Result<()>;
+
+    /// Save the notification diff artifact (full `DiffResult` with payloads)
+    /// and its compact ID-only summary.
+    async fn save_diff(&self, diff: &DiffResult) -> Result<()>;
+
+    /// Load the notification diff artifact.
+    async fn load_diff(&self) -> Result<Option<DiffResult>>;
+
+    /// Load the conditional-request cache (empty if none was saved).
+    async fn load_http_cache(&self) -> Result<HttpCache>;
+
+    /// Save the conditional-request cache for the next run.
+    async fn save_http_cache(&self, cache: &HttpCache) -> Result<()>;
+
+    /// Save the per-campus health report of the latest crawl.
+    async fn save_health_report(&self, report: &HealthReport) -> Result<()>;
+
+    /// Delete whole monthly archives, oldest first, until total usage is at
+    /// or under `max_bytes`. Hot data (`current.json`) is never pruned, and
+    /// nothing is deleted when the data outside archives alone exceeds
+    /// `max_bytes`.
+    async fn prune_to_size(&self, max_bytes: u64) -> Result<PruneResult>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct MemoryStorage(std::collections::HashMap<String, Vec<u8>>);
+
+    #[async_trait]
+    impl ByteReader for MemoryStorage {
+        async fn read_bytes(&self, key: &str) -> Result<Option<Vec<u8>>> {
+            Ok(self.0.get(key).cloned())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_load_config_from_storage_matches_file() {
+        let toml = r#"
+            [crawler]
+            max_concurrent = 3
+
+            [[campuses]]
+            name = "신촌캠퍼스"
+            url = "https://www.yonsei.ac.kr/sc/186/subview.do"
+
+            [[keywords]]
+            keyword = "공지"
+            id = "notice"
+            display_name = "일반공지"
+        "#;
+        let tmp = tempfile::TempDir::new().unwrap();
+        let path = tmp.path().join(Config::STORAGE_KEY);
+        std::fs::write(&path, toml).unwrap();
+
+        let memory =
+            MemoryStorage([(Config::STORAGE_KEY.to_string(), toml.as_bytes().to_vec())].into());
+
+        let from_file = Config::load(&path).unwrap();
+        let from_storage = memory.load_config(Config::STORAGE_KEY).await.unwrap();
+
+        assert_eq!(from_storage.crawler.max_concurrent, 3);
+        assert_eq!(
+            serde_json::to_value(&from_file).unwrap(),
+            serde_json::to_value(&from_storage).unwrap()
+        );
+        let empty = MemoryStorage(Default::default());
+        assert!(empty.load_config(Config::STORAGE_KEY).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_load_sitemap_from_storage_or_file() {
+        let campus = Campus {
+            campus: "TestCampus".to_string(),
+            colleges: vec![],
+            departments: vec![],
+            source_url: None,
+        };
+        let json = serde_json::to_vec(&vec![campus]).unwrap();
+        let memory = MemoryStorage([(Campus::STORAGE_KEY.to_string(), json.clone())].into());
+        let empty = MemoryStorage(Default::default());
+        let tmp = tempfile::TempDir::new().unwrap();
+        let fallback = tmp.path().join("siteMap.json");
+
+        let campuses = memory
+            .load_sitemap_or_file(Campus::STORAGE_KEY, &fallback)
+            .await
+            .unwrap();
+        assert_eq!(campuses[0].campus, "TestCampus");
+
+        let err = empty
+            .load_sitemap_or_file(Campus::STORAGE_KEY, &fallback)
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("Sitemap not found"));
+
+        std::fs::write(&fallback, &json).unwrap();
+        let campuses = empty
+            .load_sitemap_or_file(Campus::STORAGE_KEY, &fallback)
+            .await
+            .unwrap();
+        assert_eq!(campuses.len(), 1);
+    }
 }
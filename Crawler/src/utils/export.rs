@@ -0,0 +1,298 @@
+//! Export helpers for publishing notice snapshots.
+//!
+//! Produces files consumed outside the crawler, such as search-engine sitemaps
+//! and spreadsheet-friendly CSV.
+
+use chrono::NaiveDate;
+
+use crate::error::Result;
+use crate::models::NoticeOutput;
+
+/// Maximum URLs per sitemap file (sitemaps.org protocol limit).
+pub const SITEMAP_MAX_URLS: usize = 50_000;
+
+/// Maximum uncompressed size of a sitemap file in bytes.
+pub const SITEMAP_MAX_BYTES: usize = 50 * 1024 * 1024;
+
+/// File name of the root sitemap (or sitemap index when split).
+pub const SITEMAP_ROOT: &str = "sitemap.xml";
+
+/// File name of the CSV export.
+pub const CSV_FILE: &str = "notices.csv";
+
+/// File name of the JSON export.
+pub const JSON_FILE: &str = "notices.json";
+
+/// Column headers of the CSV export, in order.
+pub const CSV_COLUMNS: [&str; 9] = [
+    "campus",
+    "college",
+    "department_name",
+    "board_name",
+    "title",
+    "author",
+    "date",
+    "link",
+    "is_pinned",
+];
+
+const URLSET_OPEN: &str = concat!(
+    r#"<?xml version="1.0" encoding="UTF-8"?>"#,
+    "\n",
+    r#"<urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">"#,
+    "\n"
+);
+const URLSET_CLOSE: &str = "</urlset>\n";
+
+/// A generated export file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExportFile {
+    /// File name relative to the export directory
+    pub name: String,
+    /// File contents
+    pub contents: String,
+}
+
+/// Build a search-engine sitemap listing each notice's link.
+///
+/// Returns a single `sitemap.xml` when within protocol limits. Otherwise the
+/// URLs are split into `sitemap-N.xml` parts and `sitemap.xml` becomes a
+/// sitemap index referencing them under `base_url`.
+pub fn write_search_sitemap(notices: &[NoticeOutput], base_url: &str) -> Vec<ExportFile> {
+    build_sitemap_files(notices, base_url, SITEMAP_MAX_URLS, SITEMAP_MAX_BYTES)
+}
+
+fn build_sitemap_files(
+    notices: &[NoticeOutput],
+    base_url: &str,
+    max_urls: usize,
+    max_bytes: usize,
+) -> Vec<ExportFile> {
+    split_sitemap(notices, base_url, max_urls, max_bytes, |n| n.link.clone())
+}
+
+/// Build sitemap files with `loc` giving each notice's URL, splitting past
+/// `max_urls` or `max_bytes` per file as [`write_search_sitemap`] does.
+pub(crate) fn split_sitemap(
+    notices: &[NoticeOutput],
+    base_url: &str,
+    max_urls: usize,
+    max_bytes: usize,
+    loc: impl Fn(&NoticeOutput) -> String,
+) -> Vec<ExportFile> {
+    let budget = max_bytes.saturating_sub(URLSET_OPEN.len() + URLSET_CLOSE.len());
+
+    let mut parts: Vec<String> = Vec::new();
+    let mut body = String::new();
+    let mut count = 0;
+
+    for notice in notices {
+        let entry = url_entry(&loc(notice), notice);
+        if count > 0 && (count >= max_urls || body.len() + entry.len() > budget) {
+            parts.push(wrap_urlset(&body));
+            body.clear();
+            count = 0;
+        }
+        body.push_str(&entry);
+        count += 1;
+    }
+
+    if parts.is_empty() {
+        return vec![ExportFile {
+            name: SITEMAP_ROOT.to_string(),
+            contents: wrap_urlset(&body),
+        }];
+    }
+    parts.push(wrap_urlset(&body));
+
+    let base = base_url.trim_end_matches('/');
+    let mut index = String::from(concat!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>"#,
+        "\n",
+        r#"<sitemapindex xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">"#,
+        "\n"
+    ));
+    let mut files = Vec::with_capacity(parts.len() + 1);
+
+    for (i, contents) in parts.into_iter().enumerate() {
+        let name = format!("sitemap-{}.xml", i + 1);
+        index.push_str(&format!(
+            "  <sitemap><loc>{}</loc></sitemap>\n",
+            escape_xml(&format!("{}/{}", base, name))
+        ));
+        files.push(ExportFile { name, contents });
+    }
+    index.push_str("</sitemapindex>\n");
+
+    files.insert(
+        0,
+        ExportFile {
+            name: SITEMAP_ROOT.to_string(),
+            contents: index,
+        },
+    );
+    files
+}
+
+fn wrap_urlset(body: &str) -> String {
+    format!("{}{}{}", URLSET_OPEN, body, URLSET_CLOSE)
+}
+
+fn url_entry(loc: &str, notice: &NoticeOutput) -> String {
+    let loc = escape_xml(loc);
+    match NaiveDate::parse_from_str(&notice.metadata.date, "%Y-%m-%d") {
+        Ok(date) => format!(
+            "  <url><loc>{}</loc><lastmod>{}</lastmod></url>\n",
+            loc,
+            date.format("%Y-%m-%d")
+        ),
+        Err(_) => format!("  <url><loc>{}</loc></url>\n", loc),
+    }
+}
+
+/// Render notices as CSV with a header row ([`CSV_COLUMNS`]).
+///
+/// Fields containing commas, quotes or newlines are quoted. With `bom`, the
+/// output starts with a UTF-8 byte order mark so Excel detects the encoding
+/// of Korean text.
+pub fn to_csv(notices: &[NoticeOutput], bom: bool) -> Result<String> {
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    writer
+        .write_record(CSV_COLUMNS)
+        .map_err(std::io::Error::from)?;
+    for notice in notices {
+        let meta = &notice.metadata;
+        writer
+            .write_record([
+                meta.campus.as_str(),
+                meta.college.as_str(),
+                meta.department_name.as_str(),
+                meta.board_name.as_str(),
+                notice.title.as_str(),
+                meta.author.as_str(),
+                meta.date.as_str(),
+                notice.link.as_str(),
+                if meta.pinned { "true" } else { "false" },
+            ])
+            .map_err(std::io::Error::from)?;
+    }
+    let bytes = writer
+        .into_inner()
+        .map_err(|e| std::io::Error::other(e.to_string()))?;
+
+    let mut out = String::with_capacity(bytes.len() + 3);
+    if bom {
+        out.push('\u{feff}');
+    }
+    // Every field written is a `&str`, so the output is valid UTF-8
+    out.push_str(&String::from_utf8_lossy(&bytes));
+    Ok(out)
+}
+
+/// Escape the five XML special characters.
+pub fn escape_xml(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&apos;"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::test_fixtures::notice;
+
+    #[test]
+    fn test_csv_quotes_commas() {
+        let mut pinned = notice(1, "2026-02-01");
+        pinned.title = "장학금 신청, \"2차\" 안내".to_string();
+        pinned.metadata.author = "학생처, 장학팀".to_string();
+        pinned.metadata.pinned = true;
+        let csv = to_csv(&[pinned, notice(2, "2026-02-02")], false).unwrap();
+
+        let lines: Vec<_> = csv.lines().collect();
+        assert_eq!(
+            lines[0],
+            "campus,college,department_name,board_name,title,author,date,link,is_pinned"
+        );
+        assert_eq!(
+            lines[1],
+            r#"신촌캠퍼스,,학생처,공지,"장학금 신청, ""2차"" 안내","학생처, 장학팀",2026-02-01,https://example.com/view?id=1&page=1,true"#
+        );
+        assert!(lines[2].ends_with(",false"));
+        assert_eq!(lines.len(), 3);
+
+        let with_bom = to_csv(&[], true).unwrap();
+        assert!(with_bom.starts_with('\u{feff}'));
+    }
+
+    #[test]
+    fn test_sitemap_structure() {
+        let notices = vec![notice(1, "2026-02-01"), notice(2, "unknown")];
+        let files = write_search_sitemap(&notices, "https://notices.example.com");
+
+        assert_eq!(files.len(), 1);
+        assert_eq!(files[0].name, SITEMAP_ROOT);
+
+        let xml = &files[0].contents;
+        assert!(xml.starts_with(r#"<?xml version="1.0" encoding="UTF-8"?>"#));
+        assert!(xml.contains(r#"<urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">"#));
+        assert!(xml.contains(
+            "<url><loc>https://example.com/view?id=1&amp;page=1</loc><lastmod>2026-02-01</lastmod></url>"
+        ));
+        assert!(xml.contains("<url><loc>https://example.com/view?id=2&amp;page=1</loc></url>"));
+        assert!(xml.trim_end().ends_with("</urlset>"));
+    }
+
+    #[test]
+    fn test_sitemap_splits_into_index_past_url_limit() {
+        let notices: Vec<_> = (0..5).map(|i| notice(i, "2026-02-01")).collect();
+        let files = build_sitemap_files(
+            &notices,
+            "https://notices.example.com/",
+            2,
+            SITEMAP_MAX_BYTES,
+        );
+
+        let names: Vec<_> = files.iter().map(|f| f.name.as_str()).collect();
+        assert_eq!(
+            names,
+            [
+                "sitemap.xml",
+                "sitemap-1.xml",
+                "sitemap-2.xml",
+                "sitemap-3.xml"
+            ]
+        );
+
+        let index = &files[0].contents;
+        assert!(index.contains("<sitemapindex"));
+        assert!(index.contains("<loc>https://notices.example.com/sitemap-3.xml</loc>"));
+
+        let url_counts: Vec<_> = files[1..]
+            .iter()
+            .map(|f| f.contents.matches("<url>").count())
+            .collect();
+        assert_eq!(url_counts, [2, 2, 1]);
+    }
+
+    #[test]
+    fn test_sitemap_splits_on_byte_limit() {
+        let notices: Vec<_> = (0..4).map(|i| notice(i, "2026-02-01")).collect();
+        let entry_len = url_entry(&notices[0].link, &notices[0]).len();
+        let max_bytes = URLSET_OPEN.len() + URLSET_CLOSE.len() + entry_len * 2;
+
+        let files = build_sitemap_files(&notices, "https://notices.example.com", 100, max_bytes);
+
+        assert_eq!(files.len(), 3);
+        assert!(files[1..].iter().all(|f| f.contents.len() <= max_bytes));
+    }
+}
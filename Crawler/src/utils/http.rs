@@ -1,18 +1,127 @@
 //! HTTP client utilities.
 //!
 //! Provides functions to create a configured HTTP client and fetch web pages.
+//!
+//! Compressed responses are decoded here rather than by `reqwest`, so the fetch
+//! path can report both the wire size and the decoded size, and the body budget
+//! is enforced on the decoded bytes.
+//!
+//! HTML bodies are transcoded to UTF-8 from the charset named by the
+//! `Content-Type` header or a `<meta>` tag, so legacy EUC-KR/CP949 boards parse
+//! without mojibake.
 
-use std::time::Duration;
+use std::io::Read;
+use std::sync::{Arc, LazyLock};
+use std::time::{Duration, Instant};
 
+use chrono::{DateTime, Utc};
+use encoding_rs::{Encoding, UTF_8};
+use flate2::read::{DeflateDecoder, GzDecoder, ZlibDecoder};
+use regex::Regex;
+use reqwest::cookie::Jar;
 use reqwest::{StatusCode, header};
 use scraper::Html;
 
 use crate::error::{AppError, Result};
-use crate::models::CrawlerConfig;
+use crate::models::{Campus, CrawlerConfig, FetchConfig, HttpValidators};
+use crate::utils::region::{self, RegionSelector};
 
-/// Create a configured HTTP client.
+/// Create a configured HTTP client with an empty cookie store.
 pub fn create_client(config: &CrawlerConfig) -> Result<reqwest::Client> {
+    build_client(config, Arc::new(Jar::default()))
+}
+
+/// Create a crawl client whose cookie store is seeded with each board's
+/// [`FetchConfig::cookies`].
+///
+/// Seeded cookies are scoped to the board's host with `Path=/`; cookies the
+/// server sets later (e.g., a refreshed session) replace them in the store.
+/// Fails with a config error when a board's cookie string is not a valid
+/// header value.
+pub fn create_crawl_client(config: &CrawlerConfig, campuses: &[Campus]) -> Result<reqwest::Client> {
+    let jar = Jar::default();
+    for dept_ref in Campus::departments_of(campuses) {
+        for board in &dept_ref.dept.boards {
+            let Some(cookies) = &board.fetch.cookies else {
+                continue;
+            };
+            if header::HeaderValue::from_str(cookies).is_err() {
+                return Err(AppError::config(format!(
+                    "Invalid cookies for board '{}'",
+                    board.id
+                )));
+            }
+            let url = url::Url::parse(&board.url)?;
+            for pair in cookies.split(';').map(str::trim).filter(|p| !p.is_empty()) {
+                jar.add_cookie_str(&format!("{}; Path=/", pair), &url);
+            }
+        }
+    }
+    build_client(config, Arc::new(jar))
+}
+
+fn build_client(config: &CrawlerConfig, cookies: Arc<Jar>) -> Result<reqwest::Client> {
+    let mut builder = reqwest::Client::builder();
+    if let Some(proxy_url) = &config.proxy_url {
+        let proxy = reqwest::Proxy::all(proxy_url)
+            .map_err(|e| AppError::config(format!("Invalid proxy_url '{}': {}", proxy_url, e)))?
+            .no_proxy(reqwest::NoProxy::from_string(&config.no_proxy.join(",")));
+        builder = builder.proxy(proxy);
+    }
+
+    let client = builder
+        .default_headers(client_headers(config)?)
+        .timeout(Duration::from_secs(config.timeout_secs))
+        .connect_timeout(Duration::from_secs(config.timeout_secs.min(10)))
+        .pool_idle_timeout(Duration::from_secs(60))
+        .tcp_keepalive(Duration::from_secs(30))
+        .redirect(redirect_policy(config))
+        .dns_resolver(Arc::new(Resolver))
+        .cookie_provider(cookies)
+        .build()?;
+
+    Ok(client)
+}
+
+/// A host name that could not be resolved.
+///
+/// The crawl client's resolver returns this so error classification can
+/// tell DNS failures apart from other connect errors by type.
+#[derive(Debug, thiserror::Error)]
+#[error("failed to resolve '{host}'")]
+pub struct ResolveError {
+    pub host: String,
+    #[source]
+    pub source: std::io::Error,
+}
+
+/// System resolver that reports failures as [`ResolveError`].
+struct Resolver;
+
+impl reqwest::dns::Resolve for Resolver {
+    fn resolve(&self, name: reqwest::dns::Name) -> reqwest::dns::Resolving {
+        let host = name.as_str().to_string();
+        Box::pin(async move {
+            let lookup = tokio::net::lookup_host((host.as_str(), 0))
+                .await
+                .map(|addrs| addrs.collect::<Vec<_>>());
+            match lookup {
+                Ok(addrs) => Ok(Box::new(addrs.into_iter()) as reqwest::dns::Addrs),
+                Err(source) => Err(ResolveError { host, source }.into()),
+            }
+        })
+    }
+}
+
+/// Headers the crawl client sends with every request, including the
+/// configured `User-Agent`.
+fn client_headers(config: &CrawlerConfig) -> Result<header::HeaderMap> {
     let mut headers = header::HeaderMap::new();
+    headers.insert(
+        header::USER_AGENT,
+        header::HeaderValue::from_str(&config.user_agent)
+            .map_err(|_| AppError::config(format!("Invalid user_agent '{}'", config.user_agent)))?,
+    );
     headers.insert(
         header::ACCEPT,
         header::HeaderValue::from_static(
@@ -23,68 +132,1087 @@ pub fn create_client(config: &CrawlerConfig) -> Result<reqwest::Client> {
         header::ACCEPT_LANGUAGE,
         header::HeaderValue::from_static("ko-KR,ko;q=0.9,en-US;q=0.8,en;q=0.7"),
     );
+    headers.insert(
+        header::ACCEPT_ENCODING,
+        header::HeaderValue::from_static("gzip, deflate, br"),
+    );
+    Ok(headers)
+}
 
-    let client = reqwest::Client::builder()
-        .user_agent(&config.user_agent)
-        .default_headers(headers)
-        .timeout(Duration::from_secs(config.timeout_secs))
-        .connect_timeout(Duration::from_secs(config.timeout_secs.min(10)))
-        .pool_idle_timeout(Duration::from_secs(60))
-        .tcp_keepalive(Duration::from_secs(30))
-        .redirect(reqwest::redirect::Policy::limited(5))
-        .build()?;
+/// Reason a redirect was rejected by [`redirect_policy`].
+#[derive(Debug, thiserror::Error)]
+pub enum RedirectViolation {
+    #[error("more than {0} redirects")]
+    TooMany(usize),
+    #[error("redirect loop back to {0}")]
+    Loop(String),
+    #[error("off-domain redirect to {0}")]
+    OffDomain(String),
+}
 
-    Ok(client)
+/// Redirect policy enforcing `max_redirects`, loop detection and `allowed_domains`.
+fn redirect_policy(config: &CrawlerConfig) -> reqwest::redirect::Policy {
+    let max_redirects = config.max_redirects;
+    let allowed_domains: Vec<String> = config
+        .allowed_domains
+        .iter()
+        .map(|d| d.trim().trim_start_matches('.').to_ascii_lowercase())
+        .collect();
+
+    reqwest::redirect::Policy::custom(move |attempt| {
+        let target = attempt.url().to_string();
+        if attempt.previous().iter().any(|u| u.as_str() == target) {
+            return attempt.error(RedirectViolation::Loop(target));
+        }
+        if attempt.previous().len() > max_redirects {
+            return attempt.error(RedirectViolation::TooMany(max_redirects));
+        }
+        if !allowed_domains.is_empty() {
+            let host = attempt.url().host_str().unwrap_or("").to_ascii_lowercase();
+            let allowed = allowed_domains
+                .iter()
+                .any(|d| host == *d || host.ends_with(&format!(".{}", d)));
+            if !allowed {
+                return attempt.error(RedirectViolation::OffDomain(target));
+            }
+        }
+        attempt.follow()
+    })
+}
+
+/// Map a request error, surfacing redirect policy violations as `RedirectBlocked`.
+fn request_error(url: &str, error: reqwest::Error) -> AppError {
+    if !error.is_redirect() {
+        return AppError::Http(error);
+    }
+    let mut source = std::error::Error::source(&error);
+    while let Some(err) = source {
+        if let Some(violation) = err.downcast_ref::<RedirectViolation>() {
+            return AppError::RedirectBlocked {
+                url: url.to_string(),
+                reason: violation.to_string(),
+            };
+        }
+        source = err.source();
+    }
+    AppError::Http(error)
+}
+
+/// Budget guarding HTML parsing against pathological pages.
+#[derive(Debug, Clone, Copy)]
+pub struct PageLimits {
+    /// Maximum response body size in bytes
+    pub max_body_bytes: u64,
+    /// Maximum estimated element count (approximated by counting `<`)
+    pub max_nodes: usize,
+}
+
+impl PageLimits {
+    /// Build limits from crawler settings.
+    pub fn from_config(config: &CrawlerConfig) -> Self {
+        Self {
+            max_body_bytes: config.max_body_bytes,
+            max_nodes: config.max_html_nodes,
+        }
+    }
+}
+
+impl Default for PageLimits {
+    fn default() -> Self {
+        Self::from_config(&CrawlerConfig::default())
+    }
 }
 
 /// Fetch a page asynchronously and parse it as HTML.
 pub async fn fetch_page_async(client: &reqwest::Client, url: &str) -> Result<Html> {
-    let resp = client.get(url).send().await?;
+    fetch_page_with_limits(client, url, &PageLimits::default()).await
+}
+
+/// A fetched and parsed page with transfer measurements.
+pub struct FetchedPage {
+    /// Parsed document
+    pub document: Html,
+    /// Bytes received on the wire (compressed size for encoded responses)
+    pub wire_bytes: u64,
+    /// Bytes after content decoding, as checked against the body budget
+    pub decoded_bytes: u64,
+    /// `ETag` / `Last-Modified` of the response
+    pub validators: HttpValidators,
+    /// Time spent transcoding and parsing the body (part of the fetch call)
+    pub parse_time: Duration,
+}
+
+/// Result of a conditional fetch.
+pub enum ConditionalPage {
+    /// The server answered `304 Not Modified`
+    NotModified,
+    /// A fresh page
+    Modified(FetchedPage),
+}
+
+/// Fetch a page and parse it as HTML, rejecting bodies over the given budget.
+pub async fn fetch_page_with_limits(
+    client: &reqwest::Client,
+    url: &str,
+    limits: &PageLimits,
+) -> Result<Html> {
+    Ok(fetch_page_measured(client, url, limits).await?.document)
+}
+
+/// Fetch a page like [`fetch_page_with_limits`], also reporting byte counts.
+pub async fn fetch_page_measured(
+    client: &reqwest::Client,
+    url: &str,
+    limits: &PageLimits,
+) -> Result<FetchedPage> {
+    fetch_page_with_options(client, url, limits, &RequestOptions::default()).await
+}
+
+/// Fetch a page like [`fetch_page_measured`] with per-request `options`.
+pub async fn fetch_page_with_options(
+    client: &reqwest::Client,
+    url: &str,
+    limits: &PageLimits,
+    options: &RequestOptions,
+) -> Result<FetchedPage> {
+    let resp = send_checked(client, url, options, None).await?;
+    read_page(url, resp, limits, options).await
+}
+
+/// Per-request settings of a board fetch.
+#[derive(Debug, Clone, Default)]
+pub struct RequestOptions {
+    /// Headers sent with the request, replacing client defaults of the same name
+    pub headers: header::HeaderMap,
+    /// Log the request/response exchange at debug level
+    pub debug_http: bool,
+    /// Element to cut out of the page before parsing (default: the whole page)
+    pub content_root: Option<RegionSelector>,
+    /// The client's default headers, applied to the built request so the
+    /// debug log shows what is sent
+    client_headers: header::HeaderMap,
+}
+
+impl RequestOptions {
+    /// Options for fetching a board: its [`FetchConfig`] headers and the
+    /// crawler's `debug_http`.
+    pub fn for_board(fetch: &FetchConfig, config: &CrawlerConfig) -> Result<Self> {
+        Ok(Self {
+            headers: board_headers(fetch)?,
+            debug_http: config.debug_http,
+            client_headers: client_headers(config)?,
+            content_root: None,
+        })
+    }
+
+    /// Parse only the element matched by `root`, see [`parse_html_region`].
+    pub fn with_content_root(mut self, root: Option<RegionSelector>) -> Self {
+        self.content_root = root;
+        self
+    }
+
+    /// Build a GET request for `url`, sending `validators` as
+    /// `If-None-Match` / `If-Modified-Since`.
+    fn build_request(
+        &self,
+        client: &reqwest::Client,
+        url: &str,
+        validators: Option<&HttpValidators>,
+    ) -> Result<reqwest::Request> {
+        // Request headers replace client defaults of the same name
+        let mut request = client.get(url).headers(self.headers.clone());
+        if let Some(validators) = validators {
+            if let Some(etag) = &validators.etag {
+                request = request.header(header::IF_NONE_MATCH, etag);
+            }
+            if let Some(last_modified) = &validators.last_modified {
+                request = request.header(header::IF_MODIFIED_SINCE, last_modified);
+            }
+        }
+        let mut request = request.build()?;
+        // The client would add its defaults on `execute`; adding them here, the
+        // same way, lets the log show the headers actually sent
+        for (name, value) in &self.client_headers {
+            if let header::Entry::Vacant(entry) = request.headers_mut().entry(name) {
+                entry.insert(value.clone());
+            }
+        }
+        Ok(request)
+    }
+}
+
+/// Build the request headers for a board's [`FetchConfig`].
+///
+/// Fails with a config error naming the offending header when a name or
+/// value is not valid HTTP.
+pub fn board_headers(fetch: &FetchConfig) -> Result<header::HeaderMap> {
+    let invalid = |name: &str| AppError::config(format!("Invalid board header '{}'", name));
+    let mut headers = header::HeaderMap::new();
+    for (name, value) in &fetch.headers {
+        let header_name =
+            header::HeaderName::from_bytes(name.as_bytes()).map_err(|_| invalid(name))?;
+        let header_value = header::HeaderValue::from_str(value).map_err(|_| invalid(name))?;
+        headers.insert(header_name, header_value);
+    }
+    Ok(headers)
+}
+
+/// Fetch a page, sending `validators` as `If-None-Match` / `If-Modified-Since`.
+pub async fn fetch_page_conditional(
+    client: &reqwest::Client,
+    url: &str,
+    limits: &PageLimits,
+    validators: &HttpValidators,
+    options: &RequestOptions,
+) -> Result<ConditionalPage> {
+    match send_checked(client, url, options, Some(validators)).await {
+        Ok(resp) => Ok(ConditionalPage::Modified(
+            read_page(url, resp, limits, options).await?,
+        )),
+        Err(AppError::UpstreamNotModified { .. }) => Ok(ConditionalPage::NotModified),
+        Err(e) => Err(e),
+    }
+}
+
+/// Check, read and parse an HTML response.
+async fn read_page(
+    url: &str,
+    resp: reqwest::Response,
+    limits: &PageLimits,
+    options: &RequestOptions,
+) -> Result<FetchedPage> {
+    let validators = response_validators(&resp);
+
+    // Check Content-Type (prevent non-HTML responses)
+    let content_type = resp
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .map(|ct| ct.to_str().unwrap_or("").to_string());
+    if let Some(ct) = &content_type
+        && !ct.contains("text/html")
+        && !ct.contains("application/xhtml+xml")
+    {
+        return Err(AppError::UpstreamUnexpectedContentType {
+            url: url.to_string(),
+            content_type: ct.clone(),
+        });
+    }
+
+    let (raw_len, decoded) = read_body(url, resp, limits.max_body_bytes).await?;
+    let parse_started = Instant::now();
+    let text = decode_html(&decoded, content_type.as_deref());
+    let document = parse_html_region(url, &text, options.content_root.as_ref(), limits)?;
+
+    Ok(FetchedPage {
+        document,
+        wire_bytes: raw_len,
+        decoded_bytes: decoded.len() as u64,
+        validators,
+        parse_time: parse_started.elapsed(),
+    })
+}
+
+/// A fetched RSS/Atom feed, transcoded to UTF-8.
+pub struct FetchedFeed {
+    /// Feed document text
+    pub text: String,
+    /// Bytes received on the wire (compressed size for encoded responses)
+    pub wire_bytes: u64,
+    /// Bytes after content decoding, as checked against the body budget
+    pub decoded_bytes: u64,
+    /// `ETag` / `Last-Modified` of the response
+    pub validators: HttpValidators,
+}
+
+/// Result of a conditional feed fetch.
+pub enum ConditionalFeed {
+    /// The server answered `304 Not Modified`
+    NotModified,
+    /// A fresh feed
+    Modified(FetchedFeed),
+}
+
+/// Fetch an RSS/Atom feed with per-request `options`.
+///
+/// When `validators` are given they are sent as `If-None-Match` /
+/// `If-Modified-Since`. The body is decoded with [`decode_xml`].
+pub async fn fetch_feed(
+    client: &reqwest::Client,
+    url: &str,
+    limits: &PageLimits,
+    validators: Option<&HttpValidators>,
+    options: &RequestOptions,
+) -> Result<ConditionalFeed> {
+    let resp = match send_checked(client, url, options, validators).await {
+        Ok(resp) => resp,
+        Err(AppError::UpstreamNotModified { .. }) => return Ok(ConditionalFeed::NotModified),
+        Err(e) => return Err(e),
+    };
+    let validators = response_validators(&resp);
+    let content_type = resp
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|ct| ct.to_str().ok())
+        .map(str::to_string);
+    let (raw_len, decoded) = read_body(url, resp, limits.max_body_bytes).await?;
+    Ok(ConditionalFeed::Modified(FetchedFeed {
+        text: decode_xml(&decoded, content_type.as_deref()),
+        wire_bytes: raw_len,
+        decoded_bytes: decoded.len() as u64,
+        validators,
+    }))
+}
+
+/// `ETag` / `Last-Modified` of a response.
+fn response_validators(resp: &reqwest::Response) -> HttpValidators {
+    let header_value = |name| {
+        resp.headers()
+            .get(name)
+            .and_then(|v| v.to_str().ok())
+            .map(str::to_string)
+    };
+    HttpValidators {
+        etag: header_value(header::ETAG),
+        last_modified: header_value(header::LAST_MODIFIED),
+    }
+}
+
+/// Fetch a response body of any content type, decoded and size-checked.
+pub async fn fetch_bytes(client: &reqwest::Client, url: &str, max_bytes: u64) -> Result<Vec<u8>> {
+    let resp = send_checked(client, url, &RequestOptions::default(), None).await?;
+    Ok(read_body(url, resp, max_bytes).await?.1)
+}
+
+/// Send a GET request, mapping 304 and non-success statuses to errors.
+async fn send_checked(
+    client: &reqwest::Client,
+    url: &str,
+    options: &RequestOptions,
+    validators: Option<&HttpValidators>,
+) -> Result<reqwest::Response> {
+    let request = options.build_request(client, url, validators)?;
+    let request_headers = options.debug_http.then(|| request.headers().clone());
+    let resp = client
+        .execute(request)
+        .await
+        .map_err(|e| request_error(url, e))?;
+
+    if let Some(request_headers) = request_headers {
+        log::debug!(
+            "{}",
+            exchange_record(url, &request_headers, resp.status(), resp.headers())
+        );
+    }
 
     // Process http response
     let status = resp.status();
     if status == StatusCode::NOT_MODIFIED {
         return Err(AppError::UpstreamNotModified {
             url: url.to_string(),
-        }
-        .into());
+        });
     }
 
     if !status.is_success() {
+        let retry_after = matches!(
+            status,
+            StatusCode::TOO_MANY_REQUESTS | StatusCode::SERVICE_UNAVAILABLE
+        )
+        .then(|| resp.headers().get(header::RETRY_AFTER))
+        .flatten()
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| parse_retry_after(value, Utc::now()));
         return Err(AppError::UpstreamHttp {
             url: url.to_string(),
             status: status.as_u16(),
+            retry_after,
+        });
+    }
+    Ok(resp)
+}
+
+/// Parse a `Retry-After` value, either delay seconds or an HTTP date.
+///
+/// A date in the past yields a zero delay.
+fn parse_retry_after(value: &str, now: DateTime<Utc>) -> Option<Duration> {
+    let value = value.trim();
+    if let Ok(secs) = value.parse::<u64>() {
+        return Some(Duration::from_secs(secs));
+    }
+    let date = DateTime::parse_from_rfc2822(value).ok()?;
+    Some(
+        (date.with_timezone(&Utc) - now)
+            .to_std()
+            .unwrap_or_default(),
+    )
+}
+
+/// Headers whose values never appear in debug logs.
+const REDACTED_HEADERS: [header::HeaderName; 4] = [
+    header::AUTHORIZATION,
+    header::PROXY_AUTHORIZATION,
+    header::COOKIE,
+    header::SET_COOKIE,
+];
+
+/// Response headers included in debug logs besides redacted ones.
+const LOGGED_RESPONSE_HEADERS: [header::HeaderName; 3] =
+    [header::CONTENT_TYPE, header::CONTENT_LENGTH, header::ETAG];
+
+/// One-line description of a GET exchange for `debug_http` logging.
+///
+/// Request headers are listed in full and response headers are limited to
+/// [`LOGGED_RESPONSE_HEADERS`]; credentials and cookies show as `[redacted]`.
+fn exchange_record(
+    url: &str,
+    request: &header::HeaderMap,
+    status: StatusCode,
+    response: &header::HeaderMap,
+) -> String {
+    let response_headers = response.iter().filter(|(name, _)| {
+        LOGGED_RESPONSE_HEADERS.contains(name) || REDACTED_HEADERS.contains(name)
+    });
+    format!(
+        "GET {} -> {} | request [{}] | response [{}]",
+        url,
+        status.as_u16(),
+        describe_headers(request.iter()),
+        describe_headers(response_headers)
+    )
+}
+
+fn describe_headers<'a>(
+    headers: impl Iterator<Item = (&'a header::HeaderName, &'a header::HeaderValue)>,
+) -> String {
+    headers
+        .map(|(name, value)| {
+            let value = if REDACTED_HEADERS.contains(name) {
+                "[redacted]"
+            } else {
+                value.to_str().unwrap_or("<binary>")
+            };
+            format!("{}: {}", name, value)
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Read and decode a response body, returning its wire size and decoded bytes.
+async fn read_body(url: &str, resp: reqwest::Response, max_bytes: u64) -> Result<(u64, Vec<u8>)> {
+    // Check content-length to prevent large responses (error pages/file downloads).
+    // This is the wire size; the decoded size is checked again below.
+    if let Some(len) = resp.content_length()
+        && len > max_bytes
+    {
+        return Err(AppError::UpstreamBodyTooLarge {
+            url: url.to_string(),
+            bytes: len,
+            max_bytes,
+        });
+    }
+
+    let encoding = resp
+        .headers()
+        .get(header::CONTENT_ENCODING)
+        .and_then(|v| v.to_str().ok())
+        .map(|v| v.trim().to_ascii_lowercase());
+
+    let raw = resp.bytes().await?;
+    let decoded = decode_body(url, &raw, encoding.as_deref(), max_bytes)?;
+    Ok((raw.len() as u64, decoded))
+}
+
+/// Transcode an HTML body to UTF-8.
+///
+/// The charset comes from the `Content-Type` header, then a `<meta charset>` or
+/// `<meta http-equiv="Content-Type">` tag in the first 1024 bytes, defaulting
+/// to UTF-8. A byte order mark overrides both. Unknown labels fall back to
+/// UTF-8; malformed sequences become U+FFFD.
+pub fn decode_html(raw: &[u8], content_type: Option<&str>) -> String {
+    let encoding = content_type
+        .and_then(charset_label)
+        .or_else(|| meta_charset(raw))
+        .and_then(|label| Encoding::for_label(label.as_bytes()))
+        .unwrap_or(UTF_8);
+    let (text, _, _) = encoding.decode(raw);
+    text.into_owned()
+}
+
+/// Transcode an XML (RSS/Atom) body to UTF-8.
+///
+/// Like [`decode_html`], but the fallback after the `Content-Type` charset is
+/// the `encoding` attribute of the `<?xml ?>` declaration.
+pub fn decode_xml(raw: &[u8], content_type: Option<&str>) -> String {
+    static XML_ENCODING: LazyLock<Regex> = LazyLock::new(|| {
+        Regex::new(r#"^\s*<\?xml[^>]*\sencoding\s*=\s*["']([A-Za-z0-9_.:-]+)"#).unwrap()
+    });
+    let declared = || {
+        let head = String::from_utf8_lossy(&raw[..raw.len().min(256)]);
+        let head = head.trim_start_matches('\u{feff}');
+        XML_ENCODING.captures(head).map(|caps| caps[1].to_string())
+    };
+    let encoding = content_type
+        .and_then(charset_label)
+        .or_else(declared)
+        .and_then(|label| Encoding::for_label(label.as_bytes()))
+        .unwrap_or(UTF_8);
+    let (text, _, _) = encoding.decode(raw);
+    text.into_owned()
+}
+
+/// `charset` parameter of a `Content-Type` value.
+fn charset_label(content_type: &str) -> Option<String> {
+    content_type.split(';').skip(1).find_map(|param| {
+        let (name, value) = param.split_once('=')?;
+        name.trim()
+            .eq_ignore_ascii_case("charset")
+            .then(|| value.trim().trim_matches(['"', '\'']).to_string())
+    })
+}
+
+/// Charset declared by a `<meta>` tag near the start of an HTML document.
+fn meta_charset(raw: &[u8]) -> Option<String> {
+    static META_CHARSET: LazyLock<Regex> = LazyLock::new(|| {
+        Regex::new(r#"(?i)<meta[^>]+charset\s*=\s*["']?([A-Za-z0-9_.:-]+)"#).unwrap()
+    });
+    let head = String::from_utf8_lossy(&raw[..raw.len().min(1024)]);
+    META_CHARSET.captures(&head).map(|caps| caps[1].to_string())
+}
+
+/// Internal buffer size of the brotli decoder.
+const BROTLI_BUFFER_SIZE: usize = 4096;
+
+/// Decode a response body according to its `Content-Encoding`.
+///
+/// Decoding stops one byte past `max_bytes`, so compression bombs are caught
+/// without inflating them fully.
+pub fn decode_body(
+    url: &str,
+    raw: &[u8],
+    encoding: Option<&str>,
+    max_bytes: u64,
+) -> Result<Vec<u8>> {
+    let undecodable = || AppError::UpstreamUndecodable {
+        url: url.to_string(),
+        encoding: encoding.unwrap_or_default().to_string(),
+    };
+    let read_limited = |reader: &mut dyn Read| -> std::io::Result<Vec<u8>> {
+        let mut out = Vec::new();
+        reader.take(max_bytes + 1).read_to_end(&mut out)?;
+        Ok(out)
+    };
+
+    let decoded = match encoding {
+        None | Some("") | Some("identity") => raw.to_vec(),
+        Some("gzip") | Some("x-gzip") => {
+            read_limited(&mut GzDecoder::new(raw)).map_err(|_| undecodable())?
         }
-        .into());
+        // "deflate" is specified as zlib-wrapped, but some servers send raw deflate
+        Some("deflate") => read_limited(&mut ZlibDecoder::new(raw))
+            .or_else(|_| read_limited(&mut DeflateDecoder::new(raw)))
+            .map_err(|_| undecodable())?,
+        Some("br") => read_limited(&mut brotli::Decompressor::new(raw, BROTLI_BUFFER_SIZE))
+            .map_err(|_| undecodable())?,
+        Some(_) => return Err(undecodable()),
+    };
+
+    if decoded.len() as u64 > max_bytes {
+        return Err(AppError::UpstreamBodyTooLarge {
+            url: url.to_string(),
+            bytes: decoded.len() as u64,
+            max_bytes,
+        });
     }
+    Ok(decoded)
+}
 
-    // Check Content-Type (prevent non-HTML responses)
-    if let Some(ct) = resp.headers().get(header::CONTENT_TYPE) {
-        let ct = ct.to_str().unwrap_or("");
-        if !ct.contains("text/html") && !ct.contains("application/xhtml+xml") {
-            return Err(AppError::UpstreamUnexpectedContentType {
-                url: url.to_string(),
-                content_type: ct.to_string(),
+/// Parse an HTML body after checking it against the size and node budget.
+///
+/// Content-Length can be absent or wrong (chunked/compressed responses),
+/// so the decoded body is measured again before the full parse.
+pub fn parse_html_body(url: &str, body: &str, limits: &PageLimits) -> Result<Html> {
+    check_page_budget(url, body, limits)?;
+    Ok(Html::parse_document(body))
+}
+
+/// Parse only the element of an HTML body matched by `root`.
+///
+/// The element is cut out of the raw text (see [`region::slice`]), so the
+/// rest of the page is never parsed and only the element counts against the
+/// node budget; the byte budget still covers the whole body. Falls back to
+/// the whole document when `root` is unset or matches nothing.
+pub fn parse_html_region(
+    url: &str,
+    body: &str,
+    root: Option<&RegionSelector>,
+    limits: &PageLimits,
+) -> Result<Html> {
+    let Some(root) = root else {
+        return parse_html_body(url, body, limits);
+    };
+    let Some(fragment) = region::slice(body, root) else {
+        log::debug!(
+            "Content root {} not found in {}, parsing full document",
+            root,
+            url
+        );
+        return parse_html_body(url, body, limits);
+    };
+    check_body_budget(url, body, limits)?;
+    check_node_budget(url, fragment, limits)?;
+    Ok(Html::parse_fragment(fragment))
+}
+
+fn check_page_budget(url: &str, body: &str, limits: &PageLimits) -> Result<()> {
+    check_body_budget(url, body, limits)?;
+    check_node_budget(url, body, limits)
+}
+
+fn check_body_budget(url: &str, body: &str, limits: &PageLimits) -> Result<()> {
+    let bytes = body.len() as u64;
+    if bytes > limits.max_body_bytes {
+        return Err(AppError::UpstreamBodyTooLarge {
+            url: url.to_string(),
+            bytes,
+            max_bytes: limits.max_body_bytes,
+        });
+    }
+    Ok(())
+}
+
+/// Estimate the node count of `html` from its tag openers and reject it
+/// when over budget.
+fn check_node_budget(url: &str, html: &str, limits: &PageLimits) -> Result<()> {
+    let nodes = html.bytes().filter(|&b| b == b'<').count();
+    if nodes > limits.max_nodes {
+        return Err(AppError::UpstreamTooComplex {
+            url: url.to_string(),
+            nodes,
+            max_nodes: limits.max_nodes,
+        });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use flate2::{Compression, write::GzEncoder};
+
+    use super::*;
+    use crate::utils::test_server::{TestResponse, TestServer};
+
+    fn gzip(bytes: &[u8]) -> Vec<u8> {
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(bytes).unwrap();
+        encoder.finish().unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_proxy_routes_requests() {
+        let proxy = TestServer::start(|_| TestResponse::html("<p>via proxy</p>"));
+        let config = CrawlerConfig {
+            proxy_url: Some(proxy.url("")),
+            no_proxy: vec!["direct.example.com".to_string()],
+            ..CrawlerConfig::default()
+        };
+        let client = create_client(&config).unwrap();
+
+        fetch_page_async(&client, "http://campus.example.com/notice")
+            .await
+            .unwrap();
+        let requests = proxy.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].path, "http://campus.example.com/notice");
+        assert_eq!(requests[0].headers["host"], "campus.example.com");
+    }
+
+    #[test]
+    fn test_invalid_proxy_url_is_config_error() {
+        let config = CrawlerConfig {
+            proxy_url: Some("not a url".to_string()),
+            ..CrawlerConfig::default()
+        };
+        let err = create_client(&config).unwrap_err();
+        assert!(matches!(err, AppError::Config(_)), "{:?}", err);
+    }
+
+    #[tokio::test]
+    async fn test_connect_failures_are_classified_by_type() {
+        use crate::models::CrawlErrorCode;
+
+        let client = create_client(&CrawlerConfig::default()).unwrap();
+        let code = |err: AppError| CrawlErrorCode::from_error(&err);
+
+        let err = fetch_page_async(&client, "http://unresolvable.invalid/")
+            .await
+            .unwrap_err();
+        assert_eq!(code(err), CrawlErrorCode::Dns);
+
+        // A plain HTTP reply where the TLS ServerHello should be
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let _ = stream.write_all(b"HTTP/1.1 400 Bad Request\r\n\r\n");
+        });
+        let err = fetch_page_async(&client, &format!("https://{addr}/"))
+            .await
+            .unwrap_err();
+        assert_eq!(code(err), CrawlErrorCode::Tls);
+
+        let port = std::net::TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap()
+            .port();
+        let err = fetch_page_async(&client, &format!("http://127.0.0.1:{port}/"))
+            .await
+            .unwrap_err();
+        assert_eq!(code(err), CrawlErrorCode::Connect);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_gzip_records_wire_and_decoded_bytes() {
+        let html = format!(
+            "<html><body><p id=\"msg\">hello</p>{}</body></html>",
+            "<!-- padding -->".repeat(200)
+        );
+        let compressed = gzip(html.as_bytes());
+        let wire_len = compressed.len() as u64;
+        let server = TestServer::start(move |_| {
+            TestResponse::html(compressed.clone()).header("Content-Encoding", "gzip")
+        });
+
+        let client = create_client(&CrawlerConfig::default()).unwrap();
+        let page = fetch_page_measured(&client, &server.url("/board"), &PageLimits::default())
+            .await
+            .unwrap();
+
+        let selector = scraper::Selector::parse("#msg").unwrap();
+        let text: String = page
+            .document
+            .select(&selector)
+            .next()
+            .unwrap()
+            .text()
+            .collect();
+        assert_eq!(text, "hello");
+        assert_eq!(page.wire_bytes, wire_len);
+        assert_eq!(page.decoded_bytes, html.len() as u64);
+        assert!(page.wire_bytes < page.decoded_bytes);
+
+        let request = &server.requests()[0];
+        assert_eq!(request.path, "/board");
+        assert!(request.headers["accept-encoding"].contains("gzip"));
+    }
+
+    fn redirect(location: &str) -> TestResponse {
+        TestResponse {
+            status: 302,
+            headers: vec![("Location".to_string(), location.to_string())],
+            body: Vec::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_redirect_loop_is_rejected() {
+        let server = TestServer::start(|req| match req.path.as_str() {
+            "/a" => redirect("/b"),
+            _ => redirect("/a"),
+        });
+        let client = create_client(&CrawlerConfig::default()).unwrap();
+
+        let err = fetch_page_async(&client, &server.url("/a"))
+            .await
+            .err()
+            .unwrap();
+        match err {
+            AppError::RedirectBlocked { reason, .. } => {
+                assert!(reason.contains("loop"), "{reason}")
             }
-            .into());
+            other => panic!("unexpected error: {other}"),
         }
+        assert_eq!(server.requests().len(), 2);
     }
 
-    // Size limit (operational stability) - consider moving to config if needed
-    // reqwest reads the full body by default, so read as text first
-    // Check content-length to prevent large responses (error pages/file downloads).
-    if let Some(len) = resp.content_length() {
-        let max = 2_000_000u64; // 2MB For example
-        if len > max {
-            return Err(AppError::UpstreamBodyTooLarge {
-                url: url.to_string(),
-                bytes: len,
-                max_bytes: max,
+    #[tokio::test]
+    async fn test_redirect_chain_limit() {
+        let server = TestServer::start(|req| {
+            let n: usize = req.path.trim_start_matches('/').parse().unwrap_or(0);
+            redirect(&format!("/{}", n + 1))
+        });
+        let config = CrawlerConfig {
+            max_redirects: 2,
+            ..CrawlerConfig::default()
+        };
+        let client = create_client(&config).unwrap();
+
+        let err = fetch_page_async(&client, &server.url("/0"))
+            .await
+            .err()
+            .unwrap();
+        assert!(
+            matches!(err, AppError::RedirectBlocked { ref reason, .. } if reason.contains("more than 2"))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_off_domain_redirect_is_blocked() {
+        let server = TestServer::start(|_| redirect("http://offcampus.example.com/board"));
+        let config = CrawlerConfig {
+            allowed_domains: vec!["127.0.0.1".to_string()],
+            ..CrawlerConfig::default()
+        };
+        let client = create_client(&config).unwrap();
+
+        let err = fetch_page_async(&client, &server.url("/board"))
+            .await
+            .err()
+            .unwrap();
+        match err {
+            AppError::RedirectBlocked { url, reason } => {
+                assert!(url.ends_with("/board"));
+                assert!(reason.contains("offcampus.example.com"));
+            }
+            other => panic!("unexpected error: {other}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_fetch_decodes_euc_kr_page() {
+        let (body, _, _) = encoding_rs::EUC_KR
+            .encode(r#"<html><body><p id="title">장학금 신청 안내</p></body></html>"#);
+        let body = body.into_owned();
+        let server = TestServer::start(move |_| TestResponse {
+            status: 200,
+            headers: vec![("Content-Type".into(), "text/html; charset=euc-kr".into())],
+            body: body.clone(),
+        });
+
+        let document = fetch_page_async(&reqwest::Client::new(), &server.url("/"))
+            .await
+            .unwrap();
+        let selector = scraper::Selector::parse("#title").unwrap();
+        let title: String = document.select(&selector).next().unwrap().text().collect();
+        assert_eq!(title, "장학금 신청 안내");
+    }
+
+    #[test]
+    fn test_decode_html_charset_sources() {
+        let (euc_kr, _, _) = encoding_rs::EUC_KR.encode("공지사항");
+        let with_meta = [
+            br#"<html><head><meta http-equiv="Content-Type" content="text/html; charset=EUC-KR">"#
+                .as_slice(),
+            &euc_kr,
+        ]
+        .concat();
+        assert!(decode_html(&with_meta, Some("text/html")).ends_with("공지사항"));
+
+        // The header wins over a conflicting meta tag
+        let utf8 = r#"<meta charset="euc-kr">공지사항"#.as_bytes();
+        assert!(decode_html(utf8, Some("text/html; charset=UTF-8")).ends_with("공지사항"));
+
+        assert_eq!(decode_html("공지사항".as_bytes(), None), "공지사항");
+    }
+
+    #[test]
+    fn test_decode_xml_uses_declared_encoding() {
+        let (euc_kr, _, _) = encoding_rs::EUC_KR.encode("공지사항");
+        let feed = [
+            br#"<?xml version="1.0" encoding="EUC-KR"?><title>"#.as_slice(),
+            &euc_kr,
+        ]
+        .concat();
+        assert!(decode_xml(&feed, Some("application/rss+xml")).ends_with("공지사항"));
+        assert!(decode_xml("공지사항".as_bytes(), None).ends_with("공지사항"));
+    }
+
+    #[test]
+    fn test_decode_body_limits_decoded_size() {
+        let compressed = gzip(&vec![b'a'; 10_000]);
+        let err = decode_body("https://example.com", &compressed, Some("gzip"), 1_000).unwrap_err();
+        assert!(matches!(
+            err,
+            AppError::UpstreamBodyTooLarge {
+                bytes: 1_001,
+                max_bytes: 1_000,
+                ..
             }
-            .into());
+        ));
+    }
+
+    #[test]
+    fn test_decode_body_rejects_unknown_encoding() {
+        let err = decode_body("https://example.com", b"data", Some("zstd"), 1_000).unwrap_err();
+        assert!(matches!(err, AppError::UpstreamUndecodable { .. }));
+    }
+
+    #[test]
+    fn test_decode_body_brotli() {
+        let html = "<table><tr><td>장학금 안내</td></tr></table>".repeat(20);
+        let mut compressed = Vec::new();
+        {
+            let mut writer = brotli::CompressorWriter::new(&mut compressed, 4096, 5, 22);
+            writer.write_all(html.as_bytes()).unwrap();
         }
+
+        let decoded = decode_body("https://example.com", &compressed, Some("br"), 10_000).unwrap();
+        assert_eq!(decoded, html.as_bytes());
+
+        let err = decode_body("https://example.com", &compressed, Some("br"), 100).unwrap_err();
+        assert!(matches!(err, AppError::UpstreamBodyTooLarge { .. }));
+        let err = decode_body("https://example.com", b"not brotli", Some("br"), 100).unwrap_err();
+        assert!(matches!(err, AppError::UpstreamUndecodable { .. }));
+    }
+
+    #[test]
+    fn test_parse_retry_after() {
+        let now = DateTime::parse_from_rfc3339("2015-10-21T07:28:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        assert_eq!(
+            parse_retry_after("120", now),
+            Some(Duration::from_secs(120))
+        );
+        assert_eq!(
+            parse_retry_after("Wed, 21 Oct 2015 07:28:30 GMT", now),
+            Some(Duration::from_secs(30))
+        );
+        assert_eq!(
+            parse_retry_after("Wed, 21 Oct 2015 07:00:00 GMT", now),
+            Some(Duration::ZERO)
+        );
+        assert_eq!(parse_retry_after("soon", now), None);
+    }
+
+    #[tokio::test]
+    async fn test_retry_after_kept_on_503() {
+        let server = TestServer::start(|req| TestResponse {
+            status: if req.path == "/busy" { 503 } else { 404 },
+            ..TestResponse::html("").header("Retry-After", "7")
+        });
+        let client = reqwest::Client::new();
+
+        let err = fetch_bytes(&client, &server.url("/busy"), 1_000)
+            .await
+            .unwrap_err();
+        assert_eq!(err.retry_after(), Some(Duration::from_secs(7)));
+        let err = fetch_bytes(&client, &server.url("/missing"), 1_000)
+            .await
+            .unwrap_err();
+        assert_eq!(err.retry_after(), None);
+    }
+
+    #[test]
+    fn test_built_request_includes_client_headers() {
+        let config = CrawlerConfig::default();
+        let fetch = FetchConfig {
+            headers: [("Accept".to_string(), "application/json".to_string())].into(),
+            cookies: None,
+        };
+        let options = RequestOptions::for_board(&fetch, &config).unwrap();
+        let client = create_client(&config).unwrap();
+
+        let request = options
+            .build_request(&client, "https://example.com/notice", None)
+            .unwrap();
+        let headers = request.headers();
+        assert_eq!(headers[header::USER_AGENT], config.user_agent.as_str());
+        assert_eq!(headers[header::ACCEPT], "application/json");
+        assert!(headers.contains_key(header::ACCEPT_LANGUAGE));
     }
 
-    let text = resp.text().await?;
-    Ok(Html::parse_document(&text))
+    #[test]
+    fn test_exchange_record_redacts_credentials() {
+        let mut request = header::HeaderMap::new();
+        request.insert(
+            header::AUTHORIZATION,
+            "Bearer secret-token".parse().unwrap(),
+        );
+        request.insert(header::COOKIE, "session=abc123".parse().unwrap());
+        request.insert(header::ACCEPT, "text/html".parse().unwrap());
+
+        let mut response = header::HeaderMap::new();
+        response.insert(header::CONTENT_TYPE, "text/html".parse().unwrap());
+        response.insert(header::ETAG, "\"v1\"".parse().unwrap());
+        response.insert(header::SET_COOKIE, "session=def456".parse().unwrap());
+        response.insert(header::SERVER, "nginx".parse().unwrap());
+
+        let record = exchange_record(
+            "https://example.com/notice",
+            &request,
+            StatusCode::OK,
+            &response,
+        );
+
+        assert!(record.starts_with("GET https://example.com/notice -> 200"));
+        for secret in ["secret-token", "abc123", "def456"] {
+            assert!(!record.contains(secret), "{} leaked: {}", secret, record);
+        }
+        assert!(record.contains("authorization: [redacted]"));
+        assert!(record.contains("set-cookie: [redacted]"));
+        assert!(record.contains("accept: text/html"));
+        assert!(record.contains("content-type: text/html"));
+        assert!(record.contains("etag: \"v1\""));
+        assert!(!record.contains("nginx"));
+    }
+
+    #[test]
+    fn test_parse_html_body_within_limits() {
+        let limits = PageLimits::default();
+        let document =
+            parse_html_body("https://example.com", "<html><p>hello</p></html>", &limits).unwrap();
+        assert!(document.html().contains("hello"));
+    }
+
+    #[test]
+    fn test_parse_html_body_rejects_oversized() {
+        let limits = PageLimits {
+            max_body_bytes: 16,
+            max_nodes: 1_000,
+        };
+        let err = parse_html_body("https://example.com", &"x".repeat(32), &limits).unwrap_err();
+        assert!(matches!(
+            err,
+            AppError::UpstreamBodyTooLarge { bytes: 32, .. }
+        ));
+        assert!(!err.is_retryable());
+    }
+
+    #[test]
+    fn test_parse_html_body_rejects_too_many_nodes() {
+        let limits = PageLimits {
+            max_body_bytes: 1_000_000,
+            max_nodes: 10,
+        };
+        let body = "<div>".repeat(20);
+        let err = parse_html_body("https://example.com", &body, &limits).unwrap_err();
+        assert!(matches!(
+            err,
+            AppError::UpstreamTooComplex { nodes: 20, .. }
+        ));
+        assert!(!err.is_retryable());
+    }
+
+    #[test]
+    fn test_parse_html_region_budgets_only_the_region() {
+        let limits = PageLimits {
+            max_body_bytes: 1_000_000,
+            max_nodes: 10,
+        };
+        let body = format!(
+            "{}<table id=\"board\"><tr><td>row</td></tr></table>",
+            "<div></div>".repeat(20)
+        );
+        let root = RegionSelector::parse("#board").unwrap();
+        let document =
+            parse_html_region("https://example.com", &body, Some(&root), &limits).unwrap();
+        let cells = scraper::Selector::parse("td").unwrap();
+        assert_eq!(document.select(&cells).count(), 1);
+
+        // Without a root the whole page counts
+        let err = parse_html_region("https://example.com", &body, None, &limits).unwrap_err();
+        assert!(matches!(err, AppError::UpstreamTooComplex { .. }));
+    }
 }
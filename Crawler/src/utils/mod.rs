@@ -3,9 +3,25 @@
 //! This module contains various utility functions for URL resolution, domain extraction,
 //! and notice ID extraction.
 
+pub mod export;
 pub mod http;
+pub mod rate_limit;
+pub mod region;
+pub mod robots;
+pub mod rss;
+pub mod sitemap;
+pub mod slow_start;
+#[cfg(test)]
+pub(crate) mod test_fixtures;
+#[cfg(test)]
+pub(crate) mod test_server;
+pub mod url;
+
+use std::sync::LazyLock;
+
+use ::url::Url;
 
-use url::Url;
+use crate::models::IdExtractionConfig;
 
 /// Resolve a potentially relative URL against a base URL.
 pub fn resolve_url(base: &Url, href: &str) -> String {
@@ -28,28 +44,142 @@ pub fn get_domain(url_str: &str) -> Option<String> {
         .and_then(|u| u.host_str().map(|s| s.to_string()))
 }
 
-/// Extract notice ID from a URL (looks for common patterns).
+/// Extract notice ID from a URL with the default [`IdExtractionConfig`].
 pub fn extract_notice_id(url: &str) -> Option<String> {
-    // Common patterns: ?id=123, /notice/123, /view/123, &seq=123
-    let patterns = [
-        regex::Regex::new(r"[?&](?:id|seq|no|idx|article_seq|articleNo)=(\d+)").ok()?,
-        regex::Regex::new(r"/(?:view|notice|article|board)/(\d+)").ok()?,
-    ];
-
-    for pattern in &patterns {
-        if let Some(caps) = pattern.captures(url) {
-            if let Some(id) = caps.get(1) {
-                return Some(id.as_str().to_string());
+    extract_notice_id_with(url, &IdExtractionConfig::default())
+}
+
+/// Extract notice ID from a URL (looks for common patterns).
+///
+/// The leftmost `?key=123` / `&key=123` in the URL whose key is one of
+/// `config.preferred_keys` wins; with `config.prefer_key_order` the keys are
+/// instead tried in their configured order. Next comes a path such as
+/// `/notice/123` or `/view/123`, and with `numeric_fallback` any other numeric
+/// query parameter as a last resort. Keys in `config.ignore_keys` are never
+/// used. Keys compare case-sensitively.
+pub fn extract_notice_id_with(url: &str, config: &IdExtractionConfig) -> Option<String> {
+    // Every `key=value` introduced by `?` or `&`, in URL order, like the
+    // `[?&]key=(\d+)` pattern this replaces
+    let params: Vec<(&str, &str)> = url
+        .match_indices(['?', '&'])
+        .filter_map(|(pos, _)| url[pos + 1..].split_once('='))
+        .collect();
+    let is_ignored = |key: &str| config.ignore_keys.iter().any(|k| k == key);
+    let is_preferred = |key: &str| config.preferred_keys.iter().any(|k| k == key);
+    // Like `=(\d+)`: the leading digits of the value, if any
+    let leading_digits = |value: &str| {
+        let end = value
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(value.len());
+        (end > 0).then(|| value[..end].to_string())
+    };
+
+    let id = if config.prefer_key_order {
+        config
+            .preferred_keys
+            .iter()
+            .filter(|k| !is_ignored(k))
+            .find_map(|key| {
+                params
+                    .iter()
+                    .filter(|(k, _)| k == key)
+                    .find_map(|(_, value)| leading_digits(value))
+            })
+    } else {
+        params
+            .iter()
+            .filter(|(k, _)| is_preferred(k) && !is_ignored(k))
+            .find_map(|(_, value)| leading_digits(value))
+    };
+    if id.is_some() {
+        return id;
+    }
+
+    static NOTICE_PATH: LazyLock<regex::Regex> =
+        LazyLock::new(|| regex::Regex::new(r"/(?:view|notice|article|board)/(\d+)").unwrap());
+    if let Some(caps) = NOTICE_PATH.captures(url) {
+        return Some(caps[1].to_string());
+    }
+
+    if config.numeric_fallback {
+        return params
+            .iter()
+            .filter(|(k, _)| !is_ignored(k))
+            .find_map(|(_, value)| leading_digits(value));
+    }
+    None
+}
+
+/// Extract a URL embedded in a JavaScript snippet (e.g. an `onclick` handler).
+///
+/// Handles calls like `location.href='/board/view?id=1'` or `goView('view.do?no=3')`,
+/// returning the first quoted string that looks like a URL or path.
+pub fn extract_script_url(script: &str) -> Option<String> {
+    let pattern =
+        regex::Regex::new(r#"['"]((?:https?://|/|\./|\.\./)[^'"]*|[^'"\s]*\?[^'"\s]*)['"]"#)
+            .ok()?;
+    pattern
+        .captures(script)
+        .and_then(|caps| caps.get(1))
+        .map(|m| m.as_str().to_string())
+}
+
+/// Share of letters in the minority script at which text counts as mixed.
+const MIXED_SCRIPT_RATIO: f32 = 0.2;
+
+/// Tag the dominant script of `text`: `ko` (Hangul), `en` (Latin) or `mixed`.
+///
+/// Only letters are counted, so digits and punctuation do not affect the
+/// result. Returns `None` when the text has no Hangul or Latin letters.
+pub fn detect_lang(text: &str) -> Option<&'static str> {
+    let (mut hangul, mut latin) = (0usize, 0usize);
+    for c in text.chars() {
+        match c {
+            '\u{AC00}'..='\u{D7A3}' | '\u{1100}'..='\u{11FF}' | '\u{3130}'..='\u{318F}' => {
+                hangul += 1
             }
+            c if c.is_ascii_alphabetic() => latin += 1,
+            _ => {}
         }
     }
-    None
+
+    let total = hangul + latin;
+    if total == 0 {
+        return None;
+    }
+    let minority = hangul.min(latin) as f32 / total as f32;
+    Some(if minority >= MIXED_SCRIPT_RATIO {
+        "mixed"
+    } else if hangul > latin {
+        "ko"
+    } else {
+        "en"
+    })
+}
+
+/// Lowercase `text` keeping only its alphanumerics, for loose comparisons
+/// ("학사 공지" and "[학사·공지]" both become "학사공지").
+pub fn normalize_text(text: &str) -> String {
+    text.chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_detect_lang() {
+        assert_eq!(detect_lang("2024학년도 수강신청 안내"), Some("ko"));
+        assert_eq!(detect_lang("Fall 2024 Course Registration"), Some("en"));
+        assert_eq!(detect_lang("Global Seminar 국제 세미나"), Some("mixed"));
+        // A short acronym does not make a Korean title mixed
+        assert_eq!(detect_lang("SW중심대학 사업단 공모전 안내"), Some("ko"));
+        assert_eq!(detect_lang("2024-01-15 (#3)"), None);
+    }
+
     #[test]
     fn test_resolve_url() {
         let base = Url::parse("https://example.com/path/").unwrap();
@@ -90,4 +220,84 @@ mod tests {
             Some("456".to_string())
         );
     }
+
+    #[test]
+    fn test_extract_notice_id_skips_ignored_keys() {
+        let url = "https://example.com/list.do?page=2&articleNo=991&mode=view";
+        assert_eq!(extract_notice_id(url), Some("991".to_string()));
+
+        // `page` is tried first but ignored, so the real article number wins
+        let config = IdExtractionConfig {
+            preferred_keys: vec!["page".to_string(), "articleNo".to_string()],
+            ..IdExtractionConfig::default()
+        };
+        assert_eq!(
+            extract_notice_id_with(url, &config),
+            Some("991".to_string())
+        );
+
+        let fallback = IdExtractionConfig {
+            preferred_keys: Vec::new(),
+            numeric_fallback: true,
+            ..IdExtractionConfig::default()
+        };
+        assert_eq!(
+            extract_notice_id_with(url, &fallback),
+            Some("991".to_string())
+        );
+        let no_ignore = IdExtractionConfig {
+            ignore_keys: Vec::new(),
+            ..fallback
+        };
+        assert_eq!(
+            extract_notice_id_with(url, &no_ignore),
+            Some("2".to_string())
+        );
+    }
+
+    #[test]
+    fn test_extract_notice_id_prefers_keys_in_order_when_configured() {
+        let url = "https://example.com/view?no=5&article_seq=77";
+        let config = IdExtractionConfig {
+            preferred_keys: vec!["article_seq".to_string(), "no".to_string()],
+            ..IdExtractionConfig::default()
+        };
+        assert_eq!(extract_notice_id_with(url, &config), Some("5".to_string()));
+
+        let ordered = IdExtractionConfig {
+            prefer_key_order: true,
+            ..config
+        };
+        assert_eq!(
+            extract_notice_id_with(url, &ordered),
+            Some("77".to_string())
+        );
+        assert_eq!(extract_notice_id("https://example.com/view?id=abc"), None);
+    }
+
+    #[test]
+    fn test_extract_notice_id_default_is_leftmost_and_case_sensitive() {
+        assert_eq!(
+            extract_notice_id("https://example.com/view?no=5&id=9"),
+            Some("5".to_string())
+        );
+        assert_eq!(extract_notice_id("https://example.com/view?ID=5"), None);
+        assert_eq!(
+            extract_notice_id("https://example.com/view#top&id=42"),
+            Some("42".to_string())
+        );
+    }
+
+    #[test]
+    fn test_extract_script_url() {
+        assert_eq!(
+            extract_script_url("location.href='/board/view.do?id=7'; return false;"),
+            Some("/board/view.do?id=7".to_string())
+        );
+        assert_eq!(
+            extract_script_url(r#"goView("view.php?no=3")"#),
+            Some("view.php?no=3".to_string())
+        );
+        assert_eq!(extract_script_url("doSomething(3)"), None);
+    }
 }
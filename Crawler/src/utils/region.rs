@@ -0,0 +1,319 @@
+//! Cutting one element out of raw HTML before it is parsed.
+//!
+//! A board's content root names the element holding its notice list. Finding
+//! that element with a lightweight tag scan lets the fetch path parse only its
+//! markup instead of building a DOM for the whole page. The scan understands
+//! comments, quoted attribute values and raw-text elements (`script`,
+//! `style`), but none of HTML's implied end tags, so it only supports simple
+//! selectors (`div`, `#board`, `.list`, `table.board-list`) and is meant for
+//! container elements that are always closed explicitly.
+
+use std::fmt;
+
+/// Elements whose content is text, never markup.
+const RAW_TEXT: &[&str] = &["script", "style", "textarea", "title"];
+
+/// Elements that never have content or an end tag.
+const VOID: &[&str] = &[
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "source", "track",
+    "wbr",
+];
+
+/// A simple selector matched against raw start tags: an optional tag name,
+/// at most one `#id` and any number of `.class`es.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RegionSelector {
+    source: String,
+    tag: Option<String>,
+    id: Option<String>,
+    classes: Vec<String>,
+}
+
+impl RegionSelector {
+    /// Parse a simple selector. Returns `None` for anything the tag scan
+    /// cannot match: combinators, attribute selectors, pseudo-classes and
+    /// selector lists.
+    pub fn parse(selector: &str) -> Option<Self> {
+        let source = selector.trim();
+        let is_name = |name: &str| {
+            !name.is_empty()
+                && name
+                    .chars()
+                    .all(|c| c.is_alphanumeric() || c == '-' || c == '_')
+        };
+        let part_end = |s: &str| s.find(['#', '.']).unwrap_or(s.len());
+
+        let tag_end = part_end(source);
+        let tag = &source[..tag_end];
+        let mut parsed = Self {
+            source: source.to_string(),
+            tag: None,
+            id: None,
+            classes: Vec::new(),
+        };
+        if !tag.is_empty() {
+            if !is_name(tag) {
+                return None;
+            }
+            parsed.tag = Some(tag.to_ascii_lowercase());
+        }
+
+        let mut rest = &source[tag_end..];
+        while let Some(kind) = rest.chars().next() {
+            let part = &rest[1..];
+            let name = &part[..part_end(part)];
+            if !is_name(name) {
+                return None;
+            }
+            if kind == '#' {
+                if parsed.id.is_some() {
+                    return None;
+                }
+                parsed.id = Some(name.to_string());
+            } else {
+                parsed.classes.push(name.to_string());
+            }
+            rest = &part[name.len()..];
+        }
+        (parsed.tag.is_some() || parsed.id.is_some() || !parsed.classes.is_empty())
+            .then_some(parsed)
+    }
+
+    fn matches(&self, tag: &Tag<'_>) -> bool {
+        if self.tag.as_ref().is_some_and(|name| *name != tag.name) {
+            return false;
+        }
+        if let Some(id) = &self.id
+            && attribute(tag.attrs, "id") != Some(id.as_str())
+        {
+            return false;
+        }
+        let classes = attribute(tag.attrs, "class").unwrap_or_default();
+        self.classes
+            .iter()
+            .all(|class| classes.split_ascii_whitespace().any(|c| c == class))
+    }
+}
+
+impl fmt::Display for RegionSelector {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.source)
+    }
+}
+
+/// The markup of the first element matching `selector`, from its start tag
+/// through the end tag that balances it. `None` when no start tag matches.
+///
+/// An element that is never closed runs to the end of the body, as a parser
+/// would treat it.
+pub fn slice<'a>(body: &'a str, selector: &RegionSelector) -> Option<&'a str> {
+    let mut tags = Tags { body, pos: 0 };
+    let open = tags.find(|tag| !tag.closing && selector.matches(tag))?;
+    if open.self_closing || VOID.contains(&open.name.as_str()) {
+        return Some(&body[open.start..open.end]);
+    }
+
+    let mut depth = 1usize;
+    for tag in tags.filter(|tag| tag.name == open.name) {
+        if !tag.closing {
+            depth += usize::from(!tag.self_closing);
+            continue;
+        }
+        depth -= 1;
+        if depth == 0 {
+            return Some(&body[open.start..tag.end]);
+        }
+    }
+    Some(&body[open.start..])
+}
+
+/// A start or end tag found by the scan.
+struct Tag<'a> {
+    /// Byte range of the tag in the body
+    start: usize,
+    end: usize,
+    /// Lowercased tag name
+    name: String,
+    closing: bool,
+    self_closing: bool,
+    /// Raw text between the tag name and `>`
+    attrs: &'a str,
+}
+
+/// Iterator over the tags of an HTML body, skipping comments, doctypes and
+/// the content of raw-text elements.
+struct Tags<'a> {
+    body: &'a str,
+    pos: usize,
+}
+
+impl<'a> Iterator for Tags<'a> {
+    type Item = Tag<'a>;
+
+    fn next(&mut self) -> Option<Tag<'a>> {
+        let body = self.body;
+        loop {
+            let start = self.pos + body[self.pos..].find('<')?;
+            let rest = &body[start..];
+            if rest.starts_with("<!--") {
+                self.pos = rest.find("-->").map_or(body.len(), |end| start + end + 3);
+                continue;
+            }
+            if rest.starts_with("<!") || rest.starts_with("<?") {
+                self.pos = rest.find('>').map_or(body.len(), |end| start + end + 1);
+                continue;
+            }
+
+            let closing = rest[1..].starts_with('/');
+            let name_start = start + 1 + usize::from(closing);
+            let name_len = body[name_start..]
+                .find(|c: char| !(c.is_ascii_alphanumeric() || c == '-' || c == ':'))
+                .unwrap_or(body.len() - name_start);
+            if name_len == 0 || !body[name_start..].starts_with(|c: char| c.is_ascii_alphabetic()) {
+                // A stray `<` in text
+                self.pos = start + 1;
+                continue;
+            }
+
+            let attrs_start = name_start + name_len;
+            let attrs_len = tag_end(&body[attrs_start..])?;
+            let end = attrs_start + attrs_len + 1;
+            let attrs = &body[attrs_start..attrs_start + attrs_len];
+            let name = body[name_start..attrs_start].to_ascii_lowercase();
+            self.pos = end;
+            if !closing && RAW_TEXT.contains(&name.as_str()) {
+                let close = format!("</{name}");
+                self.pos = find_ignore_case(&body[end..], &close).map_or(body.len(), |i| end + i);
+            }
+
+            return Some(Tag {
+                start,
+                end,
+                closing,
+                self_closing: attrs.trim_end().ends_with('/'),
+                name,
+                attrs,
+            });
+        }
+    }
+}
+
+/// Offset of the `>` ending a tag, ignoring any inside quoted values.
+fn tag_end(text: &str) -> Option<usize> {
+    let mut quote = None;
+    for (i, c) in text.char_indices() {
+        match quote {
+            Some(q) if c == q => quote = None,
+            Some(_) => {}
+            None if c == '"' || c == '\'' => quote = Some(c),
+            None if c == '>' => return Some(i),
+            None => {}
+        }
+    }
+    None
+}
+
+fn find_ignore_case(haystack: &str, needle: &str) -> Option<usize> {
+    haystack
+        .as_bytes()
+        .windows(needle.len())
+        .position(|window| window.eq_ignore_ascii_case(needle.as_bytes()))
+}
+
+/// The value of attribute `name` in the raw attribute text of a tag.
+fn attribute<'a>(attrs: &'a str, name: &str) -> Option<&'a str> {
+    let mut rest = attrs;
+    loop {
+        rest = rest.trim_start_matches(|c: char| c.is_ascii_whitespace() || c == '/');
+        if rest.is_empty() {
+            return None;
+        }
+        let key_len = rest
+            .find(|c: char| c.is_ascii_whitespace() || c == '=' || c == '/')
+            .unwrap_or(rest.len());
+        let key = &rest[..key_len];
+        rest = rest[key_len..].trim_start();
+
+        let mut value = "";
+        if let Some(after) = rest.strip_prefix('=') {
+            let after = after.trim_start();
+            let (found, remaining) = match after.chars().next() {
+                Some(q @ ('"' | '\'')) => {
+                    let inner = &after[1..];
+                    let close = inner.find(q).unwrap_or(inner.len());
+                    (&inner[..close], inner.get(close + 1..).unwrap_or(""))
+                }
+                _ => {
+                    let end = after
+                        .find(|c: char| c.is_ascii_whitespace())
+                        .unwrap_or(after.len());
+                    after.split_at(end)
+                }
+            };
+            value = found;
+            rest = remaining;
+        }
+        if key.eq_ignore_ascii_case(name) {
+            return Some(value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn slice_with<'a>(body: &'a str, selector: &str) -> Option<&'a str> {
+        slice(body, &RegionSelector::parse(selector).unwrap())
+    }
+
+    #[test]
+    fn test_parse_accepts_only_simple_selectors() {
+        for selector in ["div", "#board", ".list", "table.board-list", "div#main.a.b"] {
+            assert!(RegionSelector::parse(selector).is_some(), "{selector}");
+        }
+        for selector in [
+            "",
+            "div > ul",
+            "#a, #b",
+            "[data-x]",
+            "li:first-child",
+            "#a#b",
+        ] {
+            assert!(RegionSelector::parse(selector).is_none(), "{selector}");
+        }
+    }
+
+    #[test]
+    fn test_slice_balances_nested_tags() {
+        let body = r#"<div id="nav"><div>menu</div></div>
+            <div class="wrap board" id="board"><div><div>row</div></div><p>x</p></div>
+            <div>footer</div>"#;
+        assert_eq!(
+            slice_with(body, "#board"),
+            Some(r#"<div class="wrap board" id="board"><div><div>row</div></div><p>x</p></div>"#)
+        );
+        assert_eq!(slice_with(body, "div.board"), slice_with(body, "#board"));
+        assert_eq!(slice_with(body, "#missing"), None);
+    }
+
+    #[test]
+    fn test_slice_skips_comments_scripts_and_quoted_brackets() {
+        let body = r#"<!-- <div id="board">old</div> -->
+            <script>var s = '<div id="board">';</script>
+            <div title="a > b" id='board'><span>row</span></DIV><p>after</p>"#;
+        assert_eq!(
+            slice_with(body, "#board"),
+            Some(r#"<div title="a > b" id='board'><span>row</span></DIV>"#)
+        );
+    }
+
+    #[test]
+    fn test_unclosed_region_runs_to_end() {
+        let body = "<p>intro</p><ul class=list><li>a<li>b";
+        assert_eq!(
+            slice_with(body, "ul.list"),
+            Some("<ul class=list><li>a<li>b")
+        );
+    }
+}
@@ -0,0 +1,203 @@
+//! robots.txt parsing and matching.
+//!
+//! Supports `User-agent` groups, `Allow`/`Disallow` with `*` wildcards and a
+//! trailing `$` anchor. The longest matching rule wins; on a tie `Allow`
+//! wins. A missing or unreadable robots.txt allows everything.
+
+use reqwest::Client;
+
+use crate::utils::{http, resolve};
+
+/// Rules from a robots.txt that apply to one user agent.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RobotsRules {
+    rules: Vec<Rule>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Rule {
+    allow: bool,
+    pattern: String,
+}
+
+#[derive(Debug, Default)]
+struct Group {
+    agents: Vec<String>,
+    rules: Vec<Rule>,
+}
+
+impl RobotsRules {
+    /// Rules that allow every path.
+    pub fn allow_all() -> Self {
+        Self::default()
+    }
+
+    /// Parse a robots.txt, keeping the rules for `user_agent`.
+    ///
+    /// A group applies when its `User-agent` token appears (case-insensitive)
+    /// in `user_agent`; the longest such token wins, then the `*` group.
+    pub fn parse(text: &str, user_agent: &str) -> Self {
+        let mut groups: Vec<Group> = Vec::new();
+        let mut in_agents = false;
+
+        for line in text.lines() {
+            let line = line.split('#').next().unwrap_or("").trim();
+            let Some((key, value)) = line.split_once(':') else {
+                continue;
+            };
+            let value = value.trim();
+
+            match key.trim().to_ascii_lowercase().as_str() {
+                "user-agent" => {
+                    if !in_agents {
+                        groups.push(Group::default());
+                        in_agents = true;
+                    }
+                    if let Some(group) = groups.last_mut() {
+                        group.agents.push(value.to_ascii_lowercase());
+                    }
+                }
+                key @ ("allow" | "disallow") => {
+                    in_agents = false;
+                    // An empty Disallow allows everything, so it adds no rule
+                    if value.is_empty() {
+                        continue;
+                    }
+                    if let Some(group) = groups.last_mut() {
+                        group.rules.push(Rule {
+                            allow: key == "allow",
+                            pattern: value.to_string(),
+                        });
+                    }
+                }
+                _ => in_agents = false,
+            }
+        }
+
+        let user_agent = user_agent.to_ascii_lowercase();
+        let specificity = |agent: &str| match agent {
+            "*" => Some(0),
+            agent if user_agent.contains(agent) => Some(agent.len()),
+            _ => None,
+        };
+        let best = groups
+            .iter()
+            .flat_map(|g| g.agents.iter().filter_map(|a| specificity(a)))
+            .max();
+
+        let rules = match best {
+            Some(best) => groups
+                .into_iter()
+                .filter(|g| g.agents.iter().any(|a| specificity(a) == Some(best)))
+                .flat_map(|g| g.rules)
+                .collect(),
+            None => Vec::new(),
+        };
+        Self { rules }
+    }
+
+    /// Whether `path` (including any query string) may be fetched.
+    pub fn is_allowed(&self, path: &str) -> bool {
+        self.rules
+            .iter()
+            .filter(|rule| pattern_matches(&rule.pattern, path))
+            .max_by_key(|rule| (rule.pattern.len(), rule.allow))
+            .is_none_or(|rule| rule.allow)
+    }
+
+    /// Whether the path and query of `url` may be fetched.
+    pub fn is_url_allowed(&self, url: &str) -> bool {
+        match url::Url::parse(url) {
+            Ok(parsed) => match parsed.query() {
+                Some(query) => self.is_allowed(&format!("{}?{}", parsed.path(), query)),
+                None => self.is_allowed(parsed.path()),
+            },
+            Err(_) => true,
+        }
+    }
+}
+
+/// Match a robots.txt path pattern against the start of `path`.
+fn pattern_matches(pattern: &str, path: &str) -> bool {
+    let (pattern, anchored) = match pattern.strip_suffix('$') {
+        Some(pattern) => (pattern, true),
+        None => (pattern, false),
+    };
+
+    let mut parts = pattern.split('*');
+    let Some(mut rest) = path.strip_prefix(parts.next().unwrap_or("")) else {
+        return false;
+    };
+    let parts: Vec<&str> = parts.collect();
+    for (i, part) in parts.iter().enumerate() {
+        if anchored && i == parts.len() - 1 {
+            return rest.ends_with(part);
+        }
+        match rest.find(part) {
+            Some(idx) => rest = &rest[idx + part.len()..],
+            None => return false,
+        }
+    }
+    !anchored || rest.is_empty()
+}
+
+/// Fetch and parse the robots.txt for the site serving `site_url`.
+///
+/// Any failure (missing file, HTTP error, network error) yields
+/// [`RobotsRules::allow_all`].
+pub async fn fetch_robots(
+    client: &Client,
+    site_url: &str,
+    user_agent: &str,
+    max_bytes: u64,
+) -> RobotsRules {
+    let Some(robots_url) = resolve(site_url, "/robots.txt") else {
+        return RobotsRules::allow_all();
+    };
+    match http::fetch_bytes(client, &robots_url, max_bytes).await {
+        Ok(body) => RobotsRules::parse(&String::from_utf8_lossy(&body), user_agent),
+        Err(e) => {
+            log::debug!("No usable robots.txt at {}: {}", robots_url, e);
+            RobotsRules::allow_all()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const UA: &str = "Mozilla/5.0 (compatible; uRing/1.0)";
+
+    #[test]
+    fn test_specific_agent_group_wins() {
+        let text = "User-agent: *\nDisallow: /\n\nUser-agent: uRing\nDisallow: /admin\n";
+        let rules = RobotsRules::parse(text, UA);
+        assert!(rules.is_allowed("/notice"));
+        assert!(!rules.is_allowed("/admin/users"));
+
+        let other = RobotsRules::parse(text, "OtherBot/2.0");
+        assert!(!other.is_allowed("/notice"));
+    }
+
+    #[test]
+    fn test_longest_match_and_allow_override() {
+        let text = "User-agent: *\nDisallow: /bbs/\nAllow: /bbs/notice\nDisallow: /*.pdf$\n";
+        let rules = RobotsRules::parse(text, UA);
+        assert!(rules.is_allowed("/bbs/notice/list.do"));
+        assert!(!rules.is_allowed("/bbs/free/list.do"));
+        assert!(!rules.is_allowed("/files/guide.pdf"));
+        assert!(rules.is_allowed("/files/guide.pdf?download=1"));
+        assert!(rules.is_allowed("/"));
+    }
+
+    #[test]
+    fn test_empty_disallow_and_wildcards() {
+        let rules = RobotsRules::parse("User-agent: *\nDisallow:\n", UA);
+        assert_eq!(rules, RobotsRules::allow_all());
+
+        let rules = RobotsRules::parse("User-agent: *\nDisallow: /*?mode=view\n", UA);
+        assert!(!rules.is_url_allowed("https://example.com/board?mode=view&id=1"));
+        assert!(rules.is_url_allowed("https://example.com/board?mode=list"));
+    }
+}
@@ -0,0 +1,197 @@
+//! RSS/Atom feed reading for feed-backed boards.
+//!
+//! Finds feeds advertised with `<link rel="alternate">` and extracts their
+//! items. Parsing is pattern-based, like the sitemap reader: enough for the
+//! flat item structure of RSS 2.0 and Atom without a full XML parser.
+
+use chrono::DateTime;
+use regex::Regex;
+use scraper::{Html, Selector};
+
+use crate::utils::resolve;
+use crate::utils::sitemap::unescape_xml;
+
+/// A feed advertised in a page's `<head>`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FeedLink {
+    /// `title` attribute of the link (empty if absent)
+    pub title: String,
+    /// Absolute feed URL
+    pub url: String,
+}
+
+/// One entry of an RSS or Atom feed.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FeedItem {
+    pub title: String,
+    pub link: String,
+    /// `YYYY-MM-DD` when the feed date parses, else the raw value
+    pub date: String,
+    pub author: String,
+}
+
+/// RSS and Atom feed links in `document`, resolved against `base_url`.
+pub fn feed_links(document: &Html, base_url: &str) -> Vec<FeedLink> {
+    let selector = Selector::parse(
+        r#"link[rel~="alternate"][type="application/rss+xml"][href], link[rel~="alternate"][type="application/atom+xml"][href]"#,
+    )
+    .unwrap();
+
+    let mut links: Vec<FeedLink> = Vec::new();
+    for element in document.select(&selector) {
+        let Some(url) = element
+            .value()
+            .attr("href")
+            .and_then(|href| resolve(base_url, href))
+        else {
+            continue;
+        };
+        if links.iter().any(|l| l.url == url) {
+            continue;
+        }
+        links.push(FeedLink {
+            title: element
+                .value()
+                .attr("title")
+                .unwrap_or("")
+                .trim()
+                .to_string(),
+            url,
+        });
+    }
+    links
+}
+
+/// Parse the items of an RSS 2.0 or Atom feed, in document order.
+///
+/// Items without a title or link are skipped.
+pub fn parse_feed(xml: &str) -> Vec<FeedItem> {
+    let item_pattern = Regex::new(r"(?is)<(item|entry)\b[^>]*>(.*?)</(?:item|entry)>").unwrap();
+    let atom_link = Regex::new(r#"(?is)<link\b([^>]*?)/?>"#).unwrap();
+    let href = Regex::new(r#"(?i)\bhref\s*=\s*["']([^"']+)["']"#).unwrap();
+
+    item_pattern
+        .captures_iter(xml)
+        .filter_map(|caps| {
+            let body = &caps[2];
+            let title = element_text(body, &["title"])?;
+            let link = match element_text(body, &["link"]) {
+                Some(link) if !link.is_empty() => link,
+                // Atom: <link href="..."/>, preferring rel="alternate"
+                _ => atom_link
+                    .captures_iter(body)
+                    .map(|link| link[1].to_string())
+                    .filter(|attrs| !attrs.contains("rel=") || attrs.contains("alternate"))
+                    .find_map(|attrs| href.captures(&attrs).map(|h| unescape_xml(&h[1])))?,
+            };
+            let date = element_text(body, &["pubDate", "published", "updated", "dc:date"])
+                .map(|raw| normalize_feed_date(&raw))
+                .unwrap_or_default();
+            let author = element_text(body, &["dc:creator", "author"])
+                .map(|raw| strip_tags(&raw))
+                .unwrap_or_default();
+            Some(FeedItem {
+                title,
+                link,
+                date,
+                author,
+            })
+        })
+        .collect()
+}
+
+/// Text of the first of `tags` present in `body`, with CDATA unwrapped.
+fn element_text(body: &str, tags: &[&str]) -> Option<String> {
+    tags.iter().find_map(|tag| {
+        let pattern = Regex::new(&format!(
+            r"(?is)<{0}\b[^>]*>(.*?)</{0}>",
+            regex::escape(tag)
+        ))
+        .unwrap();
+        let raw = pattern.captures(body)?.get(1)?.as_str().trim();
+        let text = match raw
+            .strip_prefix("<![CDATA[")
+            .and_then(|s| s.strip_suffix("]]>"))
+        {
+            Some(cdata) => cdata.trim().to_string(),
+            None => unescape_xml(raw),
+        };
+        Some(text)
+    })
+}
+
+/// Atom `<author><name>..</name></author>` holds markup; keep its text.
+fn strip_tags(s: &str) -> String {
+    let tag = Regex::new(r"<[^>]*>").unwrap();
+    tag.replace_all(s, " ")
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// RFC 2822 (RSS) or RFC 3339 (Atom) dates become `YYYY-MM-DD`.
+fn normalize_feed_date(raw: &str) -> String {
+    DateTime::parse_from_rfc2822(raw)
+        .or_else(|_| DateTime::parse_from_rfc3339(raw))
+        .map(|dt| dt.format("%Y-%m-%d").to_string())
+        .unwrap_or_else(|_| raw.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_feed_links_in_head() {
+        let html = Html::parse_document(
+            r#"<html><head>
+            <link rel="alternate" type="application/rss+xml" title="학과 공지" href="/rss/notice.xml">
+            <link rel="alternate" type="application/atom+xml" href="https://cdn.example.com/atom">
+            <link rel="stylesheet" href="/style.css">
+            </head><body></body></html>"#,
+        );
+        let links = feed_links(&html, "https://me.example.com/main");
+        assert_eq!(
+            links,
+            [
+                FeedLink {
+                    title: "학과 공지".to_string(),
+                    url: "https://me.example.com/rss/notice.xml".to_string(),
+                },
+                FeedLink {
+                    title: String::new(),
+                    url: "https://cdn.example.com/atom".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_rss_and_atom_items() {
+        let rss = r#"<?xml version="1.0"?><rss version="2.0"><channel><title>Board</title>
+            <item><title><![CDATA[수강신청 안내 & 일정]]></title><link>https://example.com/view?id=1&amp;p=2</link>
+              <pubDate>Tue, 05 Mar 2024 10:00:00 +0900</pubDate><dc:creator>학사팀</dc:creator></item>
+            <item><title>No link</title></item>
+            </channel></rss>"#;
+        assert_eq!(
+            parse_feed(rss),
+            [FeedItem {
+                title: "수강신청 안내 & 일정".to_string(),
+                link: "https://example.com/view?id=1&p=2".to_string(),
+                date: "2024-03-05".to_string(),
+                author: "학사팀".to_string(),
+            }]
+        );
+
+        let atom = r#"<feed xmlns="http://www.w3.org/2005/Atom"><title>Board</title>
+            <entry><title>Scholarship</title><link rel="edit" href="/edit/9"/>
+              <link rel="alternate" href="https://example.com/view/9"/>
+              <updated>2024-01-15T09:00:00Z</updated><author><name>장학팀</name></author></entry>
+            </feed>"#;
+        let items = parse_feed(atom);
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].link, "https://example.com/view/9");
+        assert_eq!(items[0].date, "2024-01-15");
+        assert_eq!(items[0].author, "장학팀");
+    }
+}
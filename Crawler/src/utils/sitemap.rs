@@ -0,0 +1,244 @@
+//! XML sitemap reading for board discovery.
+//!
+//! Follows `<sitemapindex>` documents down to their `<urlset>` leaves and
+//! accepts gzipped (`.xml.gz`) files. Recursion is bounded by depth and by
+//! the total number of sitemaps fetched.
+
+use std::collections::{HashSet, VecDeque};
+
+use regex::Regex;
+use reqwest::Client;
+
+use crate::utils::http;
+
+/// Bounds for a sitemap crawl.
+#[derive(Debug, Clone, Copy)]
+pub struct SitemapLimits {
+    /// Maximum index nesting followed below the root sitemap
+    pub max_depth: usize,
+    /// Maximum sitemap files fetched in total
+    pub max_fetches: usize,
+    /// Maximum decoded size of a single sitemap file
+    pub max_bytes: u64,
+}
+
+/// A parsed sitemap document.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SitemapDoc {
+    /// `<sitemapindex>`: locations of child sitemaps
+    Index(Vec<String>),
+    /// `<urlset>`: page locations
+    UrlSet(Vec<String>),
+}
+
+/// Parse a sitemap or sitemap index.
+pub fn parse_sitemap(xml: &str) -> SitemapDoc {
+    let loc_pattern = Regex::new(r"(?is)<loc>\s*(.*?)\s*</loc>").unwrap();
+    let locs = loc_pattern
+        .captures_iter(xml)
+        .map(|caps| unescape_xml(&caps[1]))
+        .filter(|loc| !loc.is_empty())
+        .collect();
+
+    if xml.contains("<sitemapindex") {
+        SitemapDoc::Index(locs)
+    } else {
+        SitemapDoc::UrlSet(locs)
+    }
+}
+
+/// Collect page URLs from the sitemap at `url`, following indexes.
+///
+/// Fetch failures are logged and skipped, so a broken child sitemap does
+/// not discard its siblings. URLs are returned in discovery order without
+/// duplicates.
+pub async fn collect_sitemap_urls(
+    client: &Client,
+    url: &str,
+    limits: &SitemapLimits,
+) -> Vec<String> {
+    let mut queue = VecDeque::from([(url.to_string(), 0)]);
+    let mut visited = HashSet::new();
+    let mut seen = HashSet::new();
+    let mut urls = Vec::new();
+
+    while let Some((sitemap_url, depth)) = queue.pop_front() {
+        if visited.len() >= limits.max_fetches {
+            log::debug!("Sitemap fetch limit reached at {}", sitemap_url);
+            break;
+        }
+        if !visited.insert(sitemap_url.clone()) {
+            continue;
+        }
+
+        let xml = match fetch_sitemap(client, &sitemap_url, limits.max_bytes).await {
+            Ok(xml) => xml,
+            Err(e) => {
+                log::debug!("Skipping sitemap {}: {}", sitemap_url, e);
+                continue;
+            }
+        };
+
+        match parse_sitemap(&xml) {
+            SitemapDoc::Index(children) if depth < limits.max_depth => {
+                queue.extend(children.into_iter().map(|child| (child, depth + 1)));
+            }
+            SitemapDoc::Index(_) => {
+                log::debug!("Sitemap depth limit reached at {}", sitemap_url);
+            }
+            SitemapDoc::UrlSet(locs) => {
+                urls.extend(locs.into_iter().filter(|loc| seen.insert(loc.clone())));
+            }
+        }
+    }
+
+    urls
+}
+
+/// Fetch a sitemap as text, inflating gzip files served without
+/// `Content-Encoding` (detected by magic bytes).
+async fn fetch_sitemap(client: &Client, url: &str, max_bytes: u64) -> crate::error::Result<String> {
+    let bytes = http::fetch_bytes(client, url, max_bytes).await?;
+    let bytes = if bytes.starts_with(&[0x1f, 0x8b]) {
+        http::decode_body(url, &bytes, Some("gzip"), max_bytes)?
+    } else {
+        bytes
+    };
+    Ok(String::from_utf8_lossy(&bytes).into_owned())
+}
+
+/// Decode the five predefined XML entities.
+pub(crate) fn unescape_xml(s: &str) -> String {
+    s.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use flate2::{Compression, write::GzEncoder};
+
+    use super::*;
+    use crate::utils::test_server::{TestResponse, TestServer};
+
+    const LIMITS: SitemapLimits = SitemapLimits {
+        max_depth: 2,
+        max_fetches: 10,
+        max_bytes: 1_000_000,
+    };
+
+    fn urlset(locs: &[&str]) -> String {
+        let entries: String = locs
+            .iter()
+            .map(|loc| format!("<url><loc>{}</loc></url>", loc))
+            .collect();
+        format!(
+            r#"<?xml version="1.0"?><urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">{entries}</urlset>"#
+        )
+    }
+
+    fn xml(body: String) -> TestResponse {
+        TestResponse {
+            status: 200,
+            headers: vec![("Content-Type".into(), "application/xml".into())],
+            body: body.into_bytes(),
+        }
+    }
+
+    #[test]
+    fn test_parse_sitemap_kinds() {
+        assert_eq!(
+            parse_sitemap(&urlset(&["https://example.com/a?x=1&amp;y=2"])),
+            SitemapDoc::UrlSet(vec!["https://example.com/a?x=1&y=2".to_string()])
+        );
+        let index =
+            "<sitemapindex><sitemap><loc>https://example.com/s1.xml</loc></sitemap></sitemapindex>";
+        assert_eq!(
+            parse_sitemap(index),
+            SitemapDoc::Index(vec!["https://example.com/s1.xml".to_string()])
+        );
+    }
+
+    #[tokio::test]
+    async fn test_gzipped_sitemap() {
+        let server = TestServer::start(|_| {
+            let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+            encoder
+                .write_all(urlset(&["https://example.com/bbs/notice"]).as_bytes())
+                .unwrap();
+            TestResponse {
+                status: 200,
+                headers: vec![("Content-Type".into(), "application/gzip".into())],
+                body: encoder.finish().unwrap(),
+            }
+        });
+
+        let urls =
+            collect_sitemap_urls(&Client::new(), &server.url("/sitemap.xml.gz"), &LIMITS).await;
+        assert_eq!(urls, ["https://example.com/bbs/notice"]);
+    }
+
+    #[tokio::test]
+    async fn test_sitemap_index_with_two_children() {
+        let server = TestServer::start(|req| {
+            let base = format!("http://{}", req.headers["host"]);
+            match req.path.as_str() {
+                "/sitemap.xml" => xml(format!(
+                    "<sitemapindex><sitemap><loc>{base}/a.xml</loc></sitemap><sitemap><loc>{base}/b.xml</loc></sitemap></sitemapindex>"
+                )),
+                "/a.xml" => xml(urlset(&[
+                    "https://example.com/notice",
+                    "https://example.com/shared",
+                ])),
+                "/b.xml" => xml(urlset(&[
+                    "https://example.com/shared",
+                    "https://example.com/career",
+                ])),
+                _ => TestResponse {
+                    status: 404,
+                    ..TestResponse::html("")
+                },
+            }
+        });
+
+        let urls = collect_sitemap_urls(&Client::new(), &server.url("/sitemap.xml"), &LIMITS).await;
+        assert_eq!(
+            urls,
+            [
+                "https://example.com/notice",
+                "https://example.com/shared",
+                "https://example.com/career"
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_self_referencing_index_is_bounded() {
+        let server = TestServer::start(|req| {
+            let base = format!("http://{}", req.headers["host"]);
+            let n: usize = req
+                .path
+                .trim_start_matches("/s")
+                .trim_end_matches(".xml")
+                .parse()
+                .unwrap_or(0);
+            xml(format!(
+                "<sitemapindex><sitemap><loc>{base}/s{}.xml</loc></sitemap><sitemap><loc>{base}/s0.xml</loc></sitemap></sitemapindex>",
+                n + 1
+            ))
+        });
+
+        let limits = SitemapLimits {
+            max_depth: 100,
+            max_fetches: 5,
+            ..LIMITS
+        };
+        let urls = collect_sitemap_urls(&Client::new(), &server.url("/s0.xml"), &limits).await;
+        assert!(urls.is_empty());
+        assert_eq!(server.requests().len(), 5);
+    }
+}
@@ -0,0 +1,200 @@
+//! Per-domain concurrency ramp-up (slow start).
+//!
+//! Each domain starts with a single in-flight request. The limit grows
+//! linearly to `max_concurrent` over the first `ramp_requests` successful
+//! requests, and halves on a retryable failure.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+
+/// Initial in-flight requests per domain.
+const INITIAL_CONCURRENCY: usize = 1;
+
+/// Successful requests over which a domain ramps up to full concurrency.
+pub const DEFAULT_RAMP_REQUESTS: usize = 20;
+
+/// Slow-start controller shared by all crawl jobs.
+pub struct SlowStart {
+    max_concurrent: usize,
+    ramp_requests: usize,
+    domains: Mutex<HashMap<String, Arc<DomainRamp>>>,
+}
+
+struct DomainRamp {
+    semaphore: Arc<Semaphore>,
+    state: Mutex<RampState>,
+}
+
+struct RampState {
+    /// Current permit budget
+    limit: usize,
+    /// Successful requests since the last back-off
+    successes: usize,
+    /// Permits to retire as they are released (after a back-off)
+    debt: usize,
+}
+
+/// An in-flight request slot. Report the outcome with [`RampPermit::finish`];
+/// dropping it releases the slot without adjusting the ramp.
+pub struct RampPermit {
+    permit: Option<OwnedSemaphorePermit>,
+    ramp: Arc<DomainRamp>,
+    max_concurrent: usize,
+    ramp_requests: usize,
+}
+
+impl SlowStart {
+    /// Create a controller ramping up to `max_concurrent` per domain.
+    pub fn new(max_concurrent: usize, ramp_requests: usize) -> Self {
+        Self {
+            max_concurrent: max_concurrent.max(INITIAL_CONCURRENCY),
+            ramp_requests: ramp_requests.max(1),
+            domains: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Wait for a request slot on `domain`.
+    pub async fn acquire(&self, domain: &str) -> RampPermit {
+        let ramp = self.domain(domain);
+        let permit = Arc::clone(&ramp.semaphore)
+            .acquire_owned()
+            .await
+            .expect("slow-start semaphore is never closed");
+        RampPermit {
+            permit: Some(permit),
+            ramp,
+            max_concurrent: self.max_concurrent,
+            ramp_requests: self.ramp_requests,
+        }
+    }
+
+    /// Current concurrency limit for `domain`.
+    pub fn limit(&self, domain: &str) -> usize {
+        self.domain(domain).state.lock().unwrap().limit
+    }
+
+    fn domain(&self, domain: &str) -> Arc<DomainRamp> {
+        let mut domains = self.domains.lock().unwrap();
+        Arc::clone(domains.entry(domain.to_string()).or_insert_with(|| {
+            Arc::new(DomainRamp {
+                semaphore: Arc::new(Semaphore::new(INITIAL_CONCURRENCY)),
+                state: Mutex::new(RampState {
+                    limit: INITIAL_CONCURRENCY,
+                    successes: 0,
+                    debt: 0,
+                }),
+            })
+        }))
+    }
+}
+
+impl RampPermit {
+    /// Release the slot, ramping up on success or backing off on failure.
+    pub fn finish(self, success: bool) {
+        let mut state = self.ramp.state.lock().unwrap();
+
+        if success {
+            state.successes += 1;
+            let span = self.max_concurrent - INITIAL_CONCURRENCY;
+            let target = (INITIAL_CONCURRENCY + span * state.successes / self.ramp_requests)
+                .min(self.max_concurrent);
+            if target > state.limit {
+                let grow = target - state.limit;
+                // Cancel outstanding debt before adding fresh permits
+                let repaid = grow.min(state.debt);
+                state.debt -= repaid;
+                self.ramp.semaphore.add_permits(grow - repaid);
+                state.limit = target;
+            }
+        } else {
+            let target = (state.limit / 2).max(INITIAL_CONCURRENCY);
+            state.debt += state.limit - target;
+            state.limit = target;
+            state.successes = 0;
+        }
+    }
+}
+
+impl Drop for RampPermit {
+    fn drop(&mut self) {
+        let Some(permit) = self.permit.take() else {
+            return;
+        };
+        let mut state = self.ramp.state.lock().unwrap();
+        if state.debt > 0 {
+            // Retire the permit instead of returning it after a back-off
+            state.debt -= 1;
+            permit.forget();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    const DOMAIN: &str = "example.com";
+
+    #[tokio::test]
+    async fn test_starts_low_and_ramps_up_without_errors() {
+        let slow_start = SlowStart::new(5, 8);
+        assert_eq!(slow_start.limit(DOMAIN), 1);
+
+        // Only one slot is available at first
+        let first = slow_start.acquire(DOMAIN).await;
+        let second = tokio::time::timeout(Duration::from_millis(20), slow_start.acquire(DOMAIN));
+        assert!(second.await.is_err());
+        first.finish(true);
+
+        let mut limits = Vec::new();
+        for _ in 0..8 {
+            slow_start.acquire(DOMAIN).await.finish(true);
+            limits.push(slow_start.limit(DOMAIN));
+        }
+        assert!(limits.windows(2).all(|w| w[0] <= w[1]));
+        assert_eq!(*limits.last().unwrap(), 5);
+
+        // Full concurrency is now available
+        let mut permits = Vec::new();
+        for _ in 0..5 {
+            permits.push(slow_start.acquire(DOMAIN).await);
+        }
+        let extra = tokio::time::timeout(Duration::from_millis(20), slow_start.acquire(DOMAIN));
+        assert!(extra.await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_backs_off_on_failure() {
+        let slow_start = SlowStart::new(8, 1);
+        slow_start.acquire(DOMAIN).await.finish(true);
+        assert_eq!(slow_start.limit(DOMAIN), 8);
+
+        let mut permits = Vec::new();
+        for _ in 0..8 {
+            permits.push(slow_start.acquire(DOMAIN).await);
+        }
+        permits.pop().unwrap().finish(false);
+        assert_eq!(slow_start.limit(DOMAIN), 4);
+        drop(permits);
+
+        // Released permits beyond the new limit were retired
+        let mut held = Vec::new();
+        for _ in 0..4 {
+            held.push(slow_start.acquire(DOMAIN).await);
+        }
+        let extra = tokio::time::timeout(Duration::from_millis(20), slow_start.acquire(DOMAIN));
+        assert!(extra.await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_domains_ramp_independently() {
+        let slow_start = SlowStart::new(4, 1);
+        slow_start.acquire("a.example.com").await.finish(true);
+        assert_eq!(slow_start.limit("a.example.com"), 4);
+        assert_eq!(slow_start.limit("b.example.com"), 1);
+    }
+}
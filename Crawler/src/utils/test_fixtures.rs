@@ -0,0 +1,24 @@
+//! Shared fixtures for unit tests.
+
+use crate::models::{NoticeMetadata, NoticeOutput};
+
+/// Snapshot notice `id_{i}` dated `date` on a 신촌캠퍼스 학생처 board.
+pub fn notice(i: usize, date: &str) -> NoticeOutput {
+    NoticeOutput {
+        id: format!("id_{}", i),
+        title: format!("Notice {}", i),
+        link: format!("https://example.com/view?id={}&page=1", i),
+        metadata: NoticeMetadata {
+            campus: "신촌캠퍼스".to_string(),
+            college: "".to_string(),
+            department_name: "학생처".to_string(),
+            board_name: "공지".to_string(),
+            author: String::new(),
+            date: date.to_string(),
+            pinned: false,
+            lang: None,
+            has_attachment: false,
+            content_hash: None,
+        },
+    }
+}
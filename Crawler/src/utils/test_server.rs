@@ -0,0 +1,131 @@
+//! Minimal blocking HTTP/1.1 server for fetch-path tests.
+//!
+//! Runs on a background thread bound to an ephemeral localhost port and
+//! answers each request through a handler closure.
+
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+/// A request as seen by the test server.
+#[derive(Debug, Clone)]
+pub struct TestRequest {
+    pub path: String,
+    /// Header names are lowercased
+    pub headers: HashMap<String, String>,
+}
+
+/// A canned response.
+#[derive(Debug, Clone)]
+pub struct TestResponse {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+}
+
+impl TestResponse {
+    /// 200 response with an HTML body.
+    pub fn html(body: impl Into<Vec<u8>>) -> Self {
+        Self {
+            status: 200,
+            headers: vec![("Content-Type".into(), "text/html; charset=utf-8".into())],
+            body: body.into(),
+        }
+    }
+
+    /// Add a response header.
+    pub fn header(mut self, name: &str, value: &str) -> Self {
+        self.headers.push((name.to_string(), value.to_string()));
+        self
+    }
+}
+
+/// Handle to a running test server.
+pub struct TestServer {
+    base_url: String,
+    requests: Arc<Mutex<Vec<TestRequest>>>,
+}
+
+impl TestServer {
+    /// Start a server answering every request with `handler`.
+    ///
+    /// Each connection is served on its own thread, so a slow response does
+    /// not hold up concurrent requests.
+    pub fn start<F>(handler: F) -> Self
+    where
+        F: Fn(&TestRequest) -> TestResponse + Send + Sync + 'static,
+    {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let recorded = Arc::clone(&requests);
+        let handler = Arc::new(handler);
+
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let Ok(stream) = stream else { break };
+                let handler = Arc::clone(&handler);
+                let recorded = Arc::clone(&recorded);
+                thread::spawn(move || serve(stream, handler.as_ref(), &recorded));
+            }
+        });
+
+        Self { base_url, requests }
+    }
+
+    /// Absolute URL for `path` on this server.
+    pub fn url(&self, path: &str) -> String {
+        format!("{}{}", self.base_url, path)
+    }
+
+    /// Requests received so far.
+    pub fn requests(&self) -> Vec<TestRequest> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+fn serve(
+    mut stream: TcpStream,
+    handler: &dyn Fn(&TestRequest) -> TestResponse,
+    recorded: &Mutex<Vec<TestRequest>>,
+) {
+    let Some(request) = read_request(&mut BufReader::new(&stream)) else {
+        return;
+    };
+    recorded.lock().unwrap().push(request.clone());
+    let response = handler(&request);
+
+    let mut head = format!("HTTP/1.1 {} Test\r\n", response.status);
+    for (name, value) in &response.headers {
+        head.push_str(&format!("{}: {}\r\n", name, value));
+    }
+    head.push_str(&format!(
+        "Content-Length: {}\r\nConnection: close\r\n\r\n",
+        response.body.len()
+    ));
+    let _ = stream.write_all(head.as_bytes());
+    let _ = stream.write_all(&response.body);
+}
+
+fn read_request(reader: &mut impl BufRead) -> Option<TestRequest> {
+    let mut line = String::new();
+    reader.read_line(&mut line).ok()?;
+    let path = line.split_whitespace().nth(1)?.to_string();
+
+    let mut headers = HashMap::new();
+    loop {
+        line.clear();
+        reader.read_line(&mut line).ok()?;
+        let trimmed = line.trim_end();
+        if trimmed.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = trimmed.split_once(':') {
+            headers.insert(name.trim().to_lowercase(), value.trim().to_string());
+        }
+    }
+
+    Some(TestRequest { path, headers })
+}
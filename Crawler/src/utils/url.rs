@@ -0,0 +1,102 @@
+//! URL canonicalization for notice identity.
+//!
+//! Boards link the same notice with query parameters in varying order, with
+//! tracking parameters or with a session token appended. The canonical form
+//! folds these together so [`crate::models::Notice::canonical_id`] is stable.
+
+use ::url::Url;
+
+/// Query parameters that carry a session rather than identify a notice.
+///
+/// Matched case-insensitively; `utm_*` parameters are always dropped too.
+pub const SESSION_PARAMS: &[&str] = &["jsessionid", "phpsessid", "aspsessionid", "sessionid"];
+
+/// Canonical form of `url`: lowercase scheme and host, no fragment, query
+/// parameters sorted, and `utm_*`, [`SESSION_PARAMS`] and `extra_params`
+/// removed. A `;jsessionid=...` path parameter is stripped; other `;`
+/// segments are kept.
+///
+/// Strings that do not parse as absolute URLs are returned trimmed.
+pub fn canonicalize_with(url: &str, extra_params: &[String]) -> String {
+    let Ok(mut parsed) = Url::parse(url.trim()) else {
+        return url.trim().to_string();
+    };
+    parsed.set_fragment(None);
+
+    let path = parsed.path().to_string();
+    if let Some(start) = path.to_ascii_lowercase().find(";jsessionid=") {
+        let end = path[start + 1..]
+            .find([';', '/'])
+            .map_or(path.len(), |i| start + 1 + i);
+        let path = format!("{}{}", &path[..start], &path[end..]);
+        parsed.set_path(&path);
+    }
+
+    let is_ignored = |key: &str| {
+        let key = key.to_ascii_lowercase();
+        key.starts_with("utm_")
+            || SESSION_PARAMS.contains(&key.as_str())
+            || extra_params.iter().any(|p| p.eq_ignore_ascii_case(&key))
+    };
+    let mut pairs: Vec<(String, String)> = parsed
+        .query_pairs()
+        .filter(|(key, _)| !is_ignored(key))
+        .map(|(key, value)| (key.into_owned(), value.into_owned()))
+        .collect();
+    pairs.sort();
+
+    if pairs.is_empty() {
+        parsed.set_query(None);
+    } else {
+        parsed.query_pairs_mut().clear().extend_pairs(pairs);
+    }
+    // `Url` already lowercases the scheme and host
+    parsed.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_canonicalize() {
+        let canonicalize = |url| canonicalize_with(url, &[]);
+        assert_eq!(
+            canonicalize("https://Example.COM/board/view.do?page=1&id=5#comments"),
+            "https://example.com/board/view.do?id=5&page=1"
+        );
+        assert_eq!(
+            canonicalize("https://example.com/view;jsessionid=ABC?utm_source=x&id=5&PHPSESSID=1"),
+            "https://example.com/view?id=5"
+        );
+        assert_eq!(
+            canonicalize("https://example.com/view?utm_medium=mail"),
+            "https://example.com/view"
+        );
+        assert_eq!(canonicalize(" /relative?b=1&a=2 "), "/relative?b=1&a=2");
+    }
+
+    #[test]
+    fn test_canonicalize_keeps_identifying_params() {
+        let canonicalize = |url| canonicalize_with(url, &[]);
+        // `sid` is a notice or site ID on some boards
+        assert_eq!(
+            canonicalize("https://example.com/view?sid=12&id=5"),
+            "https://example.com/view?id=5&sid=12"
+        );
+        // Only the session path parameter is stripped
+        assert_eq!(
+            canonicalize("https://example.com/board;type=notice/view;jsessionid=ABC?id=5"),
+            "https://example.com/board;type=notice/view?id=5"
+        );
+    }
+
+    #[test]
+    fn test_canonicalize_with_extra_params() {
+        let extra = vec!["token".to_string()];
+        assert_eq!(
+            canonicalize_with("https://example.com/view?id=5&Token=xyz", &extra),
+            "https://example.com/view?id=5"
+        );
+    }
+}